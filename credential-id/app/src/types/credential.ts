@@ -21,6 +21,35 @@ export const CredentialStatus = {
 
 export type CredentialStatus = typeof CredentialStatus[keyof typeof CredentialStatus];
 
+export const InvestorTier = {
+  Retail: 'retail',
+  Qualified: 'qualified',
+  Professional: 'professional',
+  Institutional: 'institutional',
+} as const;
+
+export type InvestorTier = typeof InvestorTier[keyof typeof InvestorTier];
+
+export const RiskBand = {
+  Low: 'low',
+  Medium: 'medium',
+  High: 'high',
+} as const;
+
+export type RiskBand = typeof RiskBand[keyof typeof RiskBand];
+
+export interface CredentialAttributes {
+  /** ISO 3166-1 alpha-2 country of residence */
+  countryOfResidence: string;
+  /** ISO 3166-1 alpha-2 country of tax residency */
+  taxResidency: string;
+  investorTier: InvestorTier;
+  riskBand: RiskBand;
+  isPep: boolean;
+  /** Self-declared investment limit in lamports */
+  investmentLimit: BN | null;
+}
+
 export interface UserCredential {
   holder: PublicKey;
   issuer: PublicKey;
@@ -31,6 +60,12 @@ export interface UserCredential {
   lastVerifiedAt: BN;
   metadataUri: string;
   revocationReason: string | null;
+  /** Suspension end (0 = until reinstated) */
+  suspendedUntil: BN;
+  suspensionReason: string | null;
+  attributes: CredentialAttributes;
+  /** Merkle root of salted off-chain KYC attributes (all zero = none) */
+  commitment: number[];
   bump: number;
 }
 
@@ -40,18 +75,23 @@ export interface CredentialIssuer {
   name: string;
   uri: string;
   isActive: boolean;
-  canIssueKyc: boolean;
-  canIssueAccredited: boolean;
+  /** Bitmap of permitted credential types (bit N = CredentialType N) */
+  permissions: number;
   credentialsIssued: BN;
   activeCredentials: BN;
   revokedCredentials: BN;
+  /** New authority if this issuer's key was rotated */
+  rotatedTo: PublicKey | null;
   bump: number;
 }
 
 export interface CredentialNetwork {
   admin: PublicKey;
+  /** Proposed admin awaiting acceptance */
+  pendingAdmin: PublicKey | null;
   name: string;
   credentialFee: BN;
+  totalFeesCollected: BN;
   isActive: boolean;
   totalCredentialsIssued: BN;
   activeCredentials: BN;
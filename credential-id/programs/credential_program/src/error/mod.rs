@@ -46,4 +46,46 @@ pub enum CredentialError {
 
     #[msg("Reason too long")]
     ReasonTooLong,
+
+    #[msg("Invalid issuer permissions")]
+    InvalidPermissions,
+
+    #[msg("Issuer is not permitted to issue this credential type")]
+    CredentialTypeNotPermitted,
+
+    #[msg("Credential is suspended")]
+    CredentialSuspended,
+
+    #[msg("Credential is not suspended")]
+    CredentialNotSuspended,
+
+    #[msg("Invalid suspension end timestamp")]
+    InvalidSuspensionPeriod,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Signer is not the pending network admin")]
+    NotPendingAdmin,
+
+    #[msg("Issuer must be deactivated first")]
+    IssuerStillActive,
+
+    #[msg("Credential was not issued by this issuer")]
+    CredentialIssuerMismatch,
+
+    #[msg("Credential has not expired")]
+    CredentialNotExpired,
+
+    #[msg("Invalid ISO 3166-1 alpha-2 country code")]
+    InvalidCountryCode,
+
+    #[msg("Credential has no attribute commitment")]
+    NoCommitment,
+
+    #[msg("Attribute too long")]
+    AttributeTooLong,
+
+    #[msg("Invalid attribute proof")]
+    InvalidAttributeProof,
 }
@@ -0,0 +1,212 @@
+/// Events emitted by the Hub Credential Protocol for indexers
+use anchor_lang::prelude::*;
+
+use crate::state::{CredentialAttributes, CredentialType};
+
+// ============================================================================
+// NETWORK
+// ============================================================================
+
+#[event]
+pub struct NetworkInitialized {
+    pub network: Pubkey,
+    pub admin: Pubkey,
+    pub name: String,
+    pub credential_fee_lamports: u64,
+    pub treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NetworkConfigUpdated {
+    pub network: Pubkey,
+    pub name: String,
+    pub credential_fee_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NetworkStatusChanged {
+    pub network: Pubkey,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+/// `pending_admin` is None when a proposal is cancelled
+#[event]
+pub struct NetworkAdminProposed {
+    pub network: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NetworkAdminTransferred {
+    pub network: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NetworkFeesWithdrawn {
+    pub network: Pubkey,
+    pub treasury: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// ISSUERS
+// ============================================================================
+
+#[event]
+pub struct IssuerRegistered {
+    pub issuer: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub permissions: u8,
+    pub timestamp: i64,
+}
+
+/// Full issuer configuration after any admin change
+/// (metadata, permissions or activation status)
+#[event]
+pub struct IssuerUpdated {
+    pub issuer: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub permissions: u8,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IssuerAuthorityRotated {
+    pub old_issuer: Pubkey,
+    pub new_issuer: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// CREDENTIALS
+// ============================================================================
+
+#[event]
+pub struct CredentialIssued {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub expires_at: i64,
+    pub metadata_uri: String,
+    pub attributes: CredentialAttributes,
+    pub commitment: [u8; 32],
+    pub fee_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialRefreshed {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub expires_at: i64,
+    pub fee_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub revoked_by: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialAttributesUpdated {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub attributes: CredentialAttributes,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialCommitmentUpdated {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// `suspended_until` of 0 means until reinstated
+#[event]
+pub struct CredentialSuspended {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub suspended_by: Pubkey,
+    pub reason: String,
+    pub suspended_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialReinstated {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub reinstated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialExpired {
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub issuer: Pubkey,
+    pub credential_type: CredentialType,
+    pub expired_at: i64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// MIGRATIONS
+// ============================================================================
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
+
+/// A first-release credential moved to its per-type address
+#[event]
+pub struct CredentialMigrated {
+    pub legacy_credential: Pubkey,
+    pub credential: Pubkey,
+    pub holder: Pubkey,
+    pub credential_type: CredentialType,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::CredentialNetwork;
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct AcceptNetworkAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.pending_admin == Some(new_admin.key()) @ CredentialError::NotPendingAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+}
+
+/// Step 2 of the admin transfer, signed by the proposed admin
+pub fn accept_network_admin(ctx: Context<AcceptNetworkAdmin>) -> Result<()> {
+    let network = &mut ctx.accounts.network;
+    let previous_admin = network.admin;
+
+    network.admin = ctx.accounts.new_admin.key();
+    network.pending_admin = None;
+
+    msg!(
+        "Network admin transferred from {} to {}",
+        previous_admin,
+        network.admin
+    );
+
+    emit!(NetworkAdminTransferred {
+        network: network.key(),
+        previous_admin,
+        new_admin: network.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential, CredentialStatus};
+use crate::error::CredentialError;
+use crate::events::*;
+
+/// Record that a credential has passed its expiry
+/// Permissionless crank - keeps status and active counts in sync for indexers
+#[derive(Accounts)]
+pub struct ExpireCredential<'info> {
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    /// The issuer of the credential (stats are kept per issuer)
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, credential.issuer.as_ref()],
+        bump = issuer.bump,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+
+    /// The wallet whose credential has expired
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+        constraint = credential.status == CredentialStatus::Active @ CredentialError::CredentialNotActive,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn expire_credential(ctx: Context<ExpireCredential>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credential = &mut ctx.accounts.credential;
+    let issuer = &mut ctx.accounts.issuer;
+    let network = &mut ctx.accounts.network;
+
+    require!(
+        credential.is_expired(clock.unix_timestamp),
+        CredentialError::CredentialNotExpired
+    );
+
+    // Update credential
+    credential.status = CredentialStatus::Expired;
+
+    // Update stats
+    issuer.active_credentials = issuer.active_credentials.saturating_sub(1);
+    network.active_credentials = network.active_credentials.saturating_sub(1);
+
+    msg!(
+        "Credential expired for holder: {} at {}",
+        credential.holder,
+        credential.expires_at
+    );
+
+    emit!(CredentialExpired {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        expired_at: credential.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
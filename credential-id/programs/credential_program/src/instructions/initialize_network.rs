@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use crate::state::CredentialNetwork;
 use crate::error::CredentialError;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct InitializeNetwork<'info> {
@@ -16,6 +18,14 @@ pub struct InitializeNetwork<'info> {
     )]
     pub network: Account<'info, CredentialNetwork>,
 
+    /// Network treasury PDA - funded to rent exemption here, collects credential fees
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -30,16 +40,44 @@ pub fn initialize_network(
     let clock = Clock::get()?;
 
     network.admin = ctx.accounts.admin.key();
+    network.pending_admin = None;
     network.name = network_name;
     network.credential_fee_lamports = credential_fee_lamports;
+    network.total_fees_collected = 0;
     network.total_credentials_issued = 0;
     network.active_credentials = 0;
     network.total_issuers = 0;
     network.is_active = true;
     network.created_at = clock.unix_timestamp;
     network.bump = ctx.bumps.network;
+    network.version = CredentialNetwork::CURRENT_VERSION;
+
+    // Fund the treasury to rent exemption so fees of any size can be collected
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let shortfall = rent_exempt_minimum.saturating_sub(ctx.accounts.treasury.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
 
     msg!("Credential Network initialized: {}", network.name);
 
+    emit!(NetworkInitialized {
+        network: network.key(),
+        admin: network.admin,
+        name: network.name.clone(),
+        credential_fee_lamports: network.credential_fee_lamports,
+        treasury: ctx.accounts.treasury.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
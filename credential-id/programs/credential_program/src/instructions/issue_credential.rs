@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential, CredentialType, CredentialStatus};
+use anchor_lang::system_program;
+use crate::state::{
+    CredentialAttributes, CredentialIssuer, CredentialNetwork, CredentialStatus, CredentialType,
+    UserCredential,
+};
 use crate::error::CredentialError;
+use crate::events::*;
 
 #[derive(Accounts)]
+#[instruction(credential_type: u8)]
 pub struct IssueCredential<'info> {
     #[account(mut)]
     pub issuer_authority: Signer<'info>,
@@ -24,6 +30,14 @@ pub struct IssueCredential<'info> {
     )]
     pub issuer: Account<'info, CredentialIssuer>,
 
+    /// Network treasury PDA - receives the credential fee
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
     /// The wallet receiving the credential
     /// CHECK: This is the credential holder's wallet
     pub holder: UncheckedAccount<'info>,
@@ -32,7 +46,7 @@ pub struct IssueCredential<'info> {
         init,
         payer = issuer_authority,
         space = 8 + UserCredential::INIT_SPACE,
-        seeds = [UserCredential::SEED, holder.key().as_ref()],
+        seeds = [UserCredential::SEED, holder.key().as_ref(), &[credential_type]],
         bump,
     )]
     pub credential: Account<'info, UserCredential>,
@@ -45,8 +59,11 @@ pub fn issue_credential(
     credential_type: u8,
     expiry_timestamp: i64,
     metadata_uri: String,
+    attributes: CredentialAttributes,
+    commitment: [u8; 32],
 ) -> Result<()> {
     require!(metadata_uri.len() <= 200, CredentialError::UriTooLong);
+    require!(attributes.is_valid(), CredentialError::InvalidCountryCode);
 
     let clock = Clock::get()?;
 
@@ -57,15 +74,29 @@ pub fn issue_credential(
     );
 
     // Convert credential type
-    let cred_type = match credential_type {
-        0 => CredentialType::KycBasic,
-        1 => CredentialType::KycFull,
-        2 => CredentialType::AccreditedInvestor,
-        3 => CredentialType::QualifiedPurchaser,
-        4 => CredentialType::BrazilianCpf,
-        5 => CredentialType::BrazilianCnpj,
-        _ => return Err(CredentialError::InvalidCredentialType.into()),
-    };
+    let cred_type = CredentialType::from_u8(credential_type)
+        .ok_or(CredentialError::InvalidCredentialType)?;
+
+    // Issuer must be permitted to issue this type
+    require!(
+        ctx.accounts.issuer.can_issue(cred_type),
+        CredentialError::CredentialTypeNotPermitted
+    );
+
+    // Collect the network credential fee from the issuer
+    let fee = ctx.accounts.network.credential_fee_lamports;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.issuer_authority.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
 
     let credential = &mut ctx.accounts.credential;
     let issuer = &mut ctx.accounts.issuer;
@@ -80,7 +111,11 @@ pub fn issue_credential(
     credential.last_verified_at = clock.unix_timestamp;
     credential.metadata_uri = metadata_uri;
     credential.revocation_reason = String::new();
-    credential.version = 1;
+    credential.suspended_until = 0;
+    credential.suspension_reason = String::new();
+    credential.attributes = attributes;
+    credential.commitment = commitment;
+    credential.version = UserCredential::CURRENT_VERSION;
     credential.bump = ctx.bumps.credential;
 
     // Update stats
@@ -88,12 +123,27 @@ pub fn issue_credential(
     issuer.active_credentials += 1;
     network.total_credentials_issued += 1;
     network.active_credentials += 1;
+    network.total_fees_collected += fee;
 
     msg!(
-        "Credential issued to {} by {}",
+        "Credential issued to {} by {} (fee: {} lamports)",
         credential.holder,
-        issuer.name
+        issuer.name,
+        fee
     );
 
+    emit!(CredentialIssued {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        expires_at: credential.expires_at,
+        metadata_uri: credential.metadata_uri.clone(),
+        attributes,
+        commitment,
+        fee_lamports: fee,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,272 @@
+/// Account migrations - Upgrade accounts created by earlier program versions
+///
+/// New fields are appended after `bump` behind a `version` byte, so an old
+/// account keeps its layout and only needs to grow: the added bytes are zero,
+/// which is a valid default for every appended field. Values that have a
+/// first-release equivalent are derived from it when the version is 0.
+/// The network migration also funds the fee treasury to rent exemption, as
+/// initialize_network does for new networks.
+///
+/// First-release credentials were derived from ["credential", holder]; they
+/// are moved to ["credential", holder, credential_type] with the appended
+/// fields zeroed, and the legacy account is closed.
+///
+/// Migrations are permissionless and idempotent; the payer funds the rent
+/// for the extra space.
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct MigrateNetwork<'info> {
+    /// Pays the rent for the added space and the treasury
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Network PDA (read raw: old accounts may not have room for the appended fields)
+    /// CHECK: Owner and seeds checked; discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [CredentialNetwork::SEED],
+        bump,
+    )]
+    pub network: UncheckedAccount<'info>,
+
+    /// Network treasury PDA - collects credential fees
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_network(ctx: Context<MigrateNetwork>) -> Result<()> {
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let network_info = ctx.accounts.network.to_account_info();
+    grow_account(
+        &network_info,
+        &payer,
+        &system_program,
+        8 + CredentialNetwork::INIT_SPACE,
+    )?;
+
+    // Fees of any size can only be collected into a rent exempt treasury
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let shortfall = rent_exempt_minimum.saturating_sub(treasury_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program,
+                system_program::Transfer {
+                    from: payer,
+                    to: treasury_info,
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    let mut network = CredentialNetwork::try_deserialize(&mut &network_info.try_borrow_data()?[..])?;
+
+    let old_version = network.version;
+    network.version = CredentialNetwork::CURRENT_VERSION;
+    network.try_serialize(&mut &mut network_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Network {} migrated: version {} -> {}",
+        network.name,
+        old_version,
+        CredentialNetwork::CURRENT_VERSION
+    );
+
+    emit!(AccountMigrated {
+        account: network_info.key(),
+        old_version,
+        new_version: CredentialNetwork::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateIssuer<'info> {
+    /// Pays the rent for the added space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The issuer's authority wallet
+    /// CHECK: Only used to derive the issuer PDA
+    pub issuer_authority: UncheckedAccount<'info>,
+
+    /// Issuer PDA (read raw: old accounts may not have room for the appended fields)
+    /// CHECK: Owner and seeds checked; discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [CredentialIssuer::SEED, issuer_authority.key().as_ref()],
+        bump,
+    )]
+    pub issuer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_issuer(ctx: Context<MigrateIssuer>) -> Result<()> {
+    let issuer_info = ctx.accounts.issuer.to_account_info();
+    grow_account(
+        &issuer_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + CredentialIssuer::INIT_SPACE,
+    )?;
+
+    let mut issuer = CredentialIssuer::try_deserialize(&mut &issuer_info.try_borrow_data()?[..])?;
+
+    let old_version = issuer.version;
+    if old_version == 0 {
+        let permissions = issuer.legacy_permissions();
+        issuer.set_permissions(permissions);
+    }
+    issuer.version = CredentialIssuer::CURRENT_VERSION;
+    issuer.try_serialize(&mut &mut issuer_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Issuer {} migrated: version {} -> {}",
+        issuer.name,
+        old_version,
+        CredentialIssuer::CURRENT_VERSION
+    );
+
+    emit!(AccountMigrated {
+        account: issuer_info.key(),
+        old_version,
+        new_version: CredentialIssuer::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(credential_type: u8)]
+pub struct MigrateCredential<'info> {
+    /// Pays the rent for the new account and receives the legacy account's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The credential holder's wallet
+    /// CHECK: Only used to derive the credential PDAs
+    pub holder: UncheckedAccount<'info>,
+
+    /// First-release credential PDA - closed
+    /// CHECK: Owner and seeds checked; discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [UserCredential::SEED, holder.key().as_ref()],
+        bump,
+    )]
+    pub legacy_credential: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserCredential::INIT_SPACE,
+        seeds = [UserCredential::SEED, holder.key().as_ref(), &[credential_type]],
+        bump,
+    )]
+    pub credential: Account<'info, UserCredential>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_credential(ctx: Context<MigrateCredential>, credential_type: u8) -> Result<()> {
+    let legacy_info = ctx.accounts.legacy_credential.to_account_info();
+
+    // The legacy account may end before the appended fields; read them as zero
+    let mut data = legacy_info.try_borrow_data()?.to_vec();
+    data.resize(8 + UserCredential::INIT_SPACE, 0);
+    let mut credential = UserCredential::try_deserialize(&mut &data[..])?;
+
+    require!(
+        credential.credential_type as u8 == credential_type,
+        CredentialError::InvalidCredentialType
+    );
+    require_keys_eq!(
+        credential.holder,
+        ctx.accounts.holder.key(),
+        CredentialError::CredentialNotFound
+    );
+
+    let old_version = credential.version;
+    credential.version = UserCredential::CURRENT_VERSION;
+    credential.bump = ctx.bumps.credential;
+    ctx.accounts.credential.set_inner(credential);
+
+    // Close the legacy account
+    let payer_info = ctx.accounts.payer.to_account_info();
+    **payer_info.try_borrow_mut_lamports()? += legacy_info.lamports();
+    **legacy_info.try_borrow_mut_lamports()? = 0;
+    legacy_info.assign(&system_program::ID);
+    legacy_info.realloc(0, false)?;
+
+    msg!(
+        "Credential of {} migrated: {} -> {}",
+        ctx.accounts.holder.key(),
+        legacy_info.key(),
+        ctx.accounts.credential.key()
+    );
+
+    emit!(CredentialMigrated {
+        legacy_credential: legacy_info.key(),
+        credential: ctx.accounts.credential.key(),
+        holder: ctx.accounts.credential.holder,
+        credential_type: ctx.accounts.credential.credential_type,
+        old_version,
+        new_version: UserCredential::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Resize a program-owned account to at least `new_len` bytes (zero-filled),
+/// topping up its lamports to stay rent exempt
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let required = Rent::get()?.minimum_balance(new_len);
+    let top_up = required.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
@@ -4,6 +4,23 @@ pub mod issue_credential;
 pub mod verify_credential;
 pub mod revoke_credential;
 pub mod refresh_credential;
+pub mod update_credential_attributes;
+pub mod update_credential_commitment;
+pub mod prove_attribute;
+pub mod update_issuer_permissions;
+pub mod suspend_credential;
+pub mod reinstate_credential;
+pub mod expire_credential;
+pub mod withdraw_network_fees;
+pub mod update_network_config;
+pub mod update_issuer;
+pub mod set_issuer_status;
+pub mod rotate_issuer_authority;
+pub mod revoke_issuer_credentials;
+pub mod set_network_status;
+pub mod propose_network_admin;
+pub mod accept_network_admin;
+pub mod migrate_accounts;
 
 pub use initialize_network::*;
 pub use register_issuer::*;
@@ -11,3 +28,20 @@ pub use issue_credential::*;
 pub use verify_credential::*;
 pub use revoke_credential::*;
 pub use refresh_credential::*;
+pub use update_credential_attributes::*;
+pub use update_credential_commitment::*;
+pub use prove_attribute::*;
+pub use update_issuer_permissions::*;
+pub use suspend_credential::*;
+pub use reinstate_credential::*;
+pub use expire_credential::*;
+pub use withdraw_network_fees::*;
+pub use update_network_config::*;
+pub use update_issuer::*;
+pub use set_issuer_status::*;
+pub use rotate_issuer_authority::*;
+pub use revoke_issuer_credentials::*;
+pub use set_network_status::*;
+pub use propose_network_admin::*;
+pub use accept_network_admin::*;
+pub use migrate_accounts::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::CredentialNetwork;
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ProposeNetworkAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+}
+
+/// Step 1 of the admin transfer; `None` cancels a pending proposal
+pub fn propose_network_admin(
+    ctx: Context<ProposeNetworkAdmin>,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    let network = &mut ctx.accounts.network;
+    network.pending_admin = new_admin;
+
+    match new_admin {
+        Some(new_admin) => msg!("Network admin transfer proposed to {}", new_admin),
+        None => msg!("Network admin transfer cancelled"),
+    }
+
+    emit!(NetworkAdminProposed {
+        network: network.key(),
+        admin: network.admin,
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
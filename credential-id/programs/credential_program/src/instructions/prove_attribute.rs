@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::UserCredential;
+use crate::error::CredentialError;
+
+/// Maximum length of a disclosed attribute (e.g. "country=BR")
+pub const MAX_ATTRIBUTE_LEN: usize = 64;
+
+/// Maximum Merkle proof depth (up to 65536 attributes)
+pub const MAX_PROOF_DEPTH: usize = 16;
+
+/// Domain separation prefixes so a node can never be presented as a leaf
+const LEAF_PREFIX: &[u8] = &[0x00];
+const NODE_PREFIX: &[u8] = &[0x01];
+
+/// Prove a single attribute of a credential's committed KYC record
+/// Discloses only that attribute and its salt (selective disclosure)
+#[derive(Accounts)]
+pub struct ProveAttribute<'info> {
+    /// The wallet whose credential attribute is being proven
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn prove_attribute(
+    ctx: Context<ProveAttribute>,
+    attribute: Vec<u8>,
+    salt: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(attribute.len() <= MAX_ATTRIBUTE_LEN, CredentialError::AttributeTooLong);
+    require!(proof.len() <= MAX_PROOF_DEPTH, CredentialError::InvalidAttributeProof);
+
+    let credential = &ctx.accounts.credential;
+    let clock = Clock::get()?;
+
+    // Attributes of an invalid credential prove nothing
+    require!(
+        credential.is_valid(clock.unix_timestamp),
+        CredentialError::CredentialNotActive
+    );
+    require!(
+        credential.commitment != [0u8; 32],
+        CredentialError::NoCommitment
+    );
+
+    require!(
+        verify_attribute_proof(&proof, &credential.commitment, attribute_leaf(&salt, &attribute)),
+        CredentialError::InvalidAttributeProof
+    );
+
+    msg!(
+        "Attribute proven for holder: {}",
+        credential.holder
+    );
+
+    Ok(())
+}
+
+/// Leaf of a committed attribute: sha256(0x00 || salt || attribute)
+pub fn attribute_leaf(salt: &[u8; 32], attribute: &[u8]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, salt, attribute]).to_bytes()
+}
+
+/// Verify a Merkle proof of a leaf against a commitment root
+/// Parent nodes are sha256(0x01 || min(a, b) || max(a, b)), so proofs need no
+/// left/right flags
+pub fn verify_attribute_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{CredentialIssuer, UserCredential, CredentialStatus};
+use anchor_lang::system_program;
+use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential, CredentialStatus};
 use crate::error::CredentialError;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct RefreshCredential<'info> {
@@ -8,6 +10,22 @@ pub struct RefreshCredential<'info> {
     pub issuer_authority: Signer<'info>,
 
     #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    /// Network treasury PDA - receives the credential fee
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        mut,
         seeds = [CredentialIssuer::SEED, issuer_authority.key().as_ref()],
         bump = issuer.bump,
         constraint = issuer.is_active @ CredentialError::IssuerNotActive,
@@ -21,11 +39,17 @@ pub struct RefreshCredential<'info> {
 
     #[account(
         mut,
-        seeds = [UserCredential::SEED, holder.key().as_ref()],
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
         bump = credential.bump,
         constraint = credential.issuer == issuer_authority.key() @ CredentialError::UnauthorizedIssuer,
     )]
     pub credential: Account<'info, UserCredential>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn refresh_credential(
@@ -41,6 +65,12 @@ pub fn refresh_credential(
         CredentialError::InvalidExpiry
     );
 
+    // Issuer must still be permitted to issue this type
+    require!(
+        ctx.accounts.issuer.can_issue(credential.credential_type),
+        CredentialError::CredentialTypeNotPermitted
+    );
+
     // Can only refresh Active or Expired credentials (not Revoked)
     require!(
         credential.status == CredentialStatus::Active ||
@@ -48,6 +78,28 @@ pub fn refresh_credential(
         CredentialError::CredentialRevoked
     );
 
+    // Collect the network credential fee from the issuer
+    let fee = ctx.accounts.network.credential_fee_lamports;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.issuer_authority.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+        ctx.accounts.network.total_fees_collected += fee;
+    }
+
+    // Expired credentials return to the active counts
+    if credential.status == CredentialStatus::Expired {
+        ctx.accounts.issuer.active_credentials += 1;
+        ctx.accounts.network.active_credentials += 1;
+    }
+
     // Update credential
     credential.status = CredentialStatus::Active;
     credential.expires_at = new_expiry_timestamp;
@@ -59,5 +111,15 @@ pub fn refresh_credential(
         new_expiry_timestamp
     );
 
+    emit!(CredentialRefreshed {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        expires_at: credential.expires_at,
+        fee_lamports: fee,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::{CredentialNetwork, CredentialIssuer};
 use crate::error::CredentialError;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct RegisterIssuer<'info> {
@@ -36,9 +37,14 @@ pub fn register_issuer(
     ctx: Context<RegisterIssuer>,
     issuer_name: String,
     issuer_uri: String,
+    permissions: u8,
 ) -> Result<()> {
     require!(issuer_name.len() <= 64, CredentialError::NameTooLong);
     require!(issuer_uri.len() <= 200, CredentialError::UriTooLong);
+    require!(
+        CredentialIssuer::is_valid_permissions(permissions),
+        CredentialError::InvalidPermissions
+    );
 
     let issuer = &mut ctx.accounts.issuer;
     let network = &mut ctx.accounts.network;
@@ -51,15 +57,29 @@ pub fn register_issuer(
     issuer.active_credentials = 0;
     issuer.revoked_credentials = 0;
     issuer.is_active = true;
-    issuer.can_issue_kyc = true;
-    issuer.can_issue_accredited = true;
+    issuer.set_permissions(permissions);
     issuer.registered_at = clock.unix_timestamp;
+    issuer.rotated_to = None;
     issuer.bump = ctx.bumps.issuer;
+    issuer.version = CredentialIssuer::CURRENT_VERSION;
 
     // Update network stats
     network.total_issuers += 1;
 
-    msg!("Issuer registered: {}", issuer.name);
+    msg!(
+        "Issuer registered: {} (permissions: {:#010b})",
+        issuer.name,
+        issuer.permissions
+    );
+
+    emit!(IssuerRegistered {
+        issuer: issuer.key(),
+        authority: issuer.authority,
+        name: issuer.name.clone(),
+        uri: issuer.uri.clone(),
+        permissions: issuer.permissions,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
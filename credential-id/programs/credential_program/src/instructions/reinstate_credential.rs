@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, UserCredential, CredentialStatus};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct ReinstateCredential<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    /// The wallet whose credential is being reinstated
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+        constraint = credential.status == CredentialStatus::Suspended @ CredentialError::CredentialNotSuspended,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn reinstate_credential(ctx: Context<ReinstateCredential>) -> Result<()> {
+    let clock = Clock::get()?;
+    let credential = &mut ctx.accounts.credential;
+    let network = &ctx.accounts.network;
+
+    // The original issuer or network admin can reinstate at any time;
+    // anyone can record the reinstatement once the suspension has lapsed
+    let is_issuer = credential.issuer == ctx.accounts.authority.key();
+    let is_admin = network.admin == ctx.accounts.authority.key();
+    let has_lapsed = !credential.is_suspended(clock.unix_timestamp);
+
+    require!(
+        is_issuer || is_admin || has_lapsed,
+        CredentialError::UnauthorizedIssuer
+    );
+
+    // Update credential (suspended credentials never left the active counts)
+    credential.status = CredentialStatus::Active;
+    credential.suspension_reason = String::new();
+    credential.suspended_until = 0;
+
+    msg!(
+        "Credential reinstated for holder: {}",
+        credential.holder
+    );
+
+    emit!(CredentialReinstated {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        reinstated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential, CredentialStatus};
 use crate::error::CredentialError;
+use crate::events::*;
 
 #[derive(Accounts)]
 pub struct RevokeCredential<'info> {
@@ -27,7 +28,11 @@ pub struct RevokeCredential<'info> {
 
     #[account(
         mut,
-        seeds = [UserCredential::SEED, holder.key().as_ref()],
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
         bump = credential.bump,
         constraint = credential.status == CredentialStatus::Active @ CredentialError::CredentialNotActive,
     )]
@@ -70,5 +75,15 @@ pub fn revoke_credential(
         credential.revocation_reason
     );
 
+    emit!(CredentialRevoked {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        revoked_by: ctx.accounts.authority.key(),
+        reason: credential.revocation_reason.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer, UserCredential, CredentialStatus};
+use crate::error::CredentialError;
+use crate::events::*;
+
+/// Cascade an issuer deactivation to its outstanding credentials
+/// Credentials are passed as writable remaining accounts, so large issuers
+/// are processed over several batched transactions
+#[derive(Accounts)]
+pub struct RevokeIssuerCredentials<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, issuer.authority.as_ref()],
+        bump = issuer.bump,
+        constraint = !issuer.is_active @ CredentialError::IssuerStillActive,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+}
+
+pub fn revoke_issuer_credentials<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevokeIssuerCredentials<'info>>,
+    reason: String,
+) -> Result<()> {
+    require!(reason.len() <= 200, CredentialError::ReasonTooLong);
+
+    let clock = Clock::get()?;
+    let admin = ctx.accounts.admin.key();
+    let issuer = &mut ctx.accounts.issuer;
+    let network = &mut ctx.accounts.network;
+    let mut revoked = 0u64;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let mut credential = Account::<UserCredential>::try_from(account_info)?;
+        require!(
+            credential.issuer == issuer.authority,
+            CredentialError::CredentialIssuerMismatch
+        );
+
+        // Already revoked credentials are skipped so batches can be retried
+        if credential.status == CredentialStatus::Revoked {
+            continue;
+        }
+
+        // Suspended credentials are still in the active counts; expired
+        // ones were removed by expire_credential
+        if credential.status != CredentialStatus::Expired {
+            issuer.active_credentials = issuer.active_credentials.saturating_sub(1);
+            network.active_credentials = network.active_credentials.saturating_sub(1);
+        }
+
+        credential.status = CredentialStatus::Revoked;
+        credential.revocation_reason = reason.clone();
+        credential.suspended_until = 0;
+        credential.suspension_reason = String::new();
+        credential.exit(&crate::ID)?;
+
+        emit!(CredentialRevoked {
+            credential: credential.key(),
+            holder: credential.holder,
+            issuer: credential.issuer,
+            credential_type: credential.credential_type,
+            revoked_by: admin,
+            reason: reason.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        issuer.revoked_credentials += 1;
+        revoked += 1;
+    }
+
+    msg!(
+        "Revoked {} credentials of deactivated issuer {}. Reason: {}",
+        revoked,
+        issuer.name,
+        reason
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer};
+use crate::error::CredentialError;
+use crate::events::*;
+
+/// Rotate an issuer to a new authority key
+/// Issuer PDAs are derived from the authority, so rotation creates a new
+/// issuer account and retires the old one
+#[derive(Accounts)]
+pub struct RotateIssuerAuthority<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, old_issuer.authority.as_ref()],
+        bump = old_issuer.bump,
+        constraint = old_issuer.rotated_to.is_none() @ CredentialError::IssuerNotActive,
+    )]
+    pub old_issuer: Account<'info, CredentialIssuer>,
+
+    /// The new authority that will control the issuer
+    /// CHECK: This is the issuer's new wallet address
+    pub new_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CredentialIssuer::INIT_SPACE,
+        seeds = [CredentialIssuer::SEED, new_authority.key().as_ref()],
+        bump,
+    )]
+    pub new_issuer: Account<'info, CredentialIssuer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn rotate_issuer_authority(ctx: Context<RotateIssuerAuthority>) -> Result<()> {
+    let old_issuer = &mut ctx.accounts.old_issuer;
+    let new_issuer = &mut ctx.accounts.new_issuer;
+    let clock = Clock::get()?;
+
+    // Credentials issued under the old key keep their stats on the old issuer
+    new_issuer.authority = ctx.accounts.new_authority.key();
+    new_issuer.name = old_issuer.name.clone();
+    new_issuer.uri = old_issuer.uri.clone();
+    new_issuer.credentials_issued = 0;
+    new_issuer.active_credentials = 0;
+    new_issuer.revoked_credentials = 0;
+    new_issuer.is_active = old_issuer.is_active;
+    new_issuer.set_permissions(old_issuer.permissions);
+    new_issuer.registered_at = clock.unix_timestamp;
+    new_issuer.rotated_to = None;
+    new_issuer.bump = ctx.bumps.new_issuer;
+    new_issuer.version = CredentialIssuer::CURRENT_VERSION;
+
+    old_issuer.is_active = false;
+    old_issuer.rotated_to = Some(new_issuer.authority);
+
+    msg!(
+        "Issuer {} rotated from {} to {}",
+        new_issuer.name,
+        old_issuer.authority,
+        new_issuer.authority
+    );
+
+    emit!(IssuerAuthorityRotated {
+        old_issuer: old_issuer.key(),
+        new_issuer: new_issuer.key(),
+        old_authority: old_issuer.authority,
+        new_authority: new_issuer.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
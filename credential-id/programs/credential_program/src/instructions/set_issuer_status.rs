@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetIssuerStatus<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, issuer.authority.as_ref()],
+        bump = issuer.bump,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+}
+
+pub fn set_issuer_status(
+    ctx: Context<SetIssuerStatus>,
+    is_active: bool,
+) -> Result<()> {
+    let issuer = &mut ctx.accounts.issuer;
+
+    // A rotated issuer stays retired; its successor holds the authority
+    require!(
+        !is_active || issuer.rotated_to.is_none(),
+        CredentialError::IssuerNotAuthorized
+    );
+
+    issuer.is_active = is_active;
+
+    msg!(
+        "Issuer {} {}",
+        issuer.name,
+        if is_active { "activated" } else { "deactivated" }
+    );
+
+    emit!(IssuerUpdated {
+        issuer: issuer.key(),
+        authority: issuer.authority,
+        name: issuer.name.clone(),
+        uri: issuer.uri.clone(),
+        permissions: issuer.permissions,
+        is_active: issuer.is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::CredentialNetwork;
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SetNetworkStatus<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+}
+
+pub fn set_network_status(
+    ctx: Context<SetNetworkStatus>,
+    is_active: bool,
+) -> Result<()> {
+    let network = &mut ctx.accounts.network;
+    network.is_active = is_active;
+
+    msg!(
+        "Credential Network {} {}",
+        network.name,
+        if is_active { "activated" } else { "deactivated" }
+    );
+
+    emit!(NetworkStatusChanged {
+        network: network.key(),
+        is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, UserCredential, CredentialStatus};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct SuspendCredential<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    /// The wallet whose credential is being suspended
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+        constraint = credential.status == CredentialStatus::Active @ CredentialError::CredentialNotActive,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn suspend_credential(
+    ctx: Context<SuspendCredential>,
+    reason: String,
+    suspended_until: i64,
+) -> Result<()> {
+    require!(reason.len() <= 200, CredentialError::ReasonTooLong);
+
+    let clock = Clock::get()?;
+
+    // Automatic reinstatement time must be in the future (or 0 for none)
+    require!(
+        suspended_until == 0 || suspended_until > clock.unix_timestamp,
+        CredentialError::InvalidSuspensionPeriod
+    );
+
+    let credential = &mut ctx.accounts.credential;
+    let network = &ctx.accounts.network;
+
+    // Only the original issuer or network admin can suspend
+    let is_issuer = credential.issuer == ctx.accounts.authority.key();
+    let is_admin = network.admin == ctx.accounts.authority.key();
+
+    require!(
+        is_issuer || is_admin,
+        CredentialError::UnauthorizedIssuer
+    );
+
+    // Update credential
+    // Suspensions are temporary (and may lapse on their own), so the
+    // credential stays in the active counts until revoked or expired
+    credential.status = CredentialStatus::Suspended;
+    credential.suspension_reason = reason;
+    credential.suspended_until = suspended_until;
+
+    msg!(
+        "Credential suspended for holder: {} until {}. Reason: {}",
+        credential.holder,
+        suspended_until,
+        credential.suspension_reason
+    );
+
+    emit!(CredentialSuspended {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        suspended_by: ctx.accounts.authority.key(),
+        reason: credential.suspension_reason.clone(),
+        suspended_until,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialAttributes, CredentialIssuer, CredentialStatus, UserCredential};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateCredentialAttributes<'info> {
+    pub issuer_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialIssuer::SEED, issuer_authority.key().as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.is_active @ CredentialError::IssuerNotActive,
+        constraint = issuer.authority == issuer_authority.key() @ CredentialError::UnauthorizedIssuer,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+
+    /// The wallet whose credential is being updated
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+        constraint = credential.issuer == issuer_authority.key() @ CredentialError::UnauthorizedIssuer,
+        constraint = credential.status != CredentialStatus::Revoked @ CredentialError::CredentialRevoked,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn update_credential_attributes(
+    ctx: Context<UpdateCredentialAttributes>,
+    attributes: CredentialAttributes,
+) -> Result<()> {
+    require!(attributes.is_valid(), CredentialError::InvalidCountryCode);
+
+    let clock = Clock::get()?;
+    let credential = &mut ctx.accounts.credential;
+
+    credential.attributes = attributes;
+    credential.last_verified_at = clock.unix_timestamp;
+
+    msg!(
+        "Credential attributes updated for holder: {}",
+        credential.holder
+    );
+
+    emit!(CredentialAttributesUpdated {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        attributes,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialIssuer, CredentialStatus, UserCredential};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateCredentialCommitment<'info> {
+    pub issuer_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialIssuer::SEED, issuer_authority.key().as_ref()],
+        bump = issuer.bump,
+        constraint = issuer.is_active @ CredentialError::IssuerNotActive,
+        constraint = issuer.authority == issuer_authority.key() @ CredentialError::UnauthorizedIssuer,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+
+    /// The wallet whose credential is being updated
+    /// CHECK: This is just the holder address for PDA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
+        bump = credential.bump,
+        constraint = credential.issuer == issuer_authority.key() @ CredentialError::UnauthorizedIssuer,
+        constraint = credential.status != CredentialStatus::Revoked @ CredentialError::CredentialRevoked,
+    )]
+    pub credential: Account<'info, UserCredential>,
+}
+
+pub fn update_credential_commitment(
+    ctx: Context<UpdateCredentialCommitment>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let credential = &mut ctx.accounts.credential;
+
+    credential.commitment = commitment;
+    credential.last_verified_at = clock.unix_timestamp;
+
+    msg!(
+        "Credential commitment updated for holder: {}",
+        credential.holder
+    );
+
+    emit!(CredentialCommitmentUpdated {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        commitment,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateIssuer<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, issuer.authority.as_ref()],
+        bump = issuer.bump,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+}
+
+pub fn update_issuer(
+    ctx: Context<UpdateIssuer>,
+    issuer_name: Option<String>,
+    issuer_uri: Option<String>,
+) -> Result<()> {
+    let issuer = &mut ctx.accounts.issuer;
+
+    if let Some(issuer_name) = issuer_name {
+        require!(issuer_name.len() <= 64, CredentialError::NameTooLong);
+        issuer.name = issuer_name;
+    }
+
+    if let Some(issuer_uri) = issuer_uri {
+        require!(issuer_uri.len() <= 200, CredentialError::UriTooLong);
+        issuer.uri = issuer_uri;
+    }
+
+    msg!("Issuer updated: {} ({})", issuer.name, issuer.uri);
+
+    emit!(IssuerUpdated {
+        issuer: issuer.key(),
+        authority: issuer.authority,
+        name: issuer.name.clone(),
+        uri: issuer.uri.clone(),
+        permissions: issuer.permissions,
+        is_active: issuer.is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
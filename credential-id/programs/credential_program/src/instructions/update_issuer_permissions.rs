@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{CredentialNetwork, CredentialIssuer};
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateIssuerPermissions<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialIssuer::SEED, issuer.authority.as_ref()],
+        bump = issuer.bump,
+    )]
+    pub issuer: Account<'info, CredentialIssuer>,
+}
+
+pub fn update_issuer_permissions(
+    ctx: Context<UpdateIssuerPermissions>,
+    permissions: u8,
+) -> Result<()> {
+    require!(
+        CredentialIssuer::is_valid_permissions(permissions),
+        CredentialError::InvalidPermissions
+    );
+
+    let issuer = &mut ctx.accounts.issuer;
+    issuer.set_permissions(permissions);
+
+    msg!(
+        "Issuer permissions updated: {} (permissions: {:#010b})",
+        issuer.name,
+        issuer.permissions
+    );
+
+    emit!(IssuerUpdated {
+        issuer: issuer.key(),
+        authority: issuer.authority,
+        name: issuer.name.clone(),
+        uri: issuer.uri.clone(),
+        permissions: issuer.permissions,
+        is_active: issuer.is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::CredentialNetwork;
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct UpdateNetworkConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+}
+
+pub fn update_network_config(
+    ctx: Context<UpdateNetworkConfig>,
+    network_name: Option<String>,
+    credential_fee_lamports: Option<u64>,
+) -> Result<()> {
+    let network = &mut ctx.accounts.network;
+
+    if let Some(network_name) = network_name {
+        require!(network_name.len() <= 64, CredentialError::NameTooLong);
+        network.name = network_name;
+    }
+
+    if let Some(credential_fee_lamports) = credential_fee_lamports {
+        network.credential_fee_lamports = credential_fee_lamports;
+    }
+
+    msg!(
+        "Network config updated: {} (fee: {} lamports)",
+        network.name,
+        network.credential_fee_lamports
+    );
+
+    emit!(NetworkConfigUpdated {
+        network: network.key(),
+        name: network.name.clone(),
+        credential_fee_lamports: network.credential_fee_lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
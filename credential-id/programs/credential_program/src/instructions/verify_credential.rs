@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserCredential, CredentialStatus};
+use crate::state::{UserCredential, CredentialAttributes, CredentialType, CredentialStatus};
 use crate::error::CredentialError;
 
 /// Verify a credential - used by Transfer Hook
@@ -11,19 +11,55 @@ pub struct VerifyCredential<'info> {
     pub holder: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [UserCredential::SEED, holder.key().as_ref()],
+        seeds = [
+            UserCredential::SEED,
+            holder.key().as_ref(),
+            &[credential.credential_type as u8],
+        ],
         bump = credential.bump,
     )]
     pub credential: Account<'info, UserCredential>,
 }
 
-pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<()> {
+/// Structured result of `verify_credential`, set as Solana return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CredentialVerification {
+    /// The verified credential account
+    pub credential: Pubkey,
+
+    /// The wallet the credential belongs to
+    pub holder: Pubkey,
+
+    /// The issuer authority who issued the credential
+    pub issuer: Pubkey,
+
+    /// Type of credential
+    pub credential_type: CredentialType,
+
+    /// Stored status (Suspended if a lapsed suspension was not yet reinstated)
+    pub status: CredentialStatus,
+
+    /// When the credential was issued
+    pub issued_at: i64,
+
+    /// When the credential expires (0 = never)
+    pub expires_at: i64,
+
+    /// Compliance attributes (jurisdiction, tier, risk)
+    pub attributes: CredentialAttributes,
+}
+
+pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialVerification> {
     let credential = &ctx.accounts.credential;
     let clock = Clock::get()?;
 
-    // Check if credential is valid
+    // Check if credential is valid (a lapsed suspension counts as reinstated)
+    require!(
+        !credential.is_suspended(clock.unix_timestamp),
+        CredentialError::CredentialSuspended
+    );
     require!(
-        credential.status == CredentialStatus::Active,
+        credential.is_active(clock.unix_timestamp),
         CredentialError::CredentialNotActive
     );
 
@@ -37,11 +73,37 @@ pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<()> {
         credential.holder
     );
 
-    Ok(())
+    Ok(CredentialVerification {
+        credential: credential.key(),
+        holder: credential.holder,
+        issuer: credential.issuer,
+        credential_type: credential.credential_type,
+        status: credential.status,
+        issued_at: credential.issued_at,
+        expires_at: credential.expires_at,
+        attributes: credential.attributes,
+    })
 }
 
-/// Helper function for CPI calls from other programs (like Transfer Hook)
+/// Helper function for programs that already hold the deserialized credential
 /// Returns true if credential is valid, false otherwise
 pub fn is_credential_valid(credential: &UserCredential, current_timestamp: i64) -> bool {
     credential.is_valid(current_timestamp)
 }
+
+/// Verify a credential from another program via CPI (enable the `cpi` feature)
+/// Fails with the credential program's error if the credential is not valid,
+/// otherwise returns the structured result read from return data
+#[cfg(feature = "cpi")]
+pub fn verify_credential_cpi<'info>(
+    credential_program: AccountInfo<'info>,
+    holder: AccountInfo<'info>,
+    credential: AccountInfo<'info>,
+) -> Result<CredentialVerification> {
+    let result = crate::cpi::verify_credential(CpiContext::new(
+        credential_program,
+        crate::cpi::accounts::VerifyCredential { holder, credential },
+    ))?;
+
+    Ok(result.get())
+}
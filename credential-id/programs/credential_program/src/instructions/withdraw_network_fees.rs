@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::CredentialNetwork;
+use crate::error::CredentialError;
+use crate::events::*;
+
+#[derive(Accounts)]
+pub struct WithdrawNetworkFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CredentialNetwork::SEED],
+        bump = network.bump,
+        constraint = network.admin == admin.key() @ CredentialError::UnauthorizedAdmin,
+    )]
+    pub network: Account<'info, CredentialNetwork>,
+
+    #[account(
+        mut,
+        seeds = [CredentialNetwork::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Wallet receiving the withdrawn fees
+    /// CHECK: Any destination chosen by the admin
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_network_fees(
+    ctx: Context<WithdrawNetworkFees>,
+    amount: u64,
+) -> Result<()> {
+    // The treasury keeps its rent-exempt minimum
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let available = ctx
+        .accounts
+        .treasury
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
+    require!(
+        amount > 0 && amount <= available,
+        CredentialError::InsufficientTreasuryBalance
+    );
+
+    let treasury_bump = ctx.bumps.treasury;
+    let signer_seeds: &[&[&[u8]]] = &[&[CredentialNetwork::TREASURY_SEED, &[treasury_bump]]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    msg!(
+        "Network fees withdrawn: {} lamports to {}",
+        amount,
+        ctx.accounts.recipient.key()
+    );
+
+    emit!(NetworkFeesWithdrawn {
+        network: ctx.accounts.network.key(),
+        treasury: ctx.accounts.treasury.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::CredentialAttributes;
 
 declare_id!("FaJ4XGCLeu7eZiMjBEkANko3TRhpjns3cv6R1vZK94Wt");
 
@@ -19,6 +21,8 @@ declare_id!("FaJ4XGCLeu7eZiMjBEkANko3TRhpjns3cv6R1vZK94Wt");
 /// - Issuer authorization system
 /// - Credential revocation
 /// - Integration with Transfer Hooks
+/// - Anchor events for indexers
+/// - Selective disclosure of committed (hashed) KYC attributes
 #[program]
 pub mod credential_program {
     use super::*;
@@ -35,28 +39,41 @@ pub mod credential_program {
 
     /// Register a new credential issuer
     /// Issuers can issue credentials to users after KYC verification
+    /// `permissions` is a bitmap of the credential types the issuer may issue
     pub fn register_issuer(
         ctx: Context<RegisterIssuer>,
         issuer_name: String,
         issuer_uri: String,
+        permissions: u8,
     ) -> Result<()> {
-        instructions::register_issuer(ctx, issuer_name, issuer_uri)
+        instructions::register_issuer(ctx, issuer_name, issuer_uri, permissions)
     }
 
     /// Issue a credential to a wallet
     /// Called by authorized issuers after off-chain KYC verification
+    /// The issuer pays the network credential fee into the treasury
     pub fn issue_credential(
         ctx: Context<IssueCredential>,
         credential_type: u8,
         expiry_timestamp: i64,
         metadata_uri: String,
+        attributes: CredentialAttributes,
+        commitment: [u8; 32],
     ) -> Result<()> {
-        instructions::issue_credential(ctx, credential_type, expiry_timestamp, metadata_uri)
+        instructions::issue_credential(
+            ctx,
+            credential_type,
+            expiry_timestamp,
+            metadata_uri,
+            attributes,
+            commitment,
+        )
     }
 
     /// Verify a credential on-chain
-    /// Returns the credential status for Transfer Hook integration
-    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<()> {
+    /// Fails if the credential is not valid; otherwise sets a `CredentialVerification`
+    /// as return data for CPI consumers (see `verify_credential_cpi`)
+    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialVerification> {
         instructions::verify_credential(ctx)
     }
 
@@ -71,10 +88,181 @@ pub mod credential_program {
 
     /// Refresh/renew a credential
     /// Extends expiry without full re-verification
+    /// The issuer pays the network credential fee into the treasury
     pub fn refresh_credential(
         ctx: Context<RefreshCredential>,
         new_expiry_timestamp: i64,
     ) -> Result<()> {
         instructions::refresh_credential(ctx, new_expiry_timestamp)
     }
+
+    /// Update the compliance attributes of a credential
+    /// Only callable by the issuer of the credential
+    pub fn update_credential_attributes(
+        ctx: Context<UpdateCredentialAttributes>,
+        attributes: CredentialAttributes,
+    ) -> Result<()> {
+        instructions::update_credential_attributes(ctx, attributes)
+    }
+
+    /// Replace the commitment to a credential's off-chain KYC attributes
+    /// Only callable by the issuer of the credential
+    pub fn update_credential_commitment(
+        ctx: Context<UpdateCredentialCommitment>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::update_credential_commitment(ctx, commitment)
+    }
+
+    /// Prove a single committed attribute (e.g. "age>=18", "country=BR")
+    /// Verifies a Merkle proof of `sha256(0x00 || salt || attribute)` against
+    /// the credential commitment without revealing the rest of the record
+    /// Fails if the proof is invalid or the credential is not valid
+    pub fn prove_attribute(
+        ctx: Context<ProveAttribute>,
+        attribute: Vec<u8>,
+        salt: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::prove_attribute(ctx, attribute, salt, proof)
+    }
+
+    /// Update the credential types an issuer may issue
+    /// Only callable by the network admin
+    pub fn update_issuer_permissions(
+        ctx: Context<UpdateIssuerPermissions>,
+        permissions: u8,
+    ) -> Result<()> {
+        instructions::update_issuer_permissions(ctx, permissions)
+    }
+
+    /// Temporarily suspend a credential (e.g. during a compliance investigation)
+    /// Can be called by issuer or network admin
+    /// `suspended_until` reinstates automatically at that time (0 = until reinstated)
+    pub fn suspend_credential(
+        ctx: Context<SuspendCredential>,
+        reason: String,
+        suspended_until: i64,
+    ) -> Result<()> {
+        instructions::suspend_credential(ctx, reason, suspended_until)
+    }
+
+    /// Reinstate a suspended credential
+    /// Can be called by issuer or network admin, or by anyone once the suspension has lapsed
+    pub fn reinstate_credential(ctx: Context<ReinstateCredential>) -> Result<()> {
+        instructions::reinstate_credential(ctx)
+    }
+
+    /// Mark a credential past its expiry as Expired
+    /// Permissionless - anyone can record the expiry
+    pub fn expire_credential(ctx: Context<ExpireCredential>) -> Result<()> {
+        instructions::expire_credential(ctx)
+    }
+
+    /// Withdraw collected credential fees from the network treasury
+    /// Only callable by the network admin
+    pub fn withdraw_network_fees(
+        ctx: Context<WithdrawNetworkFees>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_network_fees(ctx, amount)
+    }
+
+    /// Update the network name and/or credential fee
+    /// Only callable by the network admin
+    pub fn update_network_config(
+        ctx: Context<UpdateNetworkConfig>,
+        network_name: Option<String>,
+        credential_fee_lamports: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_network_config(ctx, network_name, credential_fee_lamports)
+    }
+
+    /// Activate or deactivate the credential network
+    /// A deactivated network stops issuing credentials and registering issuers
+    /// Only callable by the network admin
+    pub fn set_network_status(
+        ctx: Context<SetNetworkStatus>,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::set_network_status(ctx, is_active)
+    }
+
+    /// Propose a new network admin (step 1 of 2)
+    /// Pass `None` to cancel a pending proposal
+    /// Only callable by the network admin
+    pub fn propose_network_admin(
+        ctx: Context<ProposeNetworkAdmin>,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_network_admin(ctx, new_admin)
+    }
+
+    /// Accept the network admin role (step 2 of 2)
+    /// Only callable by the proposed admin
+    pub fn accept_network_admin(ctx: Context<AcceptNetworkAdmin>) -> Result<()> {
+        instructions::accept_network_admin(ctx)
+    }
+
+    /// Update an issuer's name and/or URI
+    /// Only callable by the network admin
+    pub fn update_issuer(
+        ctx: Context<UpdateIssuer>,
+        issuer_name: Option<String>,
+        issuer_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::update_issuer(ctx, issuer_name, issuer_uri)
+    }
+
+    /// Activate or deactivate an issuer (e.g. a compromised issuer)
+    /// Deactivation only stops new issuance: consumers such as the hub's
+    /// transfer hook read credentials without the issuer account, so the
+    /// issuer's credentials stay valid until revoked with revoke_issuer_credentials
+    /// Only callable by the network admin
+    pub fn set_issuer_status(
+        ctx: Context<SetIssuerStatus>,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::set_issuer_status(ctx, is_active)
+    }
+
+    /// Move an issuer to a new authority key
+    /// Creates the issuer account for the new key and retires the old one
+    /// Only callable by the network admin
+    pub fn rotate_issuer_authority(ctx: Context<RotateIssuerAuthority>) -> Result<()> {
+        instructions::rotate_issuer_authority(ctx)
+    }
+
+    /// Revoke a batch of credentials of a deactivated issuer
+    /// Must be run over all of its credentials after set_issuer_status(false);
+    /// not for a rotated issuer, whose credentials remain valid
+    /// Credentials are passed as writable remaining accounts
+    /// Only callable by the network admin
+    pub fn revoke_issuer_credentials<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevokeIssuerCredentials<'info>>,
+        reason: String,
+    ) -> Result<()> {
+        instructions::revoke_issuer_credentials(ctx, reason)
+    }
+
+    /// Move a first-release credential from ["credential", holder] to
+    /// ["credential", holder, credential_type] and close the legacy account
+    /// Permissionless - the payer funds the new account and receives the old rent
+    pub fn migrate_credential(ctx: Context<MigrateCredential>, credential_type: u8) -> Result<()> {
+        instructions::migrate_credential(ctx, credential_type)
+    }
+
+    /// Grow the network created by an earlier program version to the current layout
+    /// and fund the fee treasury to rent exemption
+    /// Permissionless - the payer funds the extra rent
+    pub fn migrate_network(ctx: Context<MigrateNetwork>) -> Result<()> {
+        instructions::migrate_network(ctx)
+    }
+
+    /// Grow an issuer created by an earlier program version to the current layout
+    /// Permissions are derived from the first-release capability flags
+    /// Permissionless - the payer funds the extra rent
+    pub fn migrate_issuer(ctx: Context<MigrateIssuer>) -> Result<()> {
+        instructions::migrate_issuer(ctx)
+    }
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/// Investor classification (CVM 30 categories)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, InitSpace)]
+pub enum InvestorTier {
+    /// Retail investor (investidor de varejo)
+    Retail = 0,
+    /// Qualified investor (investidor qualificado)
+    Qualified = 1,
+    /// Professional investor (investidor profissional)
+    Professional = 2,
+    /// Institutional investor (funds, banks, insurers)
+    Institutional = 3,
+}
+
+/// AML risk score band assigned by the issuer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, InitSpace)]
+pub enum RiskBand {
+    Low = 0,
+    Medium = 1,
+    High = 2,
+}
+
+/// Structured compliance attributes attached to a credential
+/// Set by the issuer; read by consumers to restrict by jurisdiction or risk level
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct CredentialAttributes {
+    /// ISO 3166-1 alpha-2 country of residence (e.g. b"BR")
+    pub country_of_residence: [u8; 2],
+
+    /// ISO 3166-1 alpha-2 country of tax residency
+    pub tax_residency: [u8; 2],
+
+    /// Investor classification
+    pub investor_tier: InvestorTier,
+
+    /// AML risk score band
+    pub risk_band: RiskBand,
+
+    /// Politically exposed person
+    pub is_pep: bool,
+
+    /// Self-declared investment limit (in lamports, None = not declared)
+    pub investment_limit: Option<u64>,
+}
+
+impl CredentialAttributes {
+    /// Check that both country codes are ISO 3166-1 alpha-2 shaped
+    pub fn is_valid(&self) -> bool {
+        is_country_code(&self.country_of_residence) && is_country_code(&self.tax_residency)
+    }
+}
+
+/// Two uppercase ASCII letters
+pub fn is_country_code(code: &[u8; 2]) -> bool {
+    code.iter().all(u8::is_ascii_uppercase)
+}
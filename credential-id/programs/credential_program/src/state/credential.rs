@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use super::CredentialAttributes;
+
 /// Credential Types for Real Estate Tokenization
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
 pub enum CredentialType {
     /// Basic KYC - Identity verified
     KycBasic = 0,
@@ -17,8 +19,29 @@ pub enum CredentialType {
     BrazilianCnpj = 5,
 }
 
+impl CredentialType {
+    /// Convert the on-wire credential type byte
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(CredentialType::KycBasic),
+            1 => Some(CredentialType::KycFull),
+            2 => Some(CredentialType::AccreditedInvestor),
+            3 => Some(CredentialType::QualifiedPurchaser),
+            4 => Some(CredentialType::BrazilianCpf),
+            5 => Some(CredentialType::BrazilianCnpj),
+            _ => None,
+        }
+    }
+
+    /// Bit for this type in credential type masks
+    /// (`CredentialIssuer::permissions`, consumer acceptance policies)
+    pub fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
 /// Credential Status
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
 pub enum CredentialStatus {
     /// Credential is active and valid
     Active = 0,
@@ -32,6 +55,8 @@ pub enum CredentialStatus {
 
 /// User Credential Account
 /// Stores the on-chain credential for a wallet
+/// A wallet holds at most one credential per `CredentialType`
+/// Seeds: ["credential", holder, credential_type]
 /// This is what the Transfer Hook checks
 #[account]
 #[derive(InitSpace)]
@@ -66,32 +91,80 @@ pub struct UserCredential {
     pub revocation_reason: String,
 
     /// Credential version (for upgrades)
+    /// 1 = first release; fields below are zero until migrated
     pub version: u8,
 
     /// Bump for PDA derivation
     pub bump: u8,
+
+    // Fields below were appended after the first release
+
+    /// When a suspension lapses automatically (0 = until reinstated)
+    pub suspended_until: i64,
+
+    /// Suspension reason (if suspended)
+    #[max_len(200)]
+    pub suspension_reason: String,
+
+    /// Structured compliance attributes (jurisdiction, tier, risk)
+    /// All zero on first-release credentials until set with update_credential_attributes
+    pub attributes: CredentialAttributes,
+
+    /// Merkle root of the salted off-chain KYC attributes (all zero = none)
+    /// Individual attributes are disclosed with `prove_attribute`
+    pub commitment: [u8; 32],
 }
 
 impl UserCredential {
     pub const SEED: &'static [u8] = b"credential";
 
+    /// Layout version written by issue_credential
+    pub const CURRENT_VERSION: u8 = 2;
+
+    /// Derive the credential PDA for a holder and credential type
+    /// Seeds: ["credential", holder, credential_type]
+    pub fn find_address(holder: &Pubkey, credential_type: CredentialType) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, holder.as_ref(), &[credential_type as u8]],
+            &crate::ID,
+        )
+    }
+
     /// Check if credential is valid for transfers
     pub fn is_valid(&self, current_timestamp: i64) -> bool {
-        // Must be active
-        if self.status != CredentialStatus::Active {
-            return false;
-        }
+        self.effective_status(current_timestamp) == CredentialStatus::Active
+    }
 
-        // Check expiry (0 means never expires)
-        if self.expires_at > 0 && current_timestamp > self.expires_at {
-            return false;
+    /// Status as of `current_timestamp`
+    /// A lapsed suspension reads as reinstated and a passed expiry as Expired
+    /// (`expires_at` of 0 means never expires)
+    pub fn effective_status(&self, current_timestamp: i64) -> CredentialStatus {
+        if !self.is_active(current_timestamp) {
+            return self.status;
         }
 
-        true
+        if self.is_expired(current_timestamp) {
+            CredentialStatus::Expired
+        } else {
+            CredentialStatus::Active
+        }
     }
 
     /// Check if credential is expired
     pub fn is_expired(&self, current_timestamp: i64) -> bool {
         self.expires_at > 0 && current_timestamp > self.expires_at
     }
+
+    /// Check if credential is currently suspended
+    /// A suspension with `suspended_until` set lapses at that time
+    pub fn is_suspended(&self, current_timestamp: i64) -> bool {
+        self.status == CredentialStatus::Suspended
+            && (self.suspended_until == 0 || current_timestamp < self.suspended_until)
+    }
+
+    /// Check if credential is active, treating a lapsed suspension as reinstated
+    pub fn is_active(&self, current_timestamp: i64) -> bool {
+        self.status == CredentialStatus::Active
+            || (self.status == CredentialStatus::Suspended && !self.is_suspended(current_timestamp))
+    }
 }
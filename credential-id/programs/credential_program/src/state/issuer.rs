@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use super::CredentialType;
 
 /// Credential Issuer account
 /// Represents an authorized entity that can issue credentials
@@ -28,10 +29,11 @@ pub struct CredentialIssuer {
     /// Is this issuer active/authorized
     pub is_active: bool,
 
-    /// Can issue KYC credentials
+    /// First-release capability flags, kept for layout compatibility
+    /// Mirrors `permissions` (KYC and Brazilian document types)
     pub can_issue_kyc: bool,
 
-    /// Can issue Accredited Investor credentials
+    /// Mirrors `permissions` (accredited investor and qualified purchaser types)
     pub can_issue_accredited: bool,
 
     /// Registration timestamp
@@ -39,8 +41,62 @@ pub struct CredentialIssuer {
 
     /// Bump for PDA derivation
     pub bump: u8,
+
+    // Fields below were appended after the first release; accounts created
+    // earlier read them as zero until migrated by migrate_issuer
+
+    /// Layout version (0 = created before the appended fields)
+    pub version: u8,
+
+    /// Credential types this issuer may issue and refresh
+    /// Bit N set = may issue `CredentialType` N (see `CredentialType::bit`)
+    pub permissions: u8,
+
+    /// New authority if this issuer's key was rotated (this issuer is then inactive)
+    pub rotated_to: Option<Pubkey>,
 }
 
 impl CredentialIssuer {
     pub const SEED: &'static [u8] = b"issuer";
+
+    /// Layout version written by register_issuer and migrate_issuer
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Permission bits covering every `CredentialType`
+    pub const ALL_PERMISSIONS: u8 = 0b0011_1111;
+
+    /// Types covered by `can_issue_kyc`: KycBasic, KycFull, BrazilianCpf, BrazilianCnpj
+    pub const KYC_PERMISSIONS: u8 = 0b0011_0011;
+
+    /// Types covered by `can_issue_accredited`: AccreditedInvestor, QualifiedPurchaser
+    pub const ACCREDITED_PERMISSIONS: u8 = 0b0000_1100;
+
+    /// Check a permission bitmap only names known credential types
+    pub fn is_valid_permissions(permissions: u8) -> bool {
+        permissions & !Self::ALL_PERMISSIONS == 0
+    }
+
+    /// Permissions granted by the first-release capability flags
+    pub fn legacy_permissions(&self) -> u8 {
+        let mut permissions = 0;
+        if self.can_issue_kyc {
+            permissions |= Self::KYC_PERMISSIONS;
+        }
+        if self.can_issue_accredited {
+            permissions |= Self::ACCREDITED_PERMISSIONS;
+        }
+        permissions
+    }
+
+    /// Set the permission bitmap, keeping the first-release flags in sync
+    pub fn set_permissions(&mut self, permissions: u8) {
+        self.permissions = permissions;
+        self.can_issue_kyc = permissions & Self::KYC_PERMISSIONS != 0;
+        self.can_issue_accredited = permissions & Self::ACCREDITED_PERMISSIONS != 0;
+    }
+
+    /// Check if this issuer may issue the given credential type
+    pub fn can_issue(&self, credential_type: CredentialType) -> bool {
+        self.permissions & credential_type.bit() != 0
+    }
 }
@@ -1,7 +1,9 @@
+pub mod attributes;
 pub mod credential;
 pub mod issuer;
 pub mod network;
 
+pub use attributes::*;
 pub use credential::*;
 pub use issuer::*;
 pub use network::*;
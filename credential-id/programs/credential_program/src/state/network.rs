@@ -12,7 +12,8 @@ pub struct CredentialNetwork {
     #[max_len(64)]
     pub name: String,
 
-    /// Fee for issuing credentials (in lamports)
+    /// Fee for issuing and refreshing credentials (in lamports)
+    /// Paid by the issuer into the network treasury PDA
     pub credential_fee_lamports: u64,
 
     /// Total credentials issued
@@ -32,8 +33,26 @@ pub struct CredentialNetwork {
 
     /// Bump for PDA derivation
     pub bump: u8,
+
+    // Fields below were appended after the first release; accounts created
+    // earlier read them as zero until migrated by migrate_network
+
+    /// Layout version (0 = created before the appended fields)
+    pub version: u8,
+
+    /// Total fees collected into the network treasury (in lamports)
+    pub total_fees_collected: u64,
+
+    /// Proposed new admin (must accept to complete the transfer)
+    pub pending_admin: Option<Pubkey>,
 }
 
 impl CredentialNetwork {
     pub const SEED: &'static [u8] = b"credential_network";
+
+    /// Layout version written by initialize_network and migrate_network
+    pub const CURRENT_VERSION: u8 = 1;
+
+    /// Seed for the network treasury PDA (holds collected fees)
+    pub const TREASURY_SEED: &'static [u8] = b"network_treasury";
 }
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { expect } from "chai";
+import { createHash, randomBytes } from "crypto";
 
 // Import the IDL
 const IDL = require("../target/idl/credential_program.json");
@@ -21,9 +22,36 @@ describe("credential_program", () => {
 
   // PDAs
   let networkPda: PublicKey;
+  let treasuryPda: PublicKey;
   let issuerPda: PublicKey;
   let credentialPda: PublicKey;
 
+  // Compliance attributes set at issuance
+  const attributes = {
+    countryOfResidence: Array.from(Buffer.from("BR")),
+    taxResidency: Array.from(Buffer.from("BR")),
+    investorTier: { retail: {} },
+    riskBand: { low: {} },
+    isPep: false,
+    investmentLimit: null,
+  };
+
+  // Salted KYC attributes committed at issuance (kept off-chain by the holder)
+  const committedAttributes = ["country=BR", "age>=18", "kyc_level=basic"].map((attribute) => ({
+    attribute: Buffer.from(attribute),
+    salt: randomBytes(32),
+  }));
+
+  const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+  const attributeLeaf = (salt: Buffer, attribute: Buffer) =>
+    sha256(Buffer.from([0x00]), salt, attribute);
+  const hashNode = (a: Buffer, b: Buffer) =>
+    Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([0x01]), a, b) : sha256(Buffer.from([0x01]), b, a);
+
+  // Three leaves: root = node(node(leaf0, leaf1), leaf2)
+  const leaves = committedAttributes.map(({ salt, attribute }) => attributeLeaf(salt, attribute));
+  const commitment = hashNode(hashNode(leaves[0], leaves[1]), leaves[2]);
+
   before(async () => {
     // Airdrop SOL to test accounts
     const airdropTx1 = await provider.connection.requestAirdrop(
@@ -44,13 +72,19 @@ describe("credential_program", () => {
       programId
     );
 
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("network_treasury")],
+      programId
+    );
+
     [issuerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("issuer"), issuerAuthority.publicKey.toBuffer()],
       programId
     );
 
+    // One credential per holder and type; these tests use KycBasic (0)
     [credentialPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("credential"), holder.publicKey.toBuffer()],
+      [Buffer.from("credential"), holder.publicKey.toBuffer(), Buffer.from([0])],
       programId
     );
 
@@ -74,6 +108,7 @@ describe("credential_program", () => {
         .accounts({
           admin: admin.publicKey,
           network: networkPda,
+          treasury: treasuryPda,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -101,9 +136,10 @@ describe("credential_program", () => {
     it("should register a new credential issuer", async () => {
       const issuerName = "Hub KYC Provider";
       const issuerUri = "https://kyc.hub.com";
+      const permissions = 0b0011_1111; // All credential types
 
       const tx = await program.methods
-        .registerIssuer(issuerName, issuerUri)
+        .registerIssuer(issuerName, issuerUri, permissions)
         .accounts({
           admin: admin.publicKey,
           network: networkPda,
@@ -124,8 +160,7 @@ describe("credential_program", () => {
       expect(issuerAccount.credentialsIssued.toNumber()).to.equal(0);
       expect(issuerAccount.activeCredentials.toNumber()).to.equal(0);
       expect(issuerAccount.isActive).to.equal(true);
-      expect(issuerAccount.canIssueKyc).to.equal(true);
-      expect(issuerAccount.canIssueAccredited).to.equal(true);
+      expect(issuerAccount.permissions).to.equal(permissions);
 
       // Verify network was updated
       const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
@@ -133,8 +168,45 @@ describe("credential_program", () => {
 
       console.log("  Issuer Name:", issuerAccount.name);
       console.log("  Issuer URI:", issuerAccount.uri);
-      console.log("  Can Issue KYC:", issuerAccount.canIssueKyc);
-      console.log("  Can Issue Accredited:", issuerAccount.canIssueAccredited);
+      console.log("  Permissions:", issuerAccount.permissions.toString(2));
+    });
+
+    it("should restrict issuer permissions to KYC types", async () => {
+      const permissions = 0b0000_0011; // KycBasic + KycFull
+
+      const tx = await program.methods
+        .updateIssuerPermissions(permissions)
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+          issuer: issuerPda,
+        })
+        .rpc();
+
+      console.log("\n  Update Issuer Permissions TX:", tx);
+
+      const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
+      expect(issuerAccount.permissions).to.equal(permissions);
+      expect(issuerAccount.canIssueKyc).to.equal(true);
+      expect(issuerAccount.canIssueAccredited).to.equal(false);
+
+      console.log("  Permissions:", issuerAccount.permissions.toString(2));
+    });
+
+    it("should leave a current issuer unchanged when migrated", async () => {
+      await program.methods
+        .migrateIssuer()
+        .accounts({
+          payer: admin.publicKey,
+          issuerAuthority: issuerAuthority.publicKey,
+          issuer: issuerPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
+      expect(issuerAccount.version).to.equal(1);
+      expect(issuerAccount.permissions).to.equal(0b0000_0011);
     });
   });
 
@@ -145,10 +217,11 @@ describe("credential_program", () => {
       const metadataUri = "https://kyc.hub.com/credentials/12345";
 
       const tx = await program.methods
-        .issueCredential(credentialType, expiryTimestamp, metadataUri)
+        .issueCredential(credentialType, expiryTimestamp, metadataUri, attributes, Array.from(commitment))
         .accounts({
           issuerAuthority: issuerAuthority.publicKey,
           network: networkPda,
+          treasury: treasuryPda,
           issuer: issuerPda,
           holder: holder.publicKey,
           credential: credentialPda,
@@ -167,6 +240,8 @@ describe("credential_program", () => {
       expect(credentialAccount.issuer.toString()).to.equal(issuerAuthority.publicKey.toString());
       expect(credentialAccount.metadataUri).to.equal(metadataUri);
       expect(credentialAccount.version).to.equal(1);
+      expect(Buffer.from(credentialAccount.attributes.countryOfResidence).toString()).to.equal("BR");
+      expect(Buffer.from(credentialAccount.commitment).equals(commitment)).to.be.true;
 
       // Check credential type (enum)
       expect(credentialAccount.credentialType.kycBasic !== undefined).to.be.true;
@@ -183,6 +258,7 @@ describe("credential_program", () => {
       const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
       expect(networkAccount.totalCredentialsIssued.toNumber()).to.equal(1);
       expect(networkAccount.activeCredentials.toNumber()).to.equal(1);
+      expect(networkAccount.totalFeesCollected.toNumber()).to.equal(10000);
 
       console.log("  Holder:", credentialAccount.holder.toString());
       console.log("  Credential Type: KycBasic");
@@ -190,6 +266,98 @@ describe("credential_program", () => {
       console.log("  Metadata URI:", credentialAccount.metadataUri);
       console.log("  Expires At:", new Date(credentialAccount.expiresAt.toNumber() * 1000).toISOString());
     });
+
+    it("should issue a second credential type to the same holder", async () => {
+      const credentialType = 1; // KycFull
+      const [kycFullPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("credential"), holder.publicKey.toBuffer(), Buffer.from([credentialType])],
+        programId
+      );
+
+      const tx = await program.methods
+        .issueCredential(
+          credentialType,
+          new anchor.BN(0),
+          "https://kyc.hub.com/credentials/12346",
+          attributes,
+          new Array(32).fill(0)
+        )
+        .accounts({
+          issuerAuthority: issuerAuthority.publicKey,
+          network: networkPda,
+          treasury: treasuryPda,
+          issuer: issuerPda,
+          holder: holder.publicKey,
+          credential: kycFullPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([issuerAuthority])
+        .rpc();
+
+      console.log("\n  Issue Second Credential TX:", tx);
+
+      const credentialAccount = await program.account.userCredential.fetch(kycFullPda);
+      expect(credentialAccount.holder.toString()).to.equal(holder.publicKey.toString());
+      expect(credentialAccount.credentialType.kycFull !== undefined).to.be.true;
+
+      const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
+      expect(issuerAccount.activeCredentials.toNumber()).to.equal(2);
+
+      console.log("  Credential PDA:", kycFullPda.toString());
+      console.log("  Credential Type: KycFull");
+    });
+
+    it("should update credential attributes", async () => {
+      const updated = {
+        ...attributes,
+        investorTier: { qualified: {} },
+        riskBand: { medium: {} },
+        investmentLimit: new anchor.BN(5 * anchor.web3.LAMPORTS_PER_SOL),
+      };
+
+      const tx = await program.methods
+        .updateCredentialAttributes(updated)
+        .accounts({
+          issuerAuthority: issuerAuthority.publicKey,
+          issuer: issuerPda,
+          holder: holder.publicKey,
+          credential: credentialPda,
+        })
+        .signers([issuerAuthority])
+        .rpc();
+
+      console.log("\n  Update Credential Attributes TX:", tx);
+
+      const credentialAccount = await program.account.userCredential.fetch(credentialPda);
+      expect(credentialAccount.attributes.investorTier.qualified !== undefined).to.be.true;
+      expect(credentialAccount.attributes.riskBand.medium !== undefined).to.be.true;
+      expect(credentialAccount.attributes.investmentLimit.toNumber()).to.equal(
+        5 * anchor.web3.LAMPORTS_PER_SOL
+      );
+    });
+
+    it("should reject an invalid country code", async () => {
+      try {
+        await program.methods
+          .updateCredentialAttributes({ ...attributes, countryOfResidence: Array.from(Buffer.from("br")) })
+          .accounts({
+            issuerAuthority: issuerAuthority.publicKey,
+            issuer: issuerPda,
+            holder: holder.publicKey,
+            credential: credentialPda,
+          })
+          .signers([issuerAuthority])
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        const errorMessage = error.message || error.toString();
+        const errorCode = error.error?.errorCode?.code || error.code || "";
+        expect(
+          errorCode === "InvalidCountryCode" || errorMessage.includes("InvalidCountryCode")
+        ).to.be.true;
+        console.log("\n  Correctly rejected lowercase country code");
+      }
+    });
   });
 
   describe("4. Verify Credential", () => {
@@ -216,6 +384,66 @@ describe("credential_program", () => {
       console.log("  Verification Successful!");
       console.log("  Last Verified At:", new Date(credentialAccount.lastVerifiedAt.toNumber() * 1000).toISOString());
     });
+
+    it("should return the structured verification result", async () => {
+      const result = await program.methods
+        .verifyCredential()
+        .accounts({
+          holder: holder.publicKey,
+          credential: credentialPda,
+        })
+        .view();
+
+      expect(result.credential.toString()).to.equal(credentialPda.toString());
+      expect(result.holder.toString()).to.equal(holder.publicKey.toString());
+      expect(result.issuer.toString()).to.equal(issuerAuthority.publicKey.toString());
+      expect(result.credentialType.kycBasic !== undefined).to.be.true;
+      expect(result.status.active !== undefined).to.be.true;
+      expect(result.attributes.investorTier.qualified !== undefined).to.be.true;
+
+      console.log("\n  Verification result expires at:", result.expiresAt.toString());
+    });
+
+    it("should prove a single committed attribute", async () => {
+      const { attribute, salt } = committedAttributes[1]; // "age>=18"
+      const proof = [leaves[0], leaves[2]];
+
+      const tx = await program.methods
+        .proveAttribute(attribute, Array.from(salt), proof.map((node) => Array.from(node)))
+        .accounts({
+          holder: holder.publicKey,
+          credential: credentialPda,
+        })
+        .rpc();
+
+      console.log("\n  Prove Attribute TX:", tx);
+      console.log("  Disclosed:", attribute.toString());
+    });
+
+    it("should reject a proof of an attribute that was not committed", async () => {
+      const { salt } = committedAttributes[0];
+      try {
+        await program.methods
+          .proveAttribute(Buffer.from("country=US"), Array.from(salt), [
+            Array.from(leaves[1]),
+            Array.from(leaves[2]),
+          ])
+          .accounts({
+            holder: holder.publicKey,
+            credential: credentialPda,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        const errorMessage = error.message || error.toString();
+        const errorCode = error.error?.errorCode?.code || error.code || "";
+        expect(
+          errorCode === "InvalidAttributeProof" || errorMessage.includes("InvalidAttributeProof")
+        ).to.be.true;
+        console.log("\n  Proof correctly rejected for an uncommitted attribute");
+      }
+    });
   });
 
   describe("5. Refresh Credential", () => {
@@ -227,9 +455,12 @@ describe("credential_program", () => {
         .refreshCredential(newExpiryTimestamp)
         .accounts({
           issuerAuthority: issuerAuthority.publicKey,
+          network: networkPda,
+          treasury: treasuryPda,
           issuer: issuerPda,
           holder: holder.publicKey,
           credential: credentialPda,
+          systemProgram: SystemProgram.programId,
         })
         .signers([issuerAuthority])
         .rpc();
@@ -248,7 +479,84 @@ describe("credential_program", () => {
     });
   });
 
-  describe("6. Revoke Credential", () => {
+  describe("6. Suspend and Reinstate Credential", () => {
+    it("should suspend a credential", async () => {
+      const reason = "Compliance investigation";
+
+      const tx = await program.methods
+        .suspendCredential(reason, new anchor.BN(0))
+        .accounts({
+          authority: issuerAuthority.publicKey,
+          network: networkPda,
+          holder: holder.publicKey,
+          credential: credentialPda,
+        })
+        .signers([issuerAuthority])
+        .rpc();
+
+      console.log("\n  Suspend Credential TX:", tx);
+
+      const credentialAccount = await program.account.userCredential.fetch(credentialPda);
+      expect(credentialAccount.status.suspended !== undefined).to.be.true;
+      expect(credentialAccount.suspensionReason).to.equal(reason);
+      expect(credentialAccount.revocationReason).to.equal("");
+      expect(credentialAccount.suspendedUntil.toNumber()).to.equal(0);
+
+      // Suspended credentials stay in the active counts
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.activeCredentials.toNumber()).to.equal(2);
+
+      console.log("  Status: Suspended");
+      console.log("  Reason:", credentialAccount.suspensionReason);
+    });
+
+    it("should fail to verify a suspended credential", async () => {
+      try {
+        await program.methods
+          .verifyCredential()
+          .accounts({
+            holder: holder.publicKey,
+            credential: credentialPda,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (error: any) {
+        const errorMessage = error.message || error.toString();
+        const errorCode = error.error?.errorCode?.code || error.code || "";
+        expect(
+          errorCode === "CredentialSuspended" || errorMessage.includes("CredentialSuspended")
+        ).to.be.true;
+        console.log("\n  Verification correctly failed for suspended credential");
+      }
+    });
+
+    it("should reinstate a suspended credential", async () => {
+      const tx = await program.methods
+        .reinstateCredential()
+        .accounts({
+          authority: issuerAuthority.publicKey,
+          network: networkPda,
+          holder: holder.publicKey,
+          credential: credentialPda,
+        })
+        .signers([issuerAuthority])
+        .rpc();
+
+      console.log("\n  Reinstate Credential TX:", tx);
+
+      const credentialAccount = await program.account.userCredential.fetch(credentialPda);
+      expect(credentialAccount.status.active !== undefined).to.be.true;
+      expect(credentialAccount.suspensionReason).to.equal("");
+
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.activeCredentials.toNumber()).to.equal(2);
+
+      console.log("  Status: Active");
+    });
+  });
+
+  describe("7. Revoke Credential", () => {
     it("should revoke a credential", async () => {
       const reason = "Compliance violation detected";
 
@@ -275,12 +583,13 @@ describe("credential_program", () => {
 
       // Verify issuer was updated
       const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
-      expect(issuerAccount.activeCredentials.toNumber()).to.equal(0);
+      // The holder's KycFull credential stays active
+      expect(issuerAccount.activeCredentials.toNumber()).to.equal(1);
       expect(issuerAccount.revokedCredentials.toNumber()).to.equal(1);
 
       // Verify network was updated
       const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
-      expect(networkAccount.activeCredentials.toNumber()).to.equal(0);
+      expect(networkAccount.activeCredentials.toNumber()).to.equal(1);
 
       console.log("  Status: Revoked");
       console.log("  Reason:", credentialAccount.revocationReason);
@@ -323,7 +632,168 @@ describe("credential_program", () => {
     });
   });
 
-  describe("7. Summary", () => {
+  describe("8. Network Fees", () => {
+    it("should update the network config", async () => {
+      const newFee = new anchor.BN(20000);
+
+      const tx = await program.methods
+        .updateNetworkConfig(null, newFee)
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+        })
+        .rpc();
+
+      console.log("\n  Update Network Config TX:", tx);
+
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.credentialFeeLamports.toNumber()).to.equal(20000);
+      expect(networkAccount.name).to.equal("Hub Credential Network");
+    });
+
+    it("should withdraw collected fees", async () => {
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      // Two issuances and one refresh at 10000 lamports each
+      expect(networkAccount.totalFeesCollected.toNumber()).to.equal(30000);
+
+      // Existing funded wallet (a fresh account would need rent exemption)
+      const recipient = issuerAuthority.publicKey;
+      const balanceBefore = await provider.connection.getBalance(recipient);
+      const amount = new anchor.BN(30000);
+
+      const tx = await program.methods
+        .withdrawNetworkFees(amount)
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+          treasury: treasuryPda,
+          recipient,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("\n  Withdraw Network Fees TX:", tx);
+
+      const balanceAfter = await provider.connection.getBalance(recipient);
+      expect(balanceAfter - balanceBefore).to.equal(30000);
+    });
+
+    it("should leave a current network unchanged when migrated", async () => {
+      await program.methods
+        .migrateNetwork()
+        .accounts({
+          payer: admin.publicKey,
+          network: networkPda,
+          treasury: treasuryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.version).to.equal(1);
+      expect(networkAccount.totalFeesCollected.toNumber()).to.equal(30000);
+    });
+  });
+
+  describe("9. Administration", () => {
+    it("should update issuer metadata", async () => {
+      const newUri = "https://kyc.hub.com/v2";
+
+      await program.methods
+        .updateIssuer(null, newUri)
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+          issuer: issuerPda,
+        })
+        .rpc();
+
+      const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
+      expect(issuerAccount.uri).to.equal(newUri);
+      expect(issuerAccount.name).to.equal("Hub KYC Provider");
+    });
+
+    it("should transfer network admin in two steps", async () => {
+      const newAdmin = Keypair.generate();
+
+      await program.methods
+        .proposeNetworkAdmin(newAdmin.publicKey)
+        .accounts({ admin: admin.publicKey, network: networkPda })
+        .rpc();
+
+      let networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.pendingAdmin.toString()).to.equal(newAdmin.publicKey.toString());
+      expect(networkAccount.admin.toString()).to.equal(admin.publicKey.toString());
+
+      await program.methods
+        .acceptNetworkAdmin()
+        .accounts({ newAdmin: newAdmin.publicKey, network: networkPda })
+        .signers([newAdmin])
+        .rpc();
+
+      networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.admin.toString()).to.equal(newAdmin.publicKey.toString());
+      expect(networkAccount.pendingAdmin).to.be.null;
+
+      // Hand the role back for the remaining tests
+      await program.methods
+        .proposeNetworkAdmin(admin.publicKey)
+        .accounts({ admin: newAdmin.publicKey, network: networkPda })
+        .signers([newAdmin])
+        .rpc();
+      await program.methods
+        .acceptNetworkAdmin()
+        .accounts({ newAdmin: admin.publicKey, network: networkPda })
+        .rpc();
+
+      networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.admin.toString()).to.equal(admin.publicKey.toString());
+    });
+
+    it("should deactivate an issuer and cascade to its credentials", async () => {
+      const [kycFullPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("credential"), holder.publicKey.toBuffer(), Buffer.from([1])],
+        programId
+      );
+
+      await program.methods
+        .setIssuerStatus(false)
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+          issuer: issuerPda,
+        })
+        .rpc();
+
+      const tx = await program.methods
+        .revokeIssuerCredentials("Issuer deactivated")
+        .accounts({
+          admin: admin.publicKey,
+          network: networkPda,
+          issuer: issuerPda,
+        })
+        .remainingAccounts([
+          { pubkey: kycFullPda, isSigner: false, isWritable: true },
+          { pubkey: credentialPda, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      console.log("\n  Revoke Issuer Credentials TX:", tx);
+
+      const credentialAccount = await program.account.userCredential.fetch(kycFullPda);
+      expect(credentialAccount.status.revoked !== undefined).to.be.true;
+
+      const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
+      expect(issuerAccount.isActive).to.equal(false);
+      expect(issuerAccount.activeCredentials.toNumber()).to.equal(0);
+      expect(issuerAccount.revokedCredentials.toNumber()).to.equal(2);
+
+      const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
+      expect(networkAccount.activeCredentials.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("10. Summary", () => {
     it("should display final state", async () => {
       const networkAccount = await program.account.credentialNetwork.fetch(networkPda);
       const issuerAccount = await program.account.credentialIssuer.fetch(issuerPda);
@@ -456,7 +456,7 @@ pub struct UserCredential {
     pub bump: u8,
 }
 
-// PDA Seed: ["credential", wallet.key()]` },
+// PDA Seed: ["credential", wallet.key(), credential_type]` },
     { type: 'heading', level: 2, text: 'Status da Credencial', id: 'credential-status' },
     { type: 'table', headers: ['Status', 'Descrição', 'Pode Transferir?'], rows: [
       ['Active', 'Credencial válida e ativa', 'Sim'],
@@ -670,7 +670,7 @@ pub struct UserCredential {
     pub metadata_hash: [u8; 32],
     pub bump: u8,
 }
-// PDA Seed: ["credential", wallet.key()]` },
+// PDA Seed: ["credential", wallet.key(), credential_type]` },
     { type: 'heading', level: 2, text: 'Instruções', id: 'instructions' },
     { type: 'table', headers: ['Instrução', 'Descrição', 'Autorização'], rows: [
       ['initialize_network', 'Inicializa a rede de credenciais', 'Deploy'],
@@ -764,7 +764,7 @@ let (pda, bump) = Pubkey::find_program_address(
     { type: 'table', headers: ['Conta', 'Seeds', 'Descrição'], rows: [
       ['CredentialNetwork', '["network"]', 'Config global da rede'],
       ['CredentialIssuer', '["issuer", authority]', 'Emissor registrado'],
-      ['UserCredential', '["credential", wallet]', 'Credencial do usuário'],
+      ['UserCredential', '["credential", wallet, credential_type]', 'Credencial do usuário (uma por tipo)'],
     ]},
     { type: 'heading', level: 2, text: 'Exemplo de Derivação', id: 'derivation-example' },
     { type: 'code', language: 'typescript', filename: 'TypeScript', code: `import { PublicKey } from '@solana/web3.js';
@@ -775,9 +775,9 @@ const [propertyPda] = PublicKey.findProgramAddressSync(
   KOTA_PROGRAM_ID
 );
 
-// Derivar PDA de UserCredential
+// Derivar PDA de UserCredential (um por tipo, ex.: 0 = KycBasic)
 const [credentialPda] = PublicKey.findProgramAddressSync(
-  [Buffer.from("credential"), wallet.toBuffer()],
+  [Buffer.from("credential"), wallet.toBuffer(), Buffer.from([credentialType])],
   CREDENTIAL_PROGRAM_ID
 );` },
   ],
@@ -24,6 +24,8 @@ import { Badge } from '@/components/ui/Badge';
 import { PageLoading } from '@/components/ui/Loading';
 import { EmptyState } from '@/components/ui/EmptyState';
 import { ImageUploader } from '@/components/property/ImageUploader';
+import { isPropertyLive } from '@/utils/propertyStatus';
+import { PropertyStatus } from '@/types';
 
 // Admin API URL (Hub Token API) - empty string means use relative URLs via Kong proxy
 const ADMIN_API_URL = import.meta.env.VITE_HUB_API_URL ?? '';
@@ -57,7 +59,7 @@ interface Property {
   authority: string;
   totalSupply: string;
   circulatingSupply: string;
-  status: string; // "active" | "paused"
+  status: PropertyStatus;
   details: PropertyDetails;
   createdAt: string;
   currentEpoch?: number;
@@ -611,8 +613,8 @@ export const AdminPage: FC = () => {
                     <div className="flex items-center gap-3 mb-2">
                       <h3 className="text-lg font-semibold text-white">{property.name}</h3>
                       <Badge variant="default">{property.symbol}</Badge>
-                      <Badge variant={property.status === 'active' ? 'success' : 'warning'}>
-                        {property.status === 'active' ? t('admin.active') : t('admin.paused')}
+                      <Badge variant={isPropertyLive(property.status) ? 'success' : 'warning'}>
+                        {property.status}
                       </Badge>
                     </div>
                     <div className="grid grid-cols-2 md:grid-cols-4 gap-4 text-sm">
@@ -654,7 +656,7 @@ export const AdminPage: FC = () => {
                       onClick={() => handleToggleStatus(property.mint)}
                       leftIcon={<Power className="w-4 h-4" />}
                     >
-                      {property.status === 'active' ? t('admin.pause') : t('admin.activate')}
+                      {property.status === 'paused' ? t('admin.activate') : t('admin.pause')}
                     </Button>
                     <Button
                       variant="ghost"
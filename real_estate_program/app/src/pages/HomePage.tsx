@@ -19,6 +19,7 @@ import { Card } from '@/components/ui/Card';
 import { StatCard, StatsGrid } from '@/components/ui/Stats';
 import { Badge } from '@/components/ui/Badge';
 import { propertiesApi, statsApi } from '@/services/api';
+import { isPropertyLive } from '@/utils/propertyStatus';
 
 export const HomePage: FC = () => {
   const { connected } = useWallet();
@@ -172,7 +173,7 @@ export const HomePage: FC = () => {
                         <h3 className="font-semibold text-white">{property.name}</h3>
                         <p className="text-sm text-solana-dark-400">{property.details.location}</p>
                       </div>
-                      <Badge variant={property.status === 'active' ? 'success' : 'warning'}>
+                      <Badge variant={isPropertyLive(property.status) ? 'success' : 'warning'}>
                         {property.status}
                       </Badge>
                     </div>
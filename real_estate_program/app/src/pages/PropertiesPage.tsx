@@ -10,6 +10,7 @@ import { SkeletonCard } from '@/components/ui/Loading';
 import { EmptyState } from '@/components/ui/EmptyState';
 import { propertiesApi } from '@/services/api';
 import { Property, PropertyStatus } from '@/types';
+import { isPropertyLive } from '@/utils/propertyStatus';
 
 export const PropertiesPage: FC = () => {
   const { t } = useTranslation();
@@ -92,7 +93,7 @@ export const PropertiesPage: FC = () => {
           />
         </div>
         <div className="flex gap-2">
-          {(['all', 'active', 'paused'] as const).map((status) => (
+          {(['all', 'fundraising', 'funded', 'operating', 'paused'] as const).map((status) => (
             <Button
               key={status}
               variant={statusFilter === status ? 'primary' : 'ghost'}
@@ -148,7 +149,7 @@ const PropertyCard: FC<{ property: Property; t: (key: string) => string }> = ({
           ) : null}
           <Building2 className={`w-16 h-16 text-solana-dark-500 ${property.details.image ? 'hidden' : ''}`} />
           <div className="absolute top-4 right-4">
-            <Badge variant={property.status === 'active' ? 'success' : 'warning'}>
+            <Badge variant={isPropertyLive(property.status) ? 'success' : 'warning'}>
               {property.status}
             </Badge>
           </div>
@@ -213,7 +214,7 @@ const PropertyListItem: FC<{ property: Property; t: (key: string) => string }> =
               <h3 className="font-semibold text-white text-lg">{property.name}</h3>
               <p className="text-sm text-solana-dark-400">{property.details.location}</p>
             </div>
-            <Badge variant={property.status === 'active' ? 'success' : 'warning'}>
+            <Badge variant={isPropertyLive(property.status) ? 'success' : 'warning'}>
               {property.status}
             </Badge>
           </div>
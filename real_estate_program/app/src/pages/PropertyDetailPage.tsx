@@ -27,6 +27,7 @@ import { EmptyState } from '@/components/ui/EmptyState';
 import { PropertyGallery } from '@/components/property/PropertyGallery';
 import { InvestmentModal } from '@/components/investment';
 import { propertiesApi } from '@/services/api';
+import { isPropertyLive } from '@/utils/propertyStatus';
 import toast from 'react-hot-toast';
 
 // IPFS API URL (Hub Token API) - empty string means use relative URLs via Kong proxy
@@ -176,7 +177,7 @@ export const PropertyDetailPage: FC = () => {
         <div className="lg:w-1/2 space-y-4">
           <div>
             <div className="flex items-center gap-3 mb-2">
-              <Badge variant={property.status === 'active' ? 'success' : 'warning'}>
+              <Badge variant={isPropertyLive(property.status) ? 'success' : 'warning'}>
                 {property.status}
               </Badge>
               <Badge variant="purple">{property.details.propertyType}</Badge>
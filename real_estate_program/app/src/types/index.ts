@@ -33,7 +33,15 @@ export interface PropertyDetail extends Property {
   };
 }
 
-export type PropertyStatus = 'active' | 'paused' | 'frozen';
+// On-chain PropertyStatus lifecycle (hub_token_program)
+export type PropertyStatus =
+  | 'draft'
+  | 'fundraising'
+  | 'funded'
+  | 'operating'
+  | 'paused'
+  | 'liquidating'
+  | 'closed';
 
 export interface PropertyFilter {
   status?: PropertyStatus;
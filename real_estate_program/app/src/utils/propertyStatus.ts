@@ -0,0 +1,5 @@
+import { PropertyStatus } from '@/types';
+
+/** Whether a property is live: open for investment or past its primary sale and not paused */
+export const isPropertyLive = (status: PropertyStatus): boolean =>
+  status === 'fundraising' || status === 'funded' || status === 'operating';
@@ -17,6 +17,72 @@ pub const INVESTMENT_VAULT_SEED: &[u8] = b"investment_vault";
 /// Seed for Reserve Fund PDA (property maintenance/guarantee)
 pub const RESERVE_FUND_SEED: &[u8] = b"reserve_fund";
 
+/// Seed for PropertyLiquidation PDA (sale proceeds distribution)
+pub const LIQUIDATION_SEED: &[u8] = b"liquidation";
+
+/// Seed for RedemptionWindow PDA (reserve-funded buybacks)
+pub const REDEMPTION_WINDOW_SEED: &[u8] = b"redemption_window";
+
+/// Seed for PlatformConfig PDA (guardian and circuit breaker)
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+
+/// Seed for GovernanceConfig PDA (per-property voting parameters)
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+
+/// Seed for Proposal PDAs (mint + proposal id)
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// Seed for VoteRecord PDAs (proposal + voter)
+pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+
+/// Seed for MarketOrder PDAs (mint + owner + order id)
+pub const MARKET_ORDER_SEED: &[u8] = b"market_order";
+
+/// Seed for LiquidityPool PDA (constant-product AMM)
+pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
+
+/// Seed for the pool's LP token mint PDA
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+
+/// Seed for the pool's property token vault PDA
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+
+/// Seed for FundingRound PDAs (mint + round index)
+pub const FUNDING_ROUND_SEED: &[u8] = b"funding_round";
+
+/// Seed for InvestmentLimits PDA (min ticket and per-credential caps)
+pub const INVESTMENT_LIMITS_SEED: &[u8] = b"investment_limits";
+
+/// Seed for ComplianceRules PDA (jurisdiction and risk restrictions)
+pub const COMPLIANCE_RULES_SEED: &[u8] = b"compliance_rules";
+
+/// Seed for InvestorRecord PDAs (mint + investor)
+pub const INVESTOR_RECORD_SEED: &[u8] = b"investor_record";
+
+/// Seed for Distributor PDAs (partner broker wallet)
+pub const DISTRIBUTOR_SEED: &[u8] = b"distributor";
+
+/// Seed for VestingSchedule PDAs (per mint and beneficiary)
+pub const VESTING_SCHEDULE_SEED: &[u8] = b"vesting_schedule";
+
+/// Seed for StakePosition PDAs (per mint and staker)
+pub const STAKE_POSITION_SEED: &[u8] = b"stake_position";
+
+/// Seed for Bundle PDAs (per bundle mint)
+pub const BUNDLE_SEED: &[u8] = b"bundle";
+
+/// Seed for BundleHolder PDAs (per bundle and holder)
+pub const BUNDLE_HOLDER_SEED: &[u8] = b"bundle_holder";
+
+/// Seed for LendingMarket PDAs (per property mint)
+pub const LENDING_MARKET_SEED: &[u8] = b"lending_market";
+
+/// Seed for lending stablecoin vault token accounts (per property mint)
+pub const LENDING_VAULT_SEED: &[u8] = b"lending_vault";
+
+/// Seed for LoanPosition PDAs (per property mint and borrower)
+pub const LOAN_POSITION_SEED: &[u8] = b"loan_position";
+
 // ============================================================================
 // STRING LENGTH LIMITS
 // ============================================================================
@@ -36,13 +102,19 @@ pub const MAX_PROPERTY_TYPE_LEN: usize = 100;
 /// Maximum length for metadata URI (IPFS/Arweave)
 pub const MAX_METADATA_URI_LEN: usize = 500;
 
+/// Maximum length for governance proposal description URI
+pub const MAX_PROPOSAL_URI_LEN: usize = 200;
+
+/// Maximum countries in each ComplianceRules list
+pub const MAX_COMPLIANCE_COUNTRIES: usize = 32;
+
 // ============================================================================
 // EXTERNAL PROGRAMS
 // ============================================================================
 
 /// Hub Credential Program ID
 /// Custom KYC/Verifiable Credentials protocol for Hub Token
-pub const HUB_CREDENTIAL_PROGRAM_ID: Pubkey = pubkey!("FaJ4XGCLeu7eZiMjBEkANko3TRhpjns3cv6R1vZK94Wt");
+pub const HUB_CREDENTIAL_PROGRAM_ID: Pubkey = credential_program::ID_CONST;
 
 // ============================================================================
 // FEES AND DISTRIBUTION (in basis points: 100 bps = 1%)
@@ -63,10 +135,25 @@ pub const SELLER_ESCROW_BPS: u16 = 9000;
 /// Basis points divisor (10000 = 100%)
 pub const BPS_DIVISOR: u64 = 10000;
 
+/// Maximum combined AMM swap fee (LP + platform): 10%
+pub const MAX_POOL_FEE_BPS: u16 = 1000;
+
+/// Platform share of AMM swaps for new pools: 0.2%
+/// Set per pool by the platform admin afterwards
+pub const DEFAULT_POOL_PLATFORM_FEE_BPS: u16 = 20;
+
+/// Maximum secondary-trade transfer fee: 5%
+pub const MAX_TRANSFER_FEE_BPS: u16 = 500;
+
 /// Kota Platform Treasury Address
 /// All platform fees are sent to this address
 pub const PLATFORM_TREASURY: Pubkey = pubkey!("AMuiRHoJLS2zhpRtUqVJUpYi4xEGbZcmMsJpqVT9uCJw");
 
+/// Kota Platform Admin (bootstrap key)
+/// Only initializes the PlatformConfig; afterwards the admin stored there
+/// governs the platform and can be rotated independently of the treasury
+pub const PLATFORM_ADMIN: Pubkey = pubkey!("DcNsn2nMRn2sbvjL4RykkHzh2DBmDkddZu2EAi86DqTj");
+
 // ============================================================================
 // MILESTONE THRESHOLDS (for seller escrow release)
 // ============================================================================
@@ -93,9 +180,94 @@ pub const MIN_RENTAL_YIELD_BPS: u16 = 0;
 /// Maximum rental yield in basis points (100% = 10000 bps)
 pub const MAX_RENTAL_YIELD_BPS: u16 = 10000;
 
+// ============================================================================
+// LIQUIDATION SETTINGS
+// ============================================================================
+
+/// Claim period in seconds (365 days) after which a liquidated property can be
+/// closed with tokens still outstanding
+pub const LIQUIDATION_CLAIM_PERIOD_SECONDS: i64 = 365 * 24 * 60 * 60;
+
 // ============================================================================
 // KYC SETTINGS
 // ============================================================================
 
 /// KYC grace period in seconds (7 days) for expiring credentials
 pub const KYC_GRACE_PERIOD_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Number of Hub Credential types (size of per-type limit tables)
+pub const HUB_CREDENTIAL_TYPE_COUNT: usize = 6;
+
+/// Credential type mask accepting every Hub Credential type
+pub const ANY_HUB_CREDENTIAL_TYPE: u8 = 0b0011_1111;
+
+// ============================================================================
+// GOVERNANCE
+// ============================================================================
+
+/// Minimum voting period in seconds (1 day)
+pub const MIN_VOTING_PERIOD_SECONDS: i64 = 24 * 60 * 60;
+
+/// Minimum quorum (participation of the circulating supply): 10%
+pub const MIN_QUORUM_BPS: u16 = 1000;
+
+/// Minimum approval threshold: a strict majority of votes cast
+pub const MIN_APPROVAL_THRESHOLD_BPS: u16 = 5001;
+
+/// Minimum delay between the end of voting and execution (2 days)
+pub const MIN_EXECUTION_DELAY_SECONDS: i64 = 2 * 24 * 60 * 60;
+
+// ============================================================================
+// STAKING
+// ============================================================================
+
+/// Minimum stake lock in seconds (30 days)
+pub const MIN_STAKE_LOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum stake lock in seconds (365 days)
+pub const MAX_STAKE_LOCK_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+/// Revenue weight boost at the maximum lock, in basis points (+50%)
+pub const MAX_STAKE_BOOST_BPS: u16 = 5000;
+
+// ============================================================================
+// BUNDLES
+// ============================================================================
+
+/// Maximum number of properties in a bundle basket
+pub const MAX_BUNDLE_COMPONENTS: usize = 8;
+
+/// Fixed-point scale for per-bundle-token revenue accounting
+pub const BUNDLE_REVENUE_PRECISION: u128 = 1_000_000_000_000;
+
+// ============================================================================
+// LENDING
+// ============================================================================
+
+/// Maximum liquidation threshold in basis points (95% LTV)
+pub const MAX_LIQUIDATION_THRESHOLD_BPS: u16 = 9500;
+
+/// Maximum liquidation bonus in basis points (20%)
+pub const MAX_LIQUIDATION_BONUS_BPS: u16 = 2000;
+
+/// Maximum annual interest rate in basis points (50%)
+pub const MAX_INTEREST_RATE_BPS: u16 = 5000;
+
+/// Seconds per year for interest accrual (365 days)
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
+/// Maximum change of a lending valuation per update in basis points (10%)
+pub const MAX_VALUATION_CHANGE_BPS: u16 = 1000;
+
+/// Minimum time between lending valuation updates (1 day)
+pub const MIN_VALUATION_UPDATE_INTERVAL: i64 = 24 * 60 * 60;
+
+/// Age after which a lending valuation no longer backs new debt (90 days)
+pub const MAX_VALUATION_AGE: i64 = 90 * 24 * 60 * 60;
+
+// ============================================================================
+// FUNDING ROUNDS
+// ============================================================================
+
+/// Maximum number of funding rounds per property
+pub const MAX_FUNDING_ROUNDS: usize = 8;
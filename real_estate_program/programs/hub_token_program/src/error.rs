@@ -21,6 +21,31 @@ pub enum RwaError {
     #[msg("Property is not active: minting is disabled")]
     PropertyNotActive,
 
+    #[msg("Invalid property status transition")]
+    InvalidStatusTransition,
+
+    #[msg("Property is not fundraising: investments are closed")]
+    PropertyNotFundraising,
+
+    #[msg("Operation not allowed in the current property status")]
+    InvalidPropertyStatus,
+
+    #[msg("Transfers are not allowed in the current property status")]
+    TransfersNotAllowed,
+
+    // Emergency Pause Errors
+    #[msg("Transfers are paused for this property")]
+    TransfersPaused,
+
+    #[msg("Transfers are paused platform-wide")]
+    GlobalTransfersPaused,
+
+    #[msg("Unauthorized: Only the platform guardian can perform this action")]
+    UnauthorizedGuardian,
+
+    #[msg("Unauthorized: Only the platform admin can perform this action")]
+    UnauthorizedPlatformAdmin,
+
     #[msg("Invalid mint account")]
     InvalidMint,
 
@@ -110,4 +135,203 @@ pub enum RwaError {
 
     #[msg("Insufficient SOL balance for investment")]
     InsufficientSolBalance,
+
+    // Liquidation Errors
+    #[msg("Property is not being liquidated")]
+    PropertyNotLiquidating,
+
+    #[msg("Liquidation incomplete: tokens still outstanding before the claim period ended")]
+    TokensOutstanding,
+
+    // Redemption Window Errors
+    #[msg("Invalid redemption window parameters")]
+    InvalidRedemptionWindow,
+
+    #[msg("Redemption window is not open")]
+    RedemptionWindowNotOpen,
+
+    #[msg("Redemption exceeds the window token cap")]
+    RedemptionCapExceeded,
+
+    #[msg("Redemption budget exhausted")]
+    RedemptionBudgetExhausted,
+
+    #[msg("Insufficient reserve fund balance")]
+    InsufficientReserve,
+
+    // Token Escrow Errors
+    #[msg("Insufficient escrowed tokens")]
+    InsufficientEscrow,
+
+    // Governance Errors
+    #[msg("Invalid governance configuration")]
+    InvalidGovernanceConfig,
+
+    #[msg("Proposer does not have enough tokens staked to create a proposal")]
+    ProposalThresholdNotMet,
+
+    #[msg("Proposal does not belong to this property")]
+    InvalidProposal,
+
+    #[msg("Voting is closed for this proposal")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended yet")]
+    VotingNotEnded,
+
+    #[msg("Proposal did not reach quorum or approval threshold")]
+    ProposalNotPassed,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Missing or mismatched accounts for the proposal action")]
+    InvalidProposalAccounts,
+
+    #[msg("Proposal is still in its execution timelock")]
+    ProposalTimelocked,
+
+    #[msg("No tokens were staked at the proposal snapshot")]
+    NoVotingWeight,
+
+    // Order Book Errors
+    #[msg("Invalid order: price and amount must be greater than zero")]
+    InvalidOrder,
+
+    #[msg("Fill exceeds the remaining order amount")]
+    OrderAmountExceeded,
+
+    #[msg("Cannot fill your own order")]
+    SelfTrade,
+
+    #[msg("Trade value rounds to zero lamports")]
+    TradeTooSmall,
+
+    #[msg("Missing or mismatched accounts for the order side")]
+    InvalidOrderAccounts,
+
+    // Liquidity Pool Errors
+    #[msg("Invalid pool fees: combined fee exceeds the maximum")]
+    InvalidPoolFees,
+
+    #[msg("Insufficient pool liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Swap must be paired with a matching pool token transfer in the same transaction")]
+    InvalidPoolTransfer,
+
+    // Transfer Fee Errors
+    #[msg("Invalid transfer fee configuration")]
+    InvalidTransferFee,
+
+    #[msg("Transfer fee is not enabled for this property")]
+    TransferFeeNotEnabled,
+
+    // Funding Round Errors
+    #[msg("Invalid funding round parameters")]
+    InvalidFundingRound,
+
+    #[msg("Maximum number of funding rounds reached")]
+    TooManyFundingRounds,
+
+    #[msg("Funding round is not active")]
+    FundingRoundNotActive,
+
+    #[msg("Funding round must start after the previous round ends")]
+    FundingRoundOverlap,
+
+    #[msg("Investment exceeds the funding round cap")]
+    FundingRoundCapExceeded,
+
+    #[msg("Investments must go through an active funding round")]
+    FundingRoundRequired,
+
+    #[msg("Investor is not on the funding round allow-list")]
+    NotOnAllowlist,
+
+    #[msg("Credential type not allowed for this investment")]
+    CredentialTypeNotAllowed,
+
+    // Investment Limit Errors
+    #[msg("Invalid investment limits")]
+    InvalidInvestmentLimits,
+
+    #[msg("Investment is below the minimum ticket")]
+    InvestmentBelowMinimum,
+
+    #[msg("Investment exceeds the limit for this credential type")]
+    InvestmentLimitExceeded,
+
+    // Compliance Rule Errors
+    #[msg("Invalid compliance rules")]
+    InvalidComplianceRules,
+
+    #[msg("Investor jurisdiction is not allowed for this property")]
+    JurisdictionNotAllowed,
+
+    #[msg("Investor tier is below the minimum for this property")]
+    InvestorTierNotAllowed,
+
+    #[msg("Investor risk band exceeds the maximum for this property")]
+    RiskBandNotAllowed,
+
+    #[msg("Politically exposed persons may not invest in this property")]
+    PepNotAllowed,
+
+    // Distributor Errors
+    #[msg("Invalid commission rate: must not exceed 100% of the platform fee")]
+    InvalidCommissionRate,
+
+    #[msg("Distributor is not active")]
+    DistributorInactive,
+
+    // Vesting Errors
+    #[msg("Invalid vesting schedule: requires an amount and start <= cliff <= end")]
+    InvalidVestingSchedule,
+
+    #[msg("No vested tokens available to claim")]
+    NoVestedTokens,
+
+    // Staking Errors
+    #[msg("Invalid stake lock duration")]
+    InvalidStakeLock,
+
+    #[msg("Stake is still locked")]
+    StakeLocked,
+
+    // Bundle Errors
+    #[msg("Invalid bundle: needs 1-8 distinct components with non-zero amounts")]
+    InvalidBundle,
+
+    #[msg("Bundle component accounts do not match the basket")]
+    InvalidBundleAccounts,
+
+    #[msg("Collect the bundle's outstanding revenue epochs before changing its units")]
+    BundleRevenueOutstanding,
+
+    #[msg("Revenue epoch was deposited before the bundle's last units change")]
+    EpochBeforeBundleUnits,
+
+    // Lending Errors
+    #[msg("Invalid lending parameters")]
+    InvalidLendingParams,
+
+    #[msg("Borrow limit exceeded: debt would be above the maximum LTV")]
+    BorrowLimitExceeded,
+
+    #[msg("Position is healthy and cannot be liquidated")]
+    PositionHealthy,
+
+    #[msg("Lending valuation is stale and must be refreshed by the platform admin")]
+    StaleValuation,
+
+    #[msg("Invalid valuation update: change too large or too soon")]
+    InvalidValuationUpdate,
+
+    // Migration Errors
+    #[msg("Account is not a transfer hook ExtraAccountMetaList")]
+    InvalidExtraAccountMetaList,
 }
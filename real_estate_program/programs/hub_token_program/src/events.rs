@@ -1,6 +1,10 @@
 /// Events emitted by the RWA Tokenization Program for audit trails
 use anchor_lang::prelude::*;
 
+use crate::constants::HUB_CREDENTIAL_TYPE_COUNT;
+use crate::state::{OrderSide, PropertyStatus, ProposalAction, SwapDirection};
+use crate::utils::{InvestorTier, RiskBand};
+
 #[event]
 pub struct PropertyInitialized {
     pub mint: Pubkey,
@@ -42,7 +46,8 @@ pub struct PropertyUpdated {
 #[event]
 pub struct PropertyStatusChanged {
     pub mint: Pubkey,
-    pub is_active: bool,
+    pub old_status: PropertyStatus,
+    pub new_status: PropertyStatus,
     pub timestamp: i64,
 }
 
@@ -89,6 +94,7 @@ pub struct InvestmentMade {
     pub investor: Pubkey,
     pub sol_amount: u64,
     pub tokens_received: u64,
+    pub funding_round: Option<u8>,
     pub platform_fee: u64,
     pub reserve_amount: u64,
     pub escrow_amount: u64,
@@ -112,3 +118,565 @@ pub struct SellerWithdrawal {
     pub remaining_escrow: u64,
     pub timestamp: i64,
 }
+
+// ============================================================================
+// LIQUIDATION EVENTS
+// ============================================================================
+
+#[event]
+pub struct PropertyLiquidationStarted {
+    pub property_mint: Pubkey,
+    pub authority: Pubkey,
+    pub sale_proceeds: u64,
+    pub reserve_swept: u64,
+    pub supply_at_liquidation: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidationRedeemed {
+    pub property_mint: Pubkey,
+    pub investor: Pubkey,
+    pub tokens_burned: u64,
+    pub payout: u64,
+    pub remaining_proceeds: u64,
+    pub circulating_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PropertyClosed {
+    pub property_mint: Pubkey,
+    pub authority: Pubkey,
+    pub total_distributed: u64,
+    pub unclaimed_proceeds: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// REDEMPTION EVENTS
+// ============================================================================
+
+#[event]
+pub struct RedemptionWindowOpened {
+    pub property_mint: Pubkey,
+    pub price_per_token: u64,
+    pub budget: u64,
+    pub max_tokens: u64,
+    pub closes_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensRedeemed {
+    pub property_mint: Pubkey,
+    pub investor: Pubkey,
+    pub tokens_burned: u64,
+    pub payout: u64,
+    pub remaining_budget: u64,
+    pub circulating_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionWindowClosed {
+    pub property_mint: Pubkey,
+    pub tokens_redeemed: u64,
+    pub spent: u64,
+    pub returned_to_reserve: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// EMERGENCY PAUSE EVENTS
+// ============================================================================
+
+#[event]
+pub struct PlatformGuardianUpdated {
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformAdminUpdated {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PropertyTransfersPauseChanged {
+    pub mint: Pubkey,
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GlobalTransfersPauseChanged {
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// GOVERNANCE EVENTS
+// ============================================================================
+
+#[event]
+pub struct GovernanceConfigured {
+    pub property_mint: Pubkey,
+    pub quorum_bps: u16,
+    pub approval_threshold_bps: u16,
+    pub voting_period_seconds: i64,
+    pub execution_delay_seconds: i64,
+    pub proposal_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub property_mint: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub snapshot_supply: u64,
+    pub voting_ends_at: i64,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub property_mint: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub property_mint: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub action: ProposalAction,
+    pub executor: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// ORDER BOOK EVENTS
+// ============================================================================
+
+#[event]
+pub struct OrderPlaced {
+    pub property_mint: Pubkey,
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub side: OrderSide,
+    pub price_per_token: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Trade print (one per fill) for price history
+#[event]
+pub struct OrderFilled {
+    pub property_mint: Pubkey,
+    pub order: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub side: OrderSide,
+    pub price_per_token: u64,
+    pub amount: u64,
+    pub lamports: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub property_mint: Pubkey,
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// LIQUIDITY POOL EVENTS
+// ============================================================================
+
+#[event]
+pub struct LiquidityPoolInitialized {
+    pub property_mint: Pubkey,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolFeesUpdated {
+    pub property_mint: Pubkey,
+    pub lp_fee_bps: u16,
+    pub platform_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub property_mint: Pubkey,
+    pub provider: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_minted: u64,
+    pub token_reserve: u64,
+    pub sol_reserve: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub property_mint: Pubkey,
+    pub provider: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub lp_burned: u64,
+    pub token_reserve: u64,
+    pub sol_reserve: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolSwap {
+    pub property_mint: Pubkey,
+    pub user: Pubkey,
+    pub direction: SwapDirection,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub platform_fee: u64,
+    pub token_reserve: u64,
+    pub sol_reserve: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// TRANSFER FEE EVENTS
+// ============================================================================
+
+#[event]
+pub struct TransferFeesHarvested {
+    pub mint: Pubkey,
+    pub harvested_accounts: u32,
+    pub total_withdrawn: u64,
+    pub platform_share: u64,
+    pub retired_share: u64,
+    pub total_supply: u64,
+    pub circulating_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeeUpdated {
+    pub mint: Pubkey,
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub retired_share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoyaltyCharged {
+    pub mint: Pubkey,
+    pub order: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+    pub royalty: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// FUNDING ROUND EVENTS
+// ============================================================================
+
+#[event]
+pub struct FundingRoundCreated {
+    pub property_mint: Pubkey,
+    pub funding_round: Pubkey,
+    pub round_index: u8,
+    pub price_per_token: u64,
+    pub token_cap: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub has_allowlist: bool,
+    pub required_credential_type: Option<u8>,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// INVESTMENT LIMIT EVENTS
+// ============================================================================
+
+#[event]
+pub struct InvestmentLimitsUpdated {
+    pub property_mint: Pubkey,
+    pub min_investment: u64,
+    pub max_investment_by_type: [u64; HUB_CREDENTIAL_TYPE_COUNT],
+    pub limit_period_seconds: i64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// COMPLIANCE RULE EVENTS
+// ============================================================================
+
+#[event]
+pub struct ComplianceRulesUpdated {
+    pub property_mint: Pubkey,
+    pub allowed_countries: Vec<[u8; 2]>,
+    pub blocked_countries: Vec<[u8; 2]>,
+    pub min_investor_tier: InvestorTier,
+    pub max_risk_band: RiskBand,
+    pub allow_pep: bool,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// DISTRIBUTOR EVENTS
+// ============================================================================
+
+#[event]
+pub struct DistributorUpdated {
+    pub distributor: Pubkey,
+    pub wallet: Pubkey,
+    pub commission_bps: u16,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributorCommissionAccrued {
+    pub distributor: Pubkey,
+    pub wallet: Pubkey,
+    pub property_mint: Pubkey,
+    pub investor: Pubkey,
+    pub investment_amount: u64,
+    pub commission: u64,
+    pub total_accrued: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributorCommissionWithdrawn {
+    pub distributor: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub total_paid: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// VESTING EVENTS
+// ============================================================================
+
+#[event]
+pub struct VestingScheduleCreated {
+    pub property_mint: Pubkey,
+    pub vesting_schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub total_amount: u64,
+    pub start_at: i64,
+    pub cliff_at: i64,
+    pub end_at: i64,
+    pub sponsor_allocated_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestedTokensClaimed {
+    pub property_mint: Pubkey,
+    pub vesting_schedule: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// STAKING EVENTS
+// ============================================================================
+
+#[event]
+pub struct TokensStaked {
+    pub property_mint: Pubkey,
+    pub stake_position: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub multiplier_bps: u16,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensUnstaked {
+    pub property_mint: Pubkey,
+    pub stake_position: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// BUNDLE EVENTS
+// ============================================================================
+
+#[event]
+pub struct BundleCreated {
+    pub bundle: Pubkey,
+    pub bundle_mint: Pubkey,
+    pub property_mints: Vec<Pubkey>,
+    pub amounts_per_unit: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleMinted {
+    pub bundle: Pubkey,
+    pub holder: Pubkey,
+    pub units: u64,
+    pub total_units: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleRedeemed {
+    pub bundle: Pubkey,
+    pub holder: Pubkey,
+    pub units: u64,
+    pub total_units: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleRevenueCollected {
+    pub bundle: Pubkey,
+    pub property_mint: Pubkey,
+    pub epoch: Pubkey,
+    pub amount: u64,
+    pub revenue_per_unit: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BundleRevenueClaimed {
+    pub bundle: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// ============================================================================
+// LENDING EVENTS
+// ============================================================================
+
+#[event]
+pub struct LendingMarketUpdated {
+    pub property_mint: Pubkey,
+    pub market: Pubkey,
+    pub loan_mint: Pubkey,
+    pub max_ltv_bps: u16,
+    pub liquidation_threshold_bps: u16,
+    pub liquidation_bonus_bps: u16,
+    pub interest_rate_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LendingValuationUpdated {
+    pub property_mint: Pubkey,
+    pub market: Pubkey,
+    pub previous_valuation_usd: u64,
+    pub valuation_usd: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LendingLiquidityWithdrawn {
+    pub property_mint: Pubkey,
+    pub market: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollateralDeposited {
+    pub property_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub amount: u64,
+    pub collateral_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollateralWithdrawn {
+    pub property_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub amount: u64,
+    pub collateral_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LoanBorrowed {
+    pub property_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub amount: u64,
+    pub debt: u64,
+    pub collateral_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LoanRepaid {
+    pub property_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub amount: u64,
+    pub debt: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LoanLiquidated {
+    pub property_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub liquidator: Pubkey,
+    pub repaid: u64,
+    pub collateral_seized: u64,
+    pub debt: u64,
+    pub collateral_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub mint: Pubkey,
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,864 @@
+/// AMM - Constant-product liquidity pool per property token
+///
+/// The pool keeps its property tokens in `token_vault`, a token account owned
+/// by the pool PDA. Swaps move tokens through the transfer hook, so they get
+/// the same KYC, status and pause checks (and transfer fee) as any secondary
+/// transfer. Since this program cannot transfer its own hooked mint, the
+/// token leg is the trader's own `TransferChecked` in the same transaction
+/// (see `utils::hooked_transfer`):
+/// - TokenToSol: trader -> vault, immediately before `swap`
+/// - SolToToken: vault -> trader, immediately after `swap`, as the delegate
+///   the swap approves for exactly the quoted amount
+///
+/// Adding and removing liquidity are not trades: tokens are re-issued
+/// between the provider and the vault (burn + mint), and LPs can exit while
+/// transfers are paused or the property is being liquidated.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    token_2022::{self, Approve, Burn, MintTo, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+// ============================================================================
+// INITIALIZE POOL
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LiquidityPool::INIT_SPACE,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// LP token mint (no transfer hook)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_MINT_SEED, mint.key().as_ref()],
+        bump,
+        mint::decimals = mint.decimals,
+        mint::authority = liquidity_pool,
+        mint::token_program = token_program,
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Property token vault (holds the token reserve)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [POOL_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = liquidity_pool,
+        token::token_program = token_program,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for initialize_pool instruction
+/// The platform fee starts at the default; only the platform admin changes it
+pub fn handler_initialize_pool(ctx: Context<InitializePool>, lp_fee_bps: u16) -> Result<()> {
+    let platform_fee_bps = DEFAULT_POOL_PLATFORM_FEE_BPS;
+    LiquidityPool::validate_fees(lp_fee_bps, platform_fee_bps)?;
+
+    let clock = Clock::get()?;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.property_state = ctx.accounts.property_state.key();
+    liquidity_pool.mint = ctx.accounts.mint.key();
+    liquidity_pool.lp_mint = ctx.accounts.lp_mint.key();
+    liquidity_pool.token_vault = ctx.accounts.token_vault.key();
+    liquidity_pool.token_reserve = 0;
+    liquidity_pool.sol_reserve = 0;
+    liquidity_pool.lp_fee_bps = lp_fee_bps;
+    liquidity_pool.platform_fee_bps = platform_fee_bps;
+    liquidity_pool.created_at = clock.unix_timestamp;
+    liquidity_pool.bump = ctx.bumps.liquidity_pool;
+
+    emit!(LiquidityPoolInitialized {
+        property_mint: liquidity_pool.mint,
+        pool: liquidity_pool.key(),
+        lp_mint: liquidity_pool.lp_mint,
+        lp_fee_bps,
+        platform_fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Liquidity pool initialized: LP fee {} bps, platform fee {} bps",
+        lp_fee_bps,
+        platform_fee_bps
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE POOL FEES
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdatePoolFees<'info> {
+    /// Property authority
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+}
+
+/// Handler for update_pool_fees instruction
+/// Sets the LP fee; the platform fee is kept
+pub fn handler_update_pool_fees(ctx: Context<UpdatePoolFees>, lp_fee_bps: u16) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    LiquidityPool::validate_fees(lp_fee_bps, liquidity_pool.platform_fee_bps)?;
+    liquidity_pool.lp_fee_bps = lp_fee_bps;
+
+    emit!(PoolFeesUpdated {
+        property_mint: ctx.accounts.mint.key(),
+        lp_fee_bps,
+        platform_fee_bps: liquidity_pool.platform_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE POOL PLATFORM FEE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdatePoolPlatformFee<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+}
+
+/// Handler for update_pool_platform_fee instruction
+/// Sets the platform fee; the LP fee is kept
+pub fn handler_update_pool_platform_fee(
+    ctx: Context<UpdatePoolPlatformFee>,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    LiquidityPool::validate_fees(liquidity_pool.lp_fee_bps, platform_fee_bps)?;
+    liquidity_pool.platform_fee_bps = platform_fee_bps;
+
+    emit!(PoolFeesUpdated {
+        property_mint: ctx.accounts.mint.key(),
+        lp_fee_bps: liquidity_pool.lp_fee_bps,
+        platform_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// ADD LIQUIDITY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    /// Liquidity provider
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA (holds the SOL reserve)
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint @ RwaError::InvalidMint,
+        has_one = token_vault @ RwaError::InvalidPoolTransfer,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// LP token mint
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool property token vault
+    #[account(mut)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's property token account
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ RwaError::Unauthorized,
+        constraint = provider_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's LP token account
+    #[account(
+        mut,
+        constraint = provider_lp_account.owner == provider.key() @ RwaError::Unauthorized,
+        constraint = provider_lp_account.mint == lp_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for provider KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub provider_credential: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions for the provider
+    /// CHECK: May not be initialized; read via ComplianceRules::enforce
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for add_liquidity instruction
+pub fn handler_add_liquidity(
+    ctx: Context<AddLiquidity>,
+    token_amount: u64,
+    max_sol_amount: u64,
+    min_lp_out: u64,
+) -> Result<()> {
+    require!(token_amount > 0 && max_sol_amount > 0, RwaError::InvalidAmount);
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    let credential = verify_hub_credential(
+        &ctx.accounts.provider_credential.to_account_info(),
+        &ctx.accounts.provider.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+    // LP tokens are a claim on the pool's property tokens
+    ComplianceRules::enforce(&ctx.accounts.compliance_rules, &credential.attributes)?;
+
+    // 1. Quote LP tokens and SOL at the current ratio
+    let (lp_minted, sol_amount) = ctx.accounts.liquidity_pool.quote_add_liquidity(
+        token_amount,
+        max_sol_amount,
+        ctx.accounts.lp_mint.supply,
+    )?;
+    require!(lp_minted > 0 && sol_amount > 0, RwaError::InsufficientLiquidity);
+    require!(
+        lp_minted >= min_lp_out && sol_amount <= max_sol_amount,
+        RwaError::SlippageExceeded
+    );
+
+    // 2. Deposit tokens (vault) and SOL (pool PDA)
+    reissue_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.provider_token_account,
+        &ctx.accounts.provider.to_account_info(),
+        &[],
+        &ctx.accounts.token_vault,
+        &ctx.accounts.property_state,
+        token_amount,
+    )?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+        ),
+        sol_amount,
+    )?;
+
+    // 3. Mint LP tokens
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        LIQUIDITY_POOL_SEED,
+        mint_key.as_ref(),
+        &[ctx.accounts.liquidity_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        lp_minted,
+    )?;
+
+    // 4. Update reserves
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.token_reserve = liquidity_pool
+        .token_reserve
+        .checked_add(token_amount)
+        .ok_or(RwaError::MathOverflow)?;
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_add(sol_amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.pool_supply = property_state
+        .pool_supply
+        .checked_add(token_amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(LiquidityAdded {
+        property_mint: mint_key,
+        provider: ctx.accounts.provider.key(),
+        token_amount,
+        sol_amount,
+        lp_minted,
+        token_reserve: liquidity_pool.token_reserve,
+        sol_reserve: liquidity_pool.sol_reserve,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Added liquidity: {} tokens + {} lamports for {} LP",
+        token_amount,
+        sol_amount,
+        lp_minted
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// REMOVE LIQUIDITY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    /// Liquidity provider
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint @ RwaError::InvalidMint,
+        has_one = token_vault @ RwaError::InvalidPoolTransfer,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// LP token mint
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool property token vault
+    #[account(mut)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's property token account
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ RwaError::Unauthorized,
+        constraint = provider_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Provider's LP token account
+    #[account(
+        mut,
+        constraint = provider_lp_account.owner == provider.key() @ RwaError::Unauthorized,
+        constraint = provider_lp_account.mint == lp_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for provider KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub provider_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for remove_liquidity instruction
+pub fn handler_remove_liquidity(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_token_out: u64,
+    min_sol_out: u64,
+) -> Result<()> {
+    require!(lp_amount > 0, RwaError::InvalidAmount);
+    require!(
+        ctx.accounts.provider_lp_account.amount >= lp_amount,
+        RwaError::InsufficientBalance
+    );
+
+    // Exits stay open while transfers are paused or the property is being
+    // liquidated, like cancel_order and unstake.
+    // Released tokens bypass the transfer hook - verify KYC here
+    verify_hub_credential(
+        &ctx.accounts.provider_credential.to_account_info(),
+        &ctx.accounts.provider.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    // 1. Quote the pro-rata share of both reserves
+    let (token_out, sol_out) = ctx
+        .accounts
+        .liquidity_pool
+        .quote_remove_liquidity(lp_amount, ctx.accounts.lp_mint.supply)?;
+    require!(
+        token_out >= min_token_out && sol_out >= min_sol_out,
+        RwaError::SlippageExceeded
+    );
+
+    // 2. Burn LP tokens
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+
+    // 3. Return tokens and SOL
+    if token_out > 0 {
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            LIQUIDITY_POOL_SEED,
+            mint_key.as_ref(),
+            &[ctx.accounts.liquidity_pool.bump],
+        ];
+
+        reissue_property_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_vault,
+            &ctx.accounts.liquidity_pool.to_account_info(),
+            &[&seeds[..]],
+            &ctx.accounts.provider_token_account,
+            &ctx.accounts.property_state,
+            token_out,
+        )?;
+    }
+
+    // Pool PDA is owned by this program
+    **ctx
+        .accounts
+        .liquidity_pool
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= sol_out;
+    **ctx.accounts.provider.to_account_info().try_borrow_mut_lamports()? += sol_out;
+
+    // 4. Update reserves
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.token_reserve = liquidity_pool
+        .token_reserve
+        .checked_sub(token_out)
+        .ok_or(RwaError::MathOverflow)?;
+    liquidity_pool.sol_reserve = liquidity_pool
+        .sol_reserve
+        .checked_sub(sol_out)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.pool_supply = property_state
+        .pool_supply
+        .checked_sub(token_out)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(LiquidityRemoved {
+        property_mint: ctx.accounts.mint.key(),
+        provider: ctx.accounts.provider.key(),
+        token_amount: token_out,
+        sol_amount: sol_out,
+        lp_burned: lp_amount,
+        token_reserve: liquidity_pool.token_reserve,
+        sol_reserve: liquidity_pool.sol_reserve,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Removed liquidity: {} LP for {} tokens + {} lamports",
+        lp_amount,
+        token_out,
+        sol_out
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// SWAP
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    /// Trader
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidity Pool PDA
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, mint.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = token_vault @ RwaError::InvalidPoolTransfer,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    /// Pool property token vault
+    #[account(mut)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Trader's property token account
+    #[account(
+        constraint = user_token_account.owner == user.key() @ RwaError::Unauthorized,
+        constraint = user_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for trader KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub user_credential: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions for the trader
+    /// CHECK: May not be initialized; read via ComplianceRules::enforce
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    /// Platform treasury (receives the platform fee)
+    /// CHECK: Validated against PLATFORM_TREASURY constant
+    #[account(
+        mut,
+        address = PLATFORM_TREASURY @ RwaError::InvalidPlatformTreasury,
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Instructions sysvar (locates the paired token transfer)
+    /// CHECK: Address checked
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for swap instruction
+///
+/// - SolToToken: the next instruction must be the trader's `TransferChecked`
+///   of `amount_out` tokens from the vault, signed as the approved delegate
+/// - TokenToSol: the previous instruction must be the trader's
+///   `TransferChecked` of `amount_in` tokens into the vault; the transfer fee
+///   withheld by Token-2022 is not part of the swap input
+pub fn handler_swap(
+    ctx: Context<Swap>,
+    direction: SwapDirection,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<()> {
+    require!(amount_in > 0, RwaError::InvalidAmount);
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    let credential = verify_hub_credential(
+        &ctx.accounts.user_credential.to_account_info(),
+        &ctx.accounts.user.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+    // Only buyers must meet the property's eligibility rules; sellers can exit
+    if direction == SwapDirection::SolToToken {
+        ComplianceRules::enforce(&ctx.accounts.compliance_rules, &credential.attributes)?;
+    }
+
+    let mint_key = ctx.accounts.mint.key();
+    let vault_key = ctx.accounts.token_vault.key();
+    let user_token_key = ctx.accounts.user_token_account.key();
+    let user_key = ctx.accounts.user.key();
+
+    // 1. Tokens received by the pool (TokenToSol) net of the transfer fee
+    let pool_in = match direction {
+        SwapDirection::SolToToken => amount_in,
+        SwapDirection::TokenToSol => {
+            require_hooked_transfer(
+                &ctx.accounts.instructions_sysvar,
+                -1,
+                &mint_key,
+                &user_token_key,
+                &vault_key,
+                &user_key,
+                amount_in,
+            )?;
+
+            let received = amount_in
+                .checked_sub(transfer_fee_for(&ctx.accounts.mint, amount_in)?)
+                .ok_or(RwaError::MathOverflow)?;
+            let vault_surplus = ctx
+                .accounts
+                .token_vault
+                .amount
+                .saturating_sub(ctx.accounts.liquidity_pool.token_reserve);
+            require!(received <= vault_surplus, RwaError::InvalidPoolTransfer);
+            received
+        }
+    };
+
+    // 2. Quote against the current reserves
+    let quote = ctx.accounts.liquidity_pool.quote_swap(direction, pool_in)?;
+    require!(quote.amount_out > 0, RwaError::TradeTooSmall);
+    require!(
+        quote.amount_out >= min_amount_out,
+        RwaError::SlippageExceeded
+    );
+
+    // 3. Settle
+    match direction {
+        SwapDirection::SolToToken => {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.liquidity_pool.to_account_info(),
+                    },
+                ),
+                quote.net_in,
+            )?;
+            if quote.platform_fee > 0 {
+                transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.user.to_account_info(),
+                            to: ctx.accounts.platform_treasury.to_account_info(),
+                        },
+                    ),
+                    quote.platform_fee,
+                )?;
+            }
+
+            // The trader pulls the tokens through the hook in the next instruction
+            let seeds = &[
+                LIQUIDITY_POOL_SEED,
+                mint_key.as_ref(),
+                &[ctx.accounts.liquidity_pool.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            token_2022::approve(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Approve {
+                        to: ctx.accounts.token_vault.to_account_info(),
+                        delegate: ctx.accounts.user.to_account_info(),
+                        authority: ctx.accounts.liquidity_pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                quote.amount_out,
+            )?;
+            require_hooked_transfer(
+                &ctx.accounts.instructions_sysvar,
+                1,
+                &mint_key,
+                &vault_key,
+                &user_token_key,
+                &user_key,
+                quote.amount_out,
+            )?;
+
+            let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+            liquidity_pool.sol_reserve = liquidity_pool
+                .sol_reserve
+                .checked_add(quote.net_in)
+                .ok_or(RwaError::MathOverflow)?;
+            liquidity_pool.token_reserve = liquidity_pool
+                .token_reserve
+                .checked_sub(quote.reserve_out)
+                .ok_or(RwaError::MathOverflow)?;
+
+            let property_state = &mut ctx.accounts.property_state;
+            property_state.pool_supply = property_state
+                .pool_supply
+                .checked_sub(quote.reserve_out)
+                .ok_or(RwaError::MathOverflow)?;
+        }
+        SwapDirection::TokenToSol => {
+            // Pool PDA is owned by this program
+            **ctx
+                .accounts
+                .liquidity_pool
+                .to_account_info()
+                .try_borrow_mut_lamports()? -= quote.reserve_out;
+            **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += quote.amount_out;
+            **ctx
+                .accounts
+                .platform_treasury
+                .to_account_info()
+                .try_borrow_mut_lamports()? += quote.platform_fee;
+
+            let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+            liquidity_pool.token_reserve = liquidity_pool
+                .token_reserve
+                .checked_add(pool_in)
+                .ok_or(RwaError::MathOverflow)?;
+            liquidity_pool.sol_reserve = liquidity_pool
+                .sol_reserve
+                .checked_sub(quote.reserve_out)
+                .ok_or(RwaError::MathOverflow)?;
+
+            let property_state = &mut ctx.accounts.property_state;
+            property_state.pool_supply = property_state
+                .pool_supply
+                .checked_add(pool_in)
+                .ok_or(RwaError::MathOverflow)?;
+        }
+    }
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    emit!(PoolSwap {
+        property_mint: mint_key,
+        user: user_key,
+        direction,
+        amount_in,
+        amount_out: quote.amount_out,
+        platform_fee: quote.platform_fee,
+        token_reserve: liquidity_pool.token_reserve,
+        sol_reserve: liquidity_pool.sol_reserve,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Swap {:?}: {} in, {} out (platform fee {} lamports)",
+        direction,
+        amount_in,
+        quote.amount_out,
+        quote.platform_fee
+    );
+
+    Ok(())
+}
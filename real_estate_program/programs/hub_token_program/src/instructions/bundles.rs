@@ -0,0 +1,742 @@
+/// Bundles - Multi-property index tokens
+///
+/// The platform admin defines a basket of property tokens and a bundle
+/// mint. Holders deposit the basket to mint bundle tokens and burn bundle
+/// tokens to get the basket back. Basket tokens sit in program escrow of
+/// each property, so the Bundle PDA needs no credential of its own.
+///
+/// Revenue epochs of the underlying properties are collected into the
+/// Bundle PDA (`collect_bundle_revenue`) and claimed by bundle holders in
+/// proportion to the bundle tokens they held (`claim_bundle_revenue`).
+/// Minting and redeeming wait until the bundle has collected every epoch
+/// deposited since its units last changed (see `Bundle`).
+///
+/// Redeeming stays open while a property is paused or liquidating, like
+/// cancel_order and unstake, so holders can always get their basket back.
+///
+/// Per-component accounts are passed as `remaining_accounts`, four per
+/// basket entry in basket order:
+/// 0. PropertyState PDA (mut)
+/// 1. Property mint (mut)
+/// 2. Holder's property token account (mut)
+/// 3. ComplianceRules PDA (may be uninitialized)
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{
+    token_2022::{
+        self,
+        spl_token_2022::{
+            extension::ExtensionType, instruction::initialize_mint2, state::Mint as MintState,
+        },
+        Burn, MintTo, Token2022,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    constants::*,
+    error::RwaError,
+    events::*,
+    instructions::revenue_vault::{
+        ClaimRecord, RevenueEpoch, CLAIM_RECORD_SEED, REVENUE_VAULT_SEED,
+    },
+    state::*,
+    utils::*,
+};
+
+/// Accounts passed per basket entry in `remaining_accounts`
+const COMPONENT_ACCOUNT_COUNT: usize = 4;
+
+// ============================================================================
+// CREATE BUNDLE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateBundle<'info> {
+    /// Platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// The bundle mint to be created
+    /// CHECK: Will be initialized as a non-transferable Token-2022 mint
+    #[account(
+        mut,
+        signer,
+    )]
+    pub bundle_mint: AccountInfo<'info>,
+
+    /// Bundle PDA (mint authority)
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Bundle::INIT_SPACE,
+        seeds = [BUNDLE_SEED, bundle_mint.key().as_ref()],
+        bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_bundle instruction
+pub fn handler_create_bundle(
+    ctx: Context<CreateBundle>,
+    components: Vec<BundleComponent>,
+) -> Result<()> {
+    Bundle::validate_components(&components)?;
+
+    let bundle_mint = &ctx.accounts.bundle_mint;
+    let bundle_key = ctx.accounts.bundle.key();
+
+    // Create the mint account with the NonTransferable extension
+    let mint_size =
+        ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::NonTransferable])
+            .map_err(|_| RwaError::MathOverflow)?;
+    let lamports = Rent::get()?.minimum_balance(mint_size);
+
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.admin.to_account_info(),
+                to: bundle_mint.to_account_info(),
+            },
+        ),
+        lamports,
+        mint_size as u64,
+        &token_2022::ID,
+    )?;
+
+    // Bundle tokens never move between wallets (see Bundle docs)
+    let init_non_transferable_ix =
+        token_2022::spl_token_2022::instruction::initialize_non_transferable_mint(
+            &token_2022::ID,
+            &bundle_mint.key(),
+        )?;
+    anchor_lang::solana_program::program::invoke(
+        &init_non_transferable_ix,
+        &[bundle_mint.to_account_info()],
+    )?;
+
+    let init_mint_ix = initialize_mint2(
+        &token_2022::ID,
+        &bundle_mint.key(),
+        &bundle_key, // Mint authority = Bundle PDA
+        None,
+        0, // 1 bundle token = 1 basket
+    )?;
+    anchor_lang::solana_program::program::invoke(&init_mint_ix, &[bundle_mint.to_account_info()])?;
+
+    let clock = Clock::get()?;
+    let bundle = &mut ctx.accounts.bundle;
+    bundle.authority = ctx.accounts.admin.key();
+    bundle.bundle_mint = bundle_mint.key();
+    bundle.component_count = components.len() as u8;
+    bundle.components = [BundleComponent {
+        property_mint: Pubkey::default(),
+        amount_per_unit: 0,
+    }; MAX_BUNDLE_COMPONENTS];
+    bundle.components[..components.len()].copy_from_slice(&components);
+    bundle.total_units = 0;
+    bundle.revenue_per_unit = 0;
+    bundle.total_revenue_collected = 0;
+    bundle.units_changed_at = clock.unix_timestamp;
+    bundle.epochs_seen = [0; MAX_BUNDLE_COMPONENTS];
+    bundle.epochs_collected = [0; MAX_BUNDLE_COMPONENTS];
+    bundle.created_at = clock.unix_timestamp;
+    bundle.bump = ctx.bumps.bundle;
+
+    emit!(BundleCreated {
+        bundle: bundle_key,
+        bundle_mint: bundle.bundle_mint,
+        property_mints: components.iter().map(|c| c.property_mint).collect(),
+        amounts_per_unit: components.iter().map(|c| c.amount_per_unit).collect(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bundle {} created with {} properties",
+        bundle.bundle_mint,
+        components.len()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// MINT BUNDLE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MintBundle<'info> {
+    /// Holder depositing the basket
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Bundle PDA
+    #[account(
+        mut,
+        seeds = [BUNDLE_SEED, bundle_mint.key().as_ref()],
+        bump = bundle.bump,
+        has_one = bundle_mint @ RwaError::InvalidMint,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    /// Bundle token mint
+    #[account(mut)]
+    pub bundle_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's bundle token account
+    #[account(
+        mut,
+        constraint = holder_bundle_account.owner == holder.key() @ RwaError::Unauthorized,
+        constraint = holder_bundle_account.mint == bundle_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub holder_bundle_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bundle Holder PDA (revenue checkpoint)
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + BundleHolder::INIT_SPACE,
+        seeds = [BUNDLE_HOLDER_SEED, bundle.key().as_ref(), holder.key().as_ref()],
+        bump,
+    )]
+    pub bundle_holder: Box<Account<'info, BundleHolder>>,
+
+    /// Hub Credential account for holder KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub holder_credential: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA (global transfer pause)
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: per-component accounts (see module docs)
+}
+
+/// Handler for mint_bundle instruction
+pub fn handler_mint_bundle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintBundle<'info>>,
+    units: u64,
+) -> Result<()> {
+    require!(units > 0, RwaError::InvalidAmount);
+
+    // 1. Bundle tokens bypass the transfer hook - verify KYC here
+    let credential = verify_hub_credential(
+        &ctx.accounts.holder_credential.to_account_info(),
+        &ctx.accounts.holder.key(),
+        &ctx.accounts.bundle_mint.key(),
+    )?;
+
+    // 2. Lock the basket in program escrow
+    let now = Clock::get()?.unix_timestamp;
+    let components = ctx.accounts.bundle.active_components().to_vec();
+    require!(
+        ctx.remaining_accounts.len() == components.len() * COMPONENT_ACCOUNT_COUNT,
+        RwaError::InvalidBundleAccounts
+    );
+
+    for (index, component) in components.iter().enumerate() {
+        let ComponentAccounts {
+            mut property_state,
+            mint,
+            holder_token_account,
+            compliance_rules,
+        } = load_component_accounts(
+            ctx.remaining_accounts,
+            index,
+            component,
+            &ctx.accounts.holder.key(),
+        )?;
+        property_state.ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+        ComplianceRules::enforce(compliance_rules, &credential.attributes)?;
+        require!(
+            ctx.accounts
+                .bundle
+                .revenue_settled(index, &property_state, now),
+            RwaError::BundleRevenueOutstanding
+        );
+        ctx.accounts
+            .bundle
+            .checkpoint_component(index, &property_state);
+
+        let amount = component.basket_amount(units)?;
+        escrow_property_tokens(
+            &ctx.accounts.token_program,
+            &mint,
+            &holder_token_account,
+            &ctx.accounts.holder,
+            &mut property_state,
+            amount,
+        )?;
+        property_state.bundled_supply = property_state
+            .bundled_supply
+            .checked_add(amount)
+            .ok_or(RwaError::MathOverflow)?;
+        property_state.exit(&crate::ID)?;
+    }
+
+    // 3. Mint bundle tokens to the holder
+    let bundle = &ctx.accounts.bundle;
+    let bundle_mint_key = ctx.accounts.bundle_mint.key();
+    let seeds = &[BUNDLE_SEED, bundle_mint_key.as_ref(), &[bundle.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.bundle_mint.to_account_info(),
+                to: ctx.accounts.holder_bundle_account.to_account_info(),
+                authority: ctx.accounts.bundle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        units,
+    )?;
+
+    // 4. Update holder checkpoint and bundle supply
+    let bundle = &mut ctx.accounts.bundle;
+    let bundle_holder = &mut ctx.accounts.bundle_holder;
+    if bundle_holder.holder == Pubkey::default() {
+        bundle_holder.bundle = bundle.key();
+        bundle_holder.holder = ctx.accounts.holder.key();
+        bundle_holder.bump = ctx.bumps.bundle_holder;
+    }
+    let new_units = bundle_holder
+        .units
+        .checked_add(units)
+        .ok_or(RwaError::MathOverflow)?;
+    bundle_holder.set_units(new_units, bundle.revenue_per_unit)?;
+
+    bundle.total_units = bundle
+        .total_units
+        .checked_add(units)
+        .ok_or(RwaError::MathOverflow)?;
+    bundle.units_changed_at = now;
+
+    emit!(BundleMinted {
+        bundle: bundle.key(),
+        holder: bundle_holder.holder,
+        units,
+        total_units: bundle.total_units,
+        timestamp: now,
+    });
+
+    msg!("Minted {} bundle tokens to {}", units, bundle_holder.holder);
+
+    Ok(())
+}
+
+// ============================================================================
+// REDEEM BUNDLE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RedeemBundle<'info> {
+    /// Holder redeeming bundle tokens
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Bundle PDA
+    #[account(
+        mut,
+        seeds = [BUNDLE_SEED, bundle_mint.key().as_ref()],
+        bump = bundle.bump,
+        has_one = bundle_mint @ RwaError::InvalidMint,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    /// Bundle token mint
+    #[account(mut)]
+    pub bundle_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's bundle token account
+    #[account(
+        mut,
+        constraint = holder_bundle_account.owner == holder.key() @ RwaError::Unauthorized,
+        constraint = holder_bundle_account.mint == bundle_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub holder_bundle_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bundle Holder PDA (revenue checkpoint)
+    #[account(
+        mut,
+        seeds = [BUNDLE_HOLDER_SEED, bundle.key().as_ref(), holder.key().as_ref()],
+        bump = bundle_holder.bump,
+        has_one = holder @ RwaError::Unauthorized,
+    )]
+    pub bundle_holder: Box<Account<'info, BundleHolder>>,
+
+    /// Hub Credential account for holder KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub holder_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    // remaining_accounts: per-component accounts (see module docs)
+}
+
+/// Handler for redeem_bundle instruction
+pub fn handler_redeem_bundle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemBundle<'info>>,
+    units: u64,
+) -> Result<()> {
+    require!(units > 0, RwaError::InvalidAmount);
+    require!(
+        ctx.accounts.bundle_holder.units >= units,
+        RwaError::InsufficientBalance
+    );
+
+    // 1. Burn the bundle tokens
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.bundle_mint.to_account_info(),
+                from: ctx.accounts.holder_bundle_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        units,
+    )?;
+
+    // 2. Release the basket to the holder (exits stay open while paused or
+    // liquidating, like cancel_order and unstake)
+    let now = Clock::get()?.unix_timestamp;
+    let components = ctx.accounts.bundle.active_components().to_vec();
+    require!(
+        ctx.remaining_accounts.len() == components.len() * COMPONENT_ACCOUNT_COUNT,
+        RwaError::InvalidBundleAccounts
+    );
+
+    for (index, component) in components.iter().enumerate() {
+        let ComponentAccounts {
+            mut property_state,
+            mint,
+            holder_token_account,
+            compliance_rules,
+        } = load_component_accounts(
+            ctx.remaining_accounts,
+            index,
+            component,
+            &ctx.accounts.holder.key(),
+        )?;
+        require!(
+            ctx.accounts
+                .bundle
+                .revenue_settled(index, &property_state, now),
+            RwaError::BundleRevenueOutstanding
+        );
+        ctx.accounts
+            .bundle
+            .checkpoint_component(index, &property_state);
+
+        // Released tokens bypass the transfer hook - verify KYC and
+        // eligibility here
+        let credential = verify_hub_credential(
+            &ctx.accounts.holder_credential.to_account_info(),
+            &ctx.accounts.holder.key(),
+            &mint.key(),
+        )?;
+        ComplianceRules::enforce(compliance_rules, &credential.attributes)?;
+
+        let amount = component.basket_amount(units)?;
+        release_property_tokens(
+            &ctx.accounts.token_program,
+            &mint,
+            &holder_token_account,
+            &mut property_state,
+            amount,
+        )?;
+        property_state.bundled_supply = property_state
+            .bundled_supply
+            .checked_sub(amount)
+            .ok_or(RwaError::MathOverflow)?;
+        property_state.exit(&crate::ID)?;
+    }
+
+    // 3. Update holder checkpoint and bundle supply
+    let bundle = &mut ctx.accounts.bundle;
+    let bundle_holder = &mut ctx.accounts.bundle_holder;
+    let new_units = bundle_holder.units - units;
+    bundle_holder.set_units(new_units, bundle.revenue_per_unit)?;
+
+    bundle.total_units = bundle
+        .total_units
+        .checked_sub(units)
+        .ok_or(RwaError::MathOverflow)?;
+    bundle.units_changed_at = now;
+
+    emit!(BundleRedeemed {
+        bundle: bundle.key(),
+        holder: bundle_holder.holder,
+        units,
+        total_units: bundle.total_units,
+        timestamp: now,
+    });
+
+    msg!("Redeemed {} bundle tokens for {}", units, bundle_holder.holder);
+
+    Ok(())
+}
+
+// ============================================================================
+// COLLECT BUNDLE REVENUE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CollectBundleRevenue<'info> {
+    /// Anyone can collect (pays for the claim record)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Bundle PDA (receives the revenue)
+    #[account(
+        mut,
+        seeds = [BUNDLE_SEED, bundle.bundle_mint.as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    /// PropertyState of the underlying property
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, property_state.mint.as_ref()],
+        bump = property_state.bump,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// Revenue Epoch being collected from
+    #[account(
+        constraint = revenue_epoch.property_state == property_state.key() @ RwaError::InvalidEpoch,
+        constraint = revenue_epoch.is_finalized @ RwaError::EpochNotFinalized,
+    )]
+    pub revenue_epoch: Box<Account<'info, RevenueEpoch>>,
+
+    /// Claim Record PDA - the bundle claims each epoch once
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ClaimRecord::INIT_SPACE,
+        seeds = [CLAIM_RECORD_SEED, revenue_epoch.key().as_ref(), bundle.key().as_ref()],
+        bump,
+    )]
+    pub claim_record: Box<Account<'info, ClaimRecord>>,
+
+    /// Revenue Vault PDA - holds the SOL
+    /// CHECK: PDA that holds SOL, validated by seeds
+    #[account(
+        mut,
+        seeds = [REVENUE_VAULT_SEED, revenue_epoch.key().as_ref()],
+        bump,
+    )]
+    pub revenue_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for collect_bundle_revenue instruction
+///
+/// The bundle's weight is the basket amount for every outstanding bundle
+/// token. Units cannot change while an epoch is uncollected, and epochs
+/// deposited before the last change are refused, so this is the bundle's
+/// weight when the epoch was deposited. Epochs worth nothing to the bundle
+/// are still recorded so its units can change again.
+pub fn handler_collect_bundle_revenue(ctx: Context<CollectBundleRevenue>) -> Result<()> {
+    let revenue_epoch = &ctx.accounts.revenue_epoch;
+    let bundle = &ctx.accounts.bundle;
+    let (index, component) = bundle
+        .component(&ctx.accounts.property_state.mint)
+        .ok_or(RwaError::InvalidBundleAccounts)?;
+    require!(
+        revenue_epoch.deposited_at >= bundle.units_changed_at,
+        RwaError::EpochBeforeBundleUnits
+    );
+
+    let weight = component.basket_amount(bundle.total_units)?;
+    let amount = (weight as u128)
+        .checked_mul(revenue_epoch.total_revenue as u128)
+        .ok_or(RwaError::MathOverflow)?
+        .checked_div(revenue_epoch.eligible_supply as u128)
+        .ok_or(RwaError::MathOverflow)? as u64;
+    require!(
+        ctx.accounts.revenue_vault.lamports() >= amount,
+        RwaError::InsufficientVaultBalance
+    );
+
+    let clock = Clock::get()?;
+    let claim_record = &mut ctx.accounts.claim_record;
+    claim_record.epoch = revenue_epoch.key();
+    claim_record.investor = bundle.key();
+    claim_record.amount_claimed = amount;
+    claim_record.claimed_at = clock.unix_timestamp;
+    claim_record.bump = ctx.bumps.claim_record;
+
+    // Vault and Bundle are both PDAs we control
+    **ctx.accounts.revenue_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.bundle.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let bundle = &mut ctx.accounts.bundle;
+    if amount > 0 {
+        bundle.distribute_revenue(amount)?;
+    }
+    bundle.epochs_collected[index] = bundle.epochs_collected[index]
+        .checked_add(1)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(BundleRevenueCollected {
+        bundle: bundle.key(),
+        property_mint: ctx.accounts.property_state.mint,
+        epoch: ctx.accounts.revenue_epoch.key(),
+        amount,
+        revenue_per_unit: bundle.revenue_per_unit,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Bundle collected {} lamports from epoch {}",
+        amount,
+        ctx.accounts.revenue_epoch.epoch_number
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM BUNDLE REVENUE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimBundleRevenue<'info> {
+    /// Bundle holder
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Bundle PDA (holds collected revenue)
+    #[account(
+        mut,
+        seeds = [BUNDLE_SEED, bundle.bundle_mint.as_ref()],
+        bump = bundle.bump,
+    )]
+    pub bundle: Box<Account<'info, Bundle>>,
+
+    /// Bundle Holder PDA (revenue checkpoint)
+    #[account(
+        mut,
+        seeds = [BUNDLE_HOLDER_SEED, bundle.key().as_ref(), holder.key().as_ref()],
+        bump = bundle_holder.bump,
+        has_one = holder @ RwaError::Unauthorized,
+    )]
+    pub bundle_holder: Box<Account<'info, BundleHolder>>,
+}
+
+/// Handler for claim_bundle_revenue instruction
+pub fn handler_claim_bundle_revenue(ctx: Context<ClaimBundleRevenue>) -> Result<()> {
+    let revenue_per_unit = ctx.accounts.bundle.revenue_per_unit;
+    let bundle_holder = &mut ctx.accounts.bundle_holder;
+    bundle_holder.settle(revenue_per_unit)?;
+
+    let amount = bundle_holder.pending_revenue;
+    require!(amount > 0, RwaError::NoFundsAvailable);
+    bundle_holder.pending_revenue = 0;
+
+    // Bundle PDA is owned by this program
+    **ctx.accounts.bundle.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.holder.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(BundleRevenueClaimed {
+        bundle: ctx.accounts.bundle.key(),
+        holder: ctx.accounts.holder.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Bundle revenue claimed: {} lamports by {}",
+        amount,
+        ctx.accounts.holder.key()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Accounts for one basket entry (see module docs)
+struct ComponentAccounts<'info> {
+    property_state: Account<'info, PropertyState>,
+    mint: InterfaceAccount<'info, Mint>,
+    holder_token_account: InterfaceAccount<'info, TokenAccount>,
+    compliance_rules: &'info AccountInfo<'info>,
+}
+
+/// Loads and checks the accounts for one basket entry
+fn load_component_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    index: usize,
+    component: &BundleComponent,
+    holder: &Pubkey,
+) -> Result<ComponentAccounts<'info>> {
+    let offset = index * COMPONENT_ACCOUNT_COUNT;
+    let property_state = Account::<PropertyState>::try_from(&remaining_accounts[offset])?;
+    let mint = InterfaceAccount::<Mint>::try_from(&remaining_accounts[offset + 1])?;
+    let holder_token_account =
+        InterfaceAccount::<TokenAccount>::try_from(&remaining_accounts[offset + 2])?;
+    let compliance_rules = &remaining_accounts[offset + 3];
+
+    let expected_property_state = Pubkey::create_program_address(
+        &[
+            PROPERTY_STATE_SEED,
+            component.property_mint.as_ref(),
+            &[property_state.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| RwaError::InvalidBundleAccounts)?;
+
+    require!(
+        property_state.key() == expected_property_state
+            && property_state.mint == component.property_mint
+            && mint.key() == component.property_mint,
+        RwaError::InvalidBundleAccounts
+    );
+    require!(
+        holder_token_account.owner == *holder
+            && holder_token_account.mint == component.property_mint,
+        RwaError::InvalidBundleAccounts
+    );
+
+    let (expected_compliance_rules, _) = Pubkey::find_program_address(
+        &[COMPLIANCE_RULES_SEED, component.property_mint.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        compliance_rules.key(),
+        expected_compliance_rules,
+        RwaError::InvalidBundleAccounts
+    );
+
+    Ok(ComponentAccounts {
+        property_state,
+        mint,
+        holder_token_account,
+        compliance_rules,
+    })
+}
@@ -38,19 +38,19 @@ pub fn handler(ctx: Context<BurnPropertyTokens>, amount: u64) -> Result<()> {
     let property_state = &mut ctx.accounts.property_state;
 
     // Burn tokens
-    let cpi_context = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Burn {
-            mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.investor_token_account.to_account_info(),
-            authority: ctx.accounts.investor.to_account_info(),
-        },
-    );
-
-    token_2022::burn(cpi_context, amount)?;
+    burn_investor_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.investor_token_account,
+        &ctx.accounts.investor,
+        amount,
+    )?;
 
     // Update circulating supply
-    property_state.circulating_supply -= amount;
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
     property_state.updated_at = Clock::get()?.unix_timestamp;
 
     // Emit tokens burned event
@@ -72,3 +72,26 @@ pub fn handler(ctx: Context<BurnPropertyTokens>, amount: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// Burn tokens from an investor's token account (investor must sign)
+///
+/// Shared by every instruction that retires property tokens so the burn
+/// path stays in one place.
+pub fn burn_investor_tokens<'info>(
+    token_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, Mint>,
+    investor_token_account: &InterfaceAccount<'info, TokenAccount>,
+    investor: &Signer<'info>,
+    amount: u64,
+) -> Result<()> {
+    let cpi_context = CpiContext::new(
+        token_program.to_account_info(),
+        Burn {
+            mint: mint.to_account_info(),
+            from: investor_token_account.to_account_info(),
+            authority: investor.to_account_info(),
+        },
+    );
+
+    token_2022::burn(cpi_context, amount)
+}
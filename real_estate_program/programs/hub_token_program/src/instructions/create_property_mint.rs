@@ -2,6 +2,7 @@
 ///
 /// This instruction creates a Token-2022 mint with the TransferHook extension
 /// enabled, ensuring ALL transfers (including P2P) verify KYC compliance.
+/// Optionally enables the TransferFeeConfig extension (secondary-trade royalty).
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::token_2022::spl_token_2022::{
@@ -44,7 +45,7 @@ pub struct CreatePropertyMint<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE,
+        space = 8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE * EXTRA_ACCOUNT_META_COUNT,
         seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
         bump,
     )]
@@ -55,6 +56,7 @@ pub struct CreatePropertyMint<'info> {
 }
 
 /// Handler for create_property_mint instruction
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreatePropertyMint>,
     property_name: String,
@@ -63,6 +65,7 @@ pub fn handler(
     total_supply: u64,
     seller_wallet: Pubkey,
     property_details: PropertyDetails,
+    transfer_fee: Option<TransferFeeParams>,
 ) -> Result<()> {
     // Validate inputs
     require!(
@@ -75,14 +78,20 @@ pub fn handler(
     );
     require!(total_supply > 0, RwaError::InvalidTotalSupply);
     property_details.validate()?;
+    if let Some(params) = &transfer_fee {
+        params.validate()?;
+    }
 
     let program_id = crate::ID;
     let mint = &ctx.accounts.mint;
     let authority = &ctx.accounts.authority;
     let property_state = &ctx.accounts.property_state;
 
-    // Calculate space for mint with TransferHook extension
-    let extension_types = [ExtensionType::TransferHook];
+    // Calculate space for mint with TransferHook (and optional TransferFeeConfig) extension
+    let mut extension_types = vec![ExtensionType::TransferHook];
+    if transfer_fee.is_some() {
+        extension_types.push(ExtensionType::TransferFeeConfig);
+    }
     let mint_size = ExtensionType::try_calculate_account_len::<MintState>(&extension_types)
         .map_err(|_| RwaError::MathOverflow)?;
 
@@ -119,6 +128,28 @@ pub fn handler(
         ],
     )?;
 
+    // Initialize TransferFeeConfig extension
+    // The PropertyState PDA is both fee authorities: the rate only changes
+    // through set_transfer_fee (capped at MAX_TRANSFER_FEE_BPS) and withheld
+    // fees are only withdrawn by harvest_transfer_fees
+    if let Some(params) = &transfer_fee {
+        let init_fee_ix = anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+            &anchor_spl::token_2022::ID,
+            &mint.key(),
+            Some(&property_state.key()),
+            Some(&property_state.key()),
+            params.fee_bps,
+            params.max_fee,
+        )?;
+
+        anchor_lang::solana_program::program::invoke(
+            &init_fee_ix,
+            &[
+                mint.to_account_info(),
+            ],
+        )?;
+    }
+
     // Initialize mint
     let init_mint_ix = initialize_mint2(
         &anchor_spl::token_2022::ID,
@@ -145,13 +176,30 @@ pub fn handler(
     property_state.total_supply = total_supply;
     property_state.circulating_supply = 0;
     property_state.details = property_details.clone();
-    property_state.is_active = true;
+    property_state.status = PropertyStatus::Draft;
     property_state.created_at = Clock::get()?.unix_timestamp;
     property_state.updated_at = Clock::get()?.unix_timestamp;
     property_state.bump = ctx.bumps.property_state;
+    property_state.version = PropertyState::CURRENT_VERSION;
+    property_state.paused_from = PropertyStatus::Draft;
+    property_state.transfers_paused = false;
+    property_state.escrowed_supply = 0;
+    property_state.transfer_fee_enabled = transfer_fee.is_some();
+    property_state.transfer_fee_retired_share_bps = transfer_fee
+        .map(|params| params.retired_share_bps)
+        .unwrap_or(0);
+    property_state.sponsor_allocated_supply = 0;
+    property_state.sponsor_claimed_supply = 0;
+    property_state.staked_supply = 0;
+    property_state.staking_boost_weight = 0;
+    property_state.pool_supply = 0;
+    property_state.bundled_supply = 0;
+    property_state.revenue_epoch_count = 0;
+    property_state.last_revenue_at = 0;
 
     // Initialize ExtraAccountMetaList for Transfer Hook
-    initialize_extra_account_meta_list(&ctx.accounts.extra_account_meta_list, &mint.key())?;
+    write_extra_account_metas(&mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?);
+    msg!("ExtraAccountMetaList initialized for mint: {}", mint.key());
 
     // Emit events
     emit!(PropertyInitialized {
@@ -174,51 +222,13 @@ pub fn handler(
     msg!("Mint: {}", mint.key());
     msg!("Transfer Hook Program: {}", program_id);
     msg!("All transfers will now verify KYC!");
-
-    Ok(())
-}
-
-/// Helper function to initialize ExtraAccountMetaList
-fn initialize_extra_account_meta_list(
-    extra_account_meta_list: &AccountInfo,
-    mint: &Pubkey,
-) -> Result<()> {
-    let mut data = extra_account_meta_list.try_borrow_mut_data()?;
-
-    // Write Execute discriminator
-    data[0..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
-
-    // Write length (total size of the account list data: 4 + 35*1 = 39)
-    let list_length: u32 = 4 + EXTRA_ACCOUNT_META_SIZE as u32;
-    data[8..12].copy_from_slice(&list_length.to_le_bytes());
-
-    // Write count (1 extra account)
-    let count: u32 = 1;
-    data[12..16].copy_from_slice(&count.to_le_bytes());
-
-    // Write ExtraAccountMeta for destination attestation
-    // discriminator = 1 (PDA from external program - SAS)
-    data[16] = 1;
-
-    // address_config: seeds configuration for SAS attestation lookup
-    let mut address_config = [0u8; 32];
-    // Seed 1: Literal "attestation"
-    address_config[0] = 11; // length
-    address_config[1] = 0;  // seed type: Literal
-    address_config[2..13].copy_from_slice(b"attestation");
-    // Seed 2: AccountKey index 2 (destination token account)
-    address_config[13] = 1; // length
-    address_config[14] = 1; // seed type: AccountKey
-    address_config[15] = 2; // index
-
-    data[17..49].copy_from_slice(&address_config);
-
-    // is_signer = false
-    data[49] = 0;
-    // is_writable = false
-    data[50] = 0;
-
-    msg!("ExtraAccountMetaList initialized for mint: {}", mint);
+    if let Some(params) = &transfer_fee {
+        msg!(
+            "Transfer fee enabled: {} bps (max {})",
+            params.fee_bps,
+            params.max_fee
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,186 @@
+/// Distributors - Partner broker registration and commission payouts
+///
+/// The platform admin registers distributors with a commission rate.
+/// `invest_in_property` accrues their commission (carved out of the
+/// platform fee) in the distributor PDA, which the distributor withdraws.
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+// ============================================================================
+// REGISTER DISTRIBUTOR
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RegisterDistributor<'info> {
+    /// Platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Distributor PDA
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Distributor::INIT_SPACE,
+        seeds = [DISTRIBUTOR_SEED, wallet.as_ref()],
+        bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for register_distributor instruction
+pub fn handler_register_distributor(
+    ctx: Context<RegisterDistributor>,
+    wallet: Pubkey,
+    commission_bps: u16,
+) -> Result<()> {
+    Distributor::validate_commission(commission_bps)?;
+
+    let clock = Clock::get()?;
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.wallet = wallet;
+    distributor.commission_bps = commission_bps;
+    distributor.is_active = true;
+    distributor.total_referred = 0;
+    distributor.total_accrued = 0;
+    distributor.total_paid = 0;
+    distributor.created_at = clock.unix_timestamp;
+    distributor.updated_at = clock.unix_timestamp;
+    distributor.bump = ctx.bumps.distributor;
+
+    emit!(DistributorUpdated {
+        distributor: distributor.key(),
+        wallet,
+        commission_bps,
+        is_active: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Distributor {} registered with {} bps of the platform fee",
+        wallet,
+        commission_bps
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE DISTRIBUTOR
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateDistributor<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Distributor PDA
+    #[account(
+        mut,
+        seeds = [DISTRIBUTOR_SEED, distributor.wallet.as_ref()],
+        bump = distributor.bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+}
+
+/// Handler for update_distributor instruction
+///
+/// Deactivated distributors stop earning but can still withdraw.
+pub fn handler_update_distributor(
+    ctx: Context<UpdateDistributor>,
+    commission_bps: u16,
+    is_active: bool,
+) -> Result<()> {
+    Distributor::validate_commission(commission_bps)?;
+
+    let clock = Clock::get()?;
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.commission_bps = commission_bps;
+    distributor.is_active = is_active;
+    distributor.updated_at = clock.unix_timestamp;
+
+    emit!(DistributorUpdated {
+        distributor: distributor.key(),
+        wallet: distributor.wallet,
+        commission_bps,
+        is_active,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// WITHDRAW COMMISSION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawDistributorCommission<'info> {
+    /// Distributor wallet
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Distributor PDA (holds accrued commissions)
+    #[account(
+        mut,
+        seeds = [DISTRIBUTOR_SEED, wallet.key().as_ref()],
+        bump = distributor.bump,
+        has_one = wallet @ RwaError::Unauthorized,
+    )]
+    pub distributor: Account<'info, Distributor>,
+}
+
+/// Handler for withdraw_distributor_commission instruction
+pub fn handler_withdraw_distributor_commission(
+    ctx: Context<WithdrawDistributorCommission>,
+) -> Result<()> {
+    let amount = ctx.accounts.distributor.unpaid_commission();
+    require!(amount > 0, RwaError::NoFundsAvailable);
+
+    // Distributor PDA is owned by this program
+    **ctx
+        .accounts
+        .distributor
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.wallet.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let clock = Clock::get()?;
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_paid = distributor
+        .total_paid
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    distributor.updated_at = clock.unix_timestamp;
+
+    emit!(DistributorCommissionWithdrawn {
+        distributor: distributor.key(),
+        wallet: distributor.wallet,
+        amount,
+        total_paid: distributor.total_paid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Distributor {} withdrew {} lamports", distributor.wallet, amount);
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+/// Emergency Pause - Guardian-controlled transfer circuit breakers
+///
+/// Secondary transfers can be stopped per property or platform-wide by a
+/// designated guardian key, independently of the property lifecycle status
+/// (minting and investments keep working).
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+// ============================================================================
+// INITIALIZE PLATFORM CONFIG
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    /// Bootstrap platform admin (becomes the stored admin)
+    #[account(
+        mut,
+        constraint = admin.key() == PLATFORM_ADMIN @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for initialize_platform_config instruction
+pub fn handler_initialize_platform_config(
+    ctx: Context<InitializePlatformConfig>,
+    guardian: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.admin = ctx.accounts.admin.key();
+    platform_config.guardian = guardian;
+    platform_config.transfers_paused = false;
+    platform_config.updated_at = clock.unix_timestamp;
+    platform_config.bump = ctx.bumps.platform_config;
+
+    emit!(PlatformGuardianUpdated {
+        old_guardian: Pubkey::default(),
+        new_guardian: guardian,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform config initialized with guardian {}", guardian);
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE GUARDIAN
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdatePlatformGuardian<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Handler for update_platform_guardian instruction
+pub fn handler_update_platform_guardian(
+    ctx: Context<UpdatePlatformGuardian>,
+    new_guardian: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let platform_config = &mut ctx.accounts.platform_config;
+    let old_guardian = platform_config.guardian;
+
+    platform_config.guardian = new_guardian;
+    platform_config.updated_at = clock.unix_timestamp;
+
+    emit!(PlatformGuardianUpdated {
+        old_guardian,
+        new_guardian,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform guardian changed: {} -> {}", old_guardian, new_guardian);
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE ADMIN
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdatePlatformAdmin<'info> {
+    /// Current platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Handler for update_platform_admin instruction
+pub fn handler_update_platform_admin(
+    ctx: Context<UpdatePlatformAdmin>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let platform_config = &mut ctx.accounts.platform_config;
+    let old_admin = platform_config.admin;
+
+    platform_config.admin = new_admin;
+    platform_config.updated_at = clock.unix_timestamp;
+
+    emit!(PlatformAdminUpdated {
+        old_admin,
+        new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Platform admin changed: {} -> {}", old_admin, new_admin);
+
+    Ok(())
+}
+
+// ============================================================================
+// PROPERTY TRANSFER PAUSE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetPropertyTransferPause<'info> {
+    /// Platform guardian
+    pub guardian: Signer<'info>,
+
+    /// PlatformConfig PDA
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = guardian @ RwaError::UnauthorizedGuardian,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// Handler for set_property_transfer_pause instruction
+pub fn handler_set_property_transfer_pause(
+    ctx: Context<SetPropertyTransferPause>,
+    paused: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let property_state = &mut ctx.accounts.property_state;
+
+    property_state.transfers_paused = paused;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(PropertyTransfersPauseChanged {
+        mint: ctx.accounts.mint.key(),
+        guardian: ctx.accounts.guardian.key(),
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Transfers for property {} {}",
+        property_state.property_name,
+        if paused { "PAUSED" } else { "RESUMED" }
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// GLOBAL TRANSFER PAUSE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetGlobalTransferPause<'info> {
+    /// Platform guardian
+    pub guardian: Signer<'info>,
+
+    /// PlatformConfig PDA
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = guardian @ RwaError::UnauthorizedGuardian,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+/// Handler for set_global_transfer_pause instruction
+pub fn handler_set_global_transfer_pause(
+    ctx: Context<SetGlobalTransferPause>,
+    paused: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.transfers_paused = paused;
+    platform_config.updated_at = clock.unix_timestamp;
+
+    emit!(GlobalTransfersPauseChanged {
+        guardian: ctx.accounts.guardian.key(),
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Platform-wide transfers {}",
+        if paused { "PAUSED" } else { "RESUMED" }
+    );
+
+    Ok(())
+}
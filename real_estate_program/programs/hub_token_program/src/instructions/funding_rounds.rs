@@ -0,0 +1,130 @@
+/// Funding Rounds - Tiered presale pricing
+///
+/// The authority defines priced rounds (private, early-bird, public) before
+/// or during fundraising. Each round starts no earlier than the previous one
+/// ends, so the active round is determined by the clock alone.
+/// `invest_in_property` then sells at the price of the active round and
+/// enforces its cap and eligibility rules.
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+#[derive(Accounts)]
+pub struct CreateFundingRound<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Investment Vault PDA (round counter and per-round totals)
+    #[account(
+        mut,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+        constraint = investment_vault.is_initialized @ RwaError::VaultNotInitialized,
+    )]
+    pub investment_vault: Box<Account<'info, InvestmentVault>>,
+
+    /// Funding Round PDA
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FundingRound::INIT_SPACE,
+        seeds = [
+            FUNDING_ROUND_SEED,
+            mint.key().as_ref(),
+            &[investment_vault.round_count],
+        ],
+        bump,
+    )]
+    pub funding_round: Box<Account<'info, FundingRound>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_funding_round instruction
+pub fn handler(
+    ctx: Context<CreateFundingRound>,
+    price_per_token: u64,
+    token_cap: u64,
+    starts_at: i64,
+    ends_at: i64,
+    allowlist_root: Option<[u8; 32]>,
+    required_credential_type: Option<u8>,
+) -> Result<()> {
+    let investment_vault = &mut ctx.accounts.investment_vault;
+
+    require!(
+        FundingRound::can_add_round(investment_vault.round_count),
+        RwaError::TooManyFundingRounds
+    );
+    require!(
+        price_per_token > 0 && token_cap > 0 && ends_at > starts_at,
+        RwaError::InvalidFundingRound
+    );
+    require!(
+        token_cap <= ctx.accounts.property_state.total_supply,
+        RwaError::InvalidFundingRound
+    );
+    require!(
+        investment_vault.round_count == 0 || starts_at >= investment_vault.rounds_end_at,
+        RwaError::FundingRoundOverlap
+    );
+    if let Some(credential_type) = required_credential_type {
+        require!(
+            HubCredentialType::from_u8(credential_type).is_some(),
+            RwaError::InvalidFundingRound
+        );
+    }
+
+    let round_index = investment_vault.round_count;
+    let funding_round = &mut ctx.accounts.funding_round;
+    funding_round.property_state = ctx.accounts.property_state.key();
+    funding_round.mint = ctx.accounts.mint.key();
+    funding_round.round_index = round_index;
+    funding_round.price_per_token = price_per_token;
+    funding_round.token_cap = token_cap;
+    funding_round.starts_at = starts_at;
+    funding_round.ends_at = ends_at;
+    funding_round.allowlist_root = allowlist_root;
+    funding_round.required_credential_type = required_credential_type;
+    funding_round.bump = ctx.bumps.funding_round;
+
+    investment_vault.round_count = round_index + 1;
+    investment_vault.rounds_end_at = ends_at;
+    investment_vault.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(FundingRoundCreated {
+        property_mint: ctx.accounts.mint.key(),
+        funding_round: funding_round.key(),
+        round_index,
+        price_per_token,
+        token_cap,
+        starts_at,
+        ends_at,
+        has_allowlist: allowlist_root.is_some(),
+        required_credential_type,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Funding round {} created: {} lamports/token, cap {} tokens",
+        round_index,
+        price_per_token,
+        token_cap
+    );
+
+    Ok(())
+}
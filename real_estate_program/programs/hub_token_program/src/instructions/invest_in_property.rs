@@ -56,6 +56,7 @@ pub struct InvestInProperty<'info> {
     pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Hub Credential account for investor KYC
+    /// Further credentials of other types may follow in remaining accounts
     /// CHECK: Will be verified using Hub Credential program
     pub investor_credential: UncheckedAccount<'info>,
 
@@ -75,6 +76,50 @@ pub struct InvestInProperty<'info> {
     )]
     pub seller: UncheckedAccount<'info>,
 
+    /// Active Funding Round (required once the property has rounds)
+    #[account(
+        seeds = [FUNDING_ROUND_SEED, mint.key().as_ref(), &[funding_round.round_index]],
+        bump = funding_round.bump,
+        constraint = funding_round.property_state == property_state.key() @ RwaError::InvalidFundingRound,
+        constraint = funding_round.round_index >= investment_vault.current_round @ RwaError::FundingRoundNotActive,
+    )]
+    pub funding_round: Option<Box<Account<'info, FundingRound>>>,
+
+    /// InvestmentLimits PDA - min ticket and per-credential caps
+    /// CHECK: May not be initialized; read via InvestmentLimits::load
+    #[account(
+        seeds = [INVESTMENT_LIMITS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub investment_limits: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions
+    /// CHECK: May not be initialized; read via ComplianceRules::load
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
+
+    /// Investor Record PDA - cumulative investment per investor
+    #[account(
+        init_if_needed,
+        payer = investor,
+        space = 8 + InvestorRecord::INIT_SPACE,
+        seeds = [INVESTOR_RECORD_SEED, mint.key().as_ref(), investor.key().as_ref()],
+        bump,
+    )]
+    pub investor_record: Box<Account<'info, InvestorRecord>>,
+
+    /// Referring distributor (optional) - earns a share of the platform fee
+    #[account(
+        mut,
+        seeds = [DISTRIBUTOR_SEED, distributor.wallet.as_ref()],
+        bump = distributor.bump,
+        constraint = distributor.is_active @ RwaError::DistributorInactive,
+    )]
+    pub distributor: Option<Box<Account<'info, Distributor>>>,
+
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -85,10 +130,18 @@ pub struct InvestInProperty<'info> {
 /// # Arguments
 /// * `sol_amount` - Amount of SOL to invest (in lamports)
 /// * `expected_tokens` - Expected number of tokens to receive (for slippage protection)
-pub fn handler(
-    ctx: Context<InvestInProperty>,
+///   When investing through a funding round, tokens are priced by the round
+///   and `expected_tokens` is the minimum accepted
+/// * `allowlist_proof` - Merkle proof for rounds with an allow-list
+///
+/// # Remaining Accounts
+/// Additional Hub Credentials of the investor. The first valid credential
+/// accepted by the active funding round is used (and sets the investment cap).
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InvestInProperty<'info>>,
     sol_amount: u64,
     expected_tokens: u64,
+    allowlist_proof: Vec<[u8; 32]>,
 ) -> Result<()> {
     let property_state = &ctx.accounts.property_state;
     let investment_vault = &mut ctx.accounts.investment_vault;
@@ -96,8 +149,11 @@ pub fn handler(
     // 1. Validate investment amount
     require!(sol_amount > 0, RwaError::InvalidInvestmentAmount);
 
-    // 2. Verify property is active
-    require!(property_state.is_active, RwaError::PropertyNotActive);
+    // 2. Verify property is fundraising
+    require!(
+        property_state.status.allows_investment(),
+        RwaError::PropertyNotFundraising
+    );
 
     // 3. Verify investor has sufficient SOL balance
     require!(
@@ -106,28 +162,151 @@ pub fn handler(
     );
 
     // 4. Verify Hub Credential for KYC compliance
-    verify_hub_credential(
-        &ctx.accounts.investor_credential.to_account_info(),
+    // Any one of the investor's credentials may satisfy the round's policy
+    let accepted_types = match ctx
+        .accounts
+        .funding_round
+        .as_deref()
+        .and_then(|funding_round| funding_round.required_credential_type)
+        .and_then(HubCredentialType::from_u8)
+    {
+        Some(required_type) => required_type.bit(),
+        None => ANY_HUB_CREDENTIAL_TYPE,
+    };
+    let credential_accounts: Vec<AccountInfo> =
+        std::iter::once(ctx.accounts.investor_credential.to_account_info())
+            .chain(ctx.remaining_accounts.iter().cloned())
+            .collect();
+    let credential = verify_any_hub_credential(
+        &credential_accounts,
         &ctx.accounts.investor.key(),
         &ctx.accounts.mint.key(),
+        accepted_types,
     )?;
 
+    // Restrict by the credential's compliance attributes (jurisdiction, risk)
+    ComplianceRules::enforce(&ctx.accounts.compliance_rules, &credential.attributes)?;
+
     msg!(
         "Hub Credential verification passed for investor: {}",
         ctx.accounts.investor.key()
     );
 
-    // 5. Calculate fee distribution
+    // 5. Apply the active funding round (price, cap and eligibility)
+    let tokens_to_mint = match ctx.accounts.funding_round.as_deref() {
+        Some(funding_round) => {
+            let clock = Clock::get()?;
+            require!(
+                funding_round.is_active(clock.unix_timestamp),
+                RwaError::FundingRoundNotActive
+            );
+
+            if let Some(root) = &funding_round.allowlist_root {
+                require!(
+                    verify_merkle_proof(
+                        &allowlist_proof,
+                        root,
+                        allowlist_leaf(&ctx.accounts.investor.key()),
+                    ),
+                    RwaError::NotOnAllowlist
+                );
+            }
+
+            let tokens = funding_round.calculate_tokens(sol_amount, ctx.accounts.mint.decimals)?;
+            require!(tokens > 0, RwaError::InvalidInvestmentAmount);
+            require!(tokens >= expected_tokens, RwaError::SlippageExceeded);
+
+            // Rounds do not overlap, so the active one is the current round
+            investment_vault.current_round = funding_round.round_index;
+
+            let round_index = funding_round.round_index as usize;
+            let round_sold = investment_vault.round_tokens_sold[round_index]
+                .checked_add(tokens)
+                .ok_or(RwaError::MathOverflow)?;
+            require!(
+                round_sold <= funding_round.token_cap,
+                RwaError::FundingRoundCapExceeded
+            );
+
+            investment_vault.round_tokens_sold[round_index] = round_sold;
+            investment_vault.round_sol_raised[round_index] = investment_vault.round_sol_raised
+                [round_index]
+                .checked_add(sol_amount)
+                .ok_or(RwaError::MathOverflow)?;
+
+            msg!(
+                "Funding round {}: {} tokens at {} lamports/token",
+                round_index,
+                tokens,
+                funding_round.price_per_token
+            );
+
+            tokens
+        }
+        None => {
+            require!(
+                investment_vault.round_count == 0,
+                RwaError::FundingRoundRequired
+            );
+            expected_tokens
+        }
+    };
+
+    // 6. Enforce investment limits for the investor's credential type
+    let clock = Clock::get()?;
+    let investor_record = &mut ctx.accounts.investor_record;
+    if investor_record.investor == Pubkey::default() {
+        investor_record.property_state = property_state.key();
+        investor_record.investor = ctx.accounts.investor.key();
+        investor_record.bump = ctx.bumps.investor_record;
+    }
+
+    let limits = InvestmentLimits::load(&ctx.accounts.investment_limits)?;
+    let limit_period_seconds = limits
+        .as_ref()
+        .map(|limits| limits.limit_period_seconds)
+        .unwrap_or(0);
+    let period_invested = investor_record.record_investment(
+        sol_amount,
+        clock.unix_timestamp,
+        limit_period_seconds,
+    )?;
+
+    if let Some(limits) = &limits {
+        require!(
+            sol_amount >= limits.min_investment,
+            RwaError::InvestmentBelowMinimum
+        );
+        if let Some(max) = limits.max_for(credential.credential_type) {
+            require!(period_invested <= max, RwaError::InvestmentLimitExceeded);
+        }
+    }
+
+    // The investor's self-declared limit applies on top of the property caps
+    if let Some(declared) = credential.attributes.investment_limit {
+        require!(period_invested <= declared, RwaError::InvestmentLimitExceeded);
+    }
+
+    // 7. Calculate fee distribution
     let platform_fee = InvestmentVault::calculate_platform_fee(sol_amount)?;
     let reserve_amount = InvestmentVault::calculate_reserve_amount(sol_amount)?;
     let escrow_amount = InvestmentVault::calculate_escrow_amount(sol_amount)?;
 
+    // Distributor commission comes out of the platform fee only
+    let commission = match ctx.accounts.distributor.as_deref() {
+        Some(distributor) => distributor.calculate_commission(platform_fee)?,
+        None => 0,
+    };
+    let platform_fee = platform_fee
+        .checked_sub(commission)
+        .ok_or(RwaError::MathOverflow)?;
+
     msg!(
-        "Investment distribution: Platform={} Reserve={} Escrow={}",
-        platform_fee, reserve_amount, escrow_amount
+        "Investment distribution: Platform={} Commission={} Reserve={} Escrow={}",
+        platform_fee, commission, reserve_amount, escrow_amount
     );
 
-    // 6. Transfer platform fee to treasury
+    // 8. Transfer platform fee to treasury
     if platform_fee > 0 {
         transfer(
             CpiContext::new(
@@ -142,7 +321,44 @@ pub fn handler(
         msg!("Transferred {} lamports to platform treasury", platform_fee);
     }
 
-    // 7. Transfer reserve to reserve fund PDA
+    // 9. Accrue distributor commission in the distributor PDA
+    if let Some(distributor) = ctx.accounts.distributor.as_mut() {
+        if commission > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.investor.to_account_info(),
+                        to: distributor.to_account_info(),
+                    },
+                ),
+                commission,
+            )?;
+        }
+
+        distributor.total_referred = distributor
+            .total_referred
+            .checked_add(sol_amount)
+            .ok_or(RwaError::MathOverflow)?;
+        distributor.total_accrued = distributor
+            .total_accrued
+            .checked_add(commission)
+            .ok_or(RwaError::MathOverflow)?;
+        distributor.updated_at = clock.unix_timestamp;
+
+        emit!(DistributorCommissionAccrued {
+            distributor: distributor.key(),
+            wallet: distributor.wallet,
+            property_mint: ctx.accounts.mint.key(),
+            investor: ctx.accounts.investor.key(),
+            investment_amount: sol_amount,
+            commission,
+            total_accrued: distributor.total_accrued,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // 10. Transfer reserve to reserve fund PDA
     if reserve_amount > 0 {
         transfer(
             CpiContext::new(
@@ -157,7 +373,7 @@ pub fn handler(
         msg!("Transferred {} lamports to reserve fund", reserve_amount);
     }
 
-    // 8. Transfer escrow to seller (for now direct, can be changed to escrow PDA)
+    // 11. Transfer escrow to seller (for now direct, can be changed to escrow PDA)
     // In production, this could go to an escrow PDA with milestone-based release
     if escrow_amount > 0 {
         transfer(
@@ -173,14 +389,14 @@ pub fn handler(
         msg!("Transferred {} lamports to seller", escrow_amount);
     }
 
-    // 9. Verify token amount doesn't exceed supply
+    // 12. Verify token amount doesn't exceed supply
     let property_state = &mut ctx.accounts.property_state;
     require!(
-        property_state.circulating_supply + expected_tokens <= property_state.total_supply,
+        tokens_to_mint <= property_state.remaining_supply(),
         RwaError::ExceedsMaxSupply
     );
 
-    // 10. Mint tokens to investor
+    // 13. Mint tokens to investor
     let mint_key = property_state.mint;
     let seeds = &[
         PROPERTY_STATE_SEED,
@@ -199,13 +415,26 @@ pub fn handler(
         signer_seeds,
     );
 
-    token_2022::mint_to(cpi_context, expected_tokens)?;
+    token_2022::mint_to(cpi_context, tokens_to_mint)?;
 
-    // 11. Update property state
-    property_state.circulating_supply += expected_tokens;
+    // 14. Update property state
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_add(tokens_to_mint)
+        .ok_or(RwaError::MathOverflow)?;
     property_state.updated_at = Clock::get()?.unix_timestamp;
 
-    // 12. Update investment vault
+    if let Some(previous) = property_state.mark_funded_if_sold_out() {
+        emit!(PropertyStatusChanged {
+            mint: ctx.accounts.mint.key(),
+            old_status: previous,
+            new_status: property_state.status,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Property fully funded!");
+    }
+
+    // 15. Update investment vault
     investment_vault.total_invested = investment_vault.total_invested
         .checked_add(sol_amount)
         .ok_or(RwaError::MathOverflow)?;
@@ -220,22 +449,22 @@ pub fn handler(
         .ok_or(RwaError::MathOverflow)?;
     investment_vault.updated_at = Clock::get()?.unix_timestamp;
 
-    // 13. Check and update milestones
-    let circulation_bps = ((property_state.circulating_supply as u128 * BPS_DIVISOR as u128)
-        / property_state.total_supply as u128) as u16;
+    // 16. Check and update milestones (sponsor vesting excluded)
+    let sold_bps = property_state.sold_bps();
 
-    let (new_milestone, _) = investment_vault.check_milestone(circulation_bps);
+    let (new_milestone, _) = investment_vault.check_milestone(sold_bps);
     if new_milestone > investment_vault.current_milestone {
         investment_vault.current_milestone = new_milestone;
         msg!("Milestone {} reached!", new_milestone);
     }
 
-    // 14. Emit investment event
+    // 17. Emit investment event
     emit!(InvestmentMade {
         property_mint: ctx.accounts.mint.key(),
         investor: ctx.accounts.investor.key(),
         sol_amount,
-        tokens_received: expected_tokens,
+        tokens_received: tokens_to_mint,
+        funding_round: ctx.accounts.funding_round.as_ref().map(|round| round.round_index),
         platform_fee,
         reserve_amount,
         escrow_amount,
@@ -245,7 +474,7 @@ pub fn handler(
     msg!(
         "Investment successful: {} SOL -> {} tokens for investor {}",
         sol_amount,
-        expected_tokens,
+        tokens_to_mint,
         ctx.accounts.investor.key()
     );
 
@@ -313,6 +542,12 @@ pub fn handler_initialize_vault(ctx: Context<InitializeInvestmentVault>) -> Resu
     investment_vault.created_at = clock.unix_timestamp;
     investment_vault.updated_at = clock.unix_timestamp;
     investment_vault.bump = ctx.bumps.investment_vault;
+    investment_vault.version = InvestmentVault::CURRENT_VERSION;
+    investment_vault.round_count = 0;
+    investment_vault.round_tokens_sold = [0; MAX_FUNDING_ROUNDS];
+    investment_vault.round_sol_raised = [0; MAX_FUNDING_ROUNDS];
+    investment_vault.current_round = 0;
+    investment_vault.rounds_end_at = 0;
 
     emit!(InvestmentVaultInitialized {
         property_mint: ctx.accounts.mint.key(),
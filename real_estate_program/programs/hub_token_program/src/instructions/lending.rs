@@ -0,0 +1,1055 @@
+/// Lending - Borrow a stablecoin against property tokens
+///
+/// Collateral is held in program escrow, like pool and vote tokens, and
+/// valued from the market's admin-set valuation (see `LendingMarket`).
+/// Positions above the liquidation threshold can be repaid by any
+/// credentialed liquidator in exchange for collateral plus a bonus.
+/// Borrowing, withdrawing against debt and liquidating all require a
+/// valuation refreshed within MAX_VALUATION_AGE.
+///
+/// Withdrawing collateral and liquidating stay open while the property is
+/// paused or liquidating, so positions can always be unwound.
+///
+/// Stablecoin liquidity is funded by transferring into the market vault.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+// ============================================================================
+// INITIALIZE LENDING MARKET
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeLendingMarket<'info> {
+    /// Platform admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stablecoin mint lent out
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Lending Market PDA
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + LendingMarket::INIT_SPACE,
+        seeds = [LENDING_MARKET_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Stablecoin vault
+    #[account(
+        init,
+        payer = admin,
+        seeds = [LENDING_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = loan_mint,
+        token::authority = lending_market,
+        token::token_program = loan_token_program,
+    )]
+    pub loan_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub loan_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for initialize_lending_market instruction
+pub fn handler_initialize_lending_market(
+    ctx: Context<InitializeLendingMarket>,
+    max_ltv_bps: u16,
+    liquidation_threshold_bps: u16,
+    liquidation_bonus_bps: u16,
+    interest_rate_bps: u16,
+) -> Result<()> {
+    LendingMarket::validate_params(
+        max_ltv_bps,
+        liquidation_threshold_bps,
+        liquidation_bonus_bps,
+        interest_rate_bps,
+    )?;
+
+    require!(
+        ctx.accounts.property_state.details.total_value_usd > 0,
+        RwaError::InvalidLendingParams
+    );
+
+    let clock = Clock::get()?;
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.property_state = ctx.accounts.property_state.key();
+    lending_market.mint = ctx.accounts.mint.key();
+    lending_market.loan_mint = ctx.accounts.loan_mint.key();
+    lending_market.loan_vault = ctx.accounts.loan_vault.key();
+    lending_market.max_ltv_bps = max_ltv_bps;
+    lending_market.liquidation_threshold_bps = liquidation_threshold_bps;
+    lending_market.liquidation_bonus_bps = liquidation_bonus_bps;
+    lending_market.interest_rate_bps = interest_rate_bps;
+    lending_market.total_collateral = 0;
+    lending_market.total_borrowed = 0;
+    lending_market.valuation_usd = ctx.accounts.property_state.details.total_value_usd;
+    lending_market.valuation_updated_at = clock.unix_timestamp;
+    lending_market.created_at = clock.unix_timestamp;
+    lending_market.bump = ctx.bumps.lending_market;
+
+    emit!(LendingMarketUpdated {
+        property_mint: lending_market.mint,
+        market: lending_market.key(),
+        loan_mint: lending_market.loan_mint,
+        max_ltv_bps,
+        liquidation_threshold_bps,
+        liquidation_bonus_bps,
+        interest_rate_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Lending market initialized: max LTV {} bps, liquidation at {} bps",
+        max_ltv_bps,
+        liquidation_threshold_bps
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE LENDING MARKET
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateLendingMarket<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, lending_market.mint.as_ref()],
+        bump = lending_market.bump,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+}
+
+/// Handler for update_lending_market instruction
+///
+/// New parameters apply to existing positions from the next interaction.
+pub fn handler_update_lending_market(
+    ctx: Context<UpdateLendingMarket>,
+    max_ltv_bps: u16,
+    liquidation_threshold_bps: u16,
+    liquidation_bonus_bps: u16,
+    interest_rate_bps: u16,
+) -> Result<()> {
+    LendingMarket::validate_params(
+        max_ltv_bps,
+        liquidation_threshold_bps,
+        liquidation_bonus_bps,
+        interest_rate_bps,
+    )?;
+
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.max_ltv_bps = max_ltv_bps;
+    lending_market.liquidation_threshold_bps = liquidation_threshold_bps;
+    lending_market.liquidation_bonus_bps = liquidation_bonus_bps;
+    lending_market.interest_rate_bps = interest_rate_bps;
+
+    emit!(LendingMarketUpdated {
+        property_mint: lending_market.mint,
+        market: lending_market.key(),
+        loan_mint: lending_market.loan_mint,
+        max_ltv_bps,
+        liquidation_threshold_bps,
+        liquidation_bonus_bps,
+        interest_rate_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// UPDATE LENDING VALUATION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateLendingValuation<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, lending_market.mint.as_ref()],
+        bump = lending_market.bump,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+}
+
+/// Handler for update_lending_valuation instruction
+///
+/// Moves the collateral valuation by at most MAX_VALUATION_CHANGE_BPS, no
+/// more than once per MIN_VALUATION_UPDATE_INTERVAL. Re-submitting the same
+/// valuation refreshes it.
+pub fn handler_update_lending_valuation(
+    ctx: Context<UpdateLendingValuation>,
+    valuation_usd: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.validate_valuation_update(valuation_usd, clock.unix_timestamp)?;
+
+    let previous_valuation_usd = lending_market.valuation_usd;
+    lending_market.valuation_usd = valuation_usd;
+    lending_market.valuation_updated_at = clock.unix_timestamp;
+
+    emit!(LendingValuationUpdated {
+        property_mint: lending_market.mint,
+        market: lending_market.key(),
+        previous_valuation_usd,
+        valuation_usd,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Lending valuation updated: {} -> {} cents",
+        previous_valuation_usd,
+        valuation_usd
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// WITHDRAW LENDING LIQUIDITY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawLendingLiquidity<'info> {
+    /// Platform admin
+    pub admin: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ RwaError::UnauthorizedPlatformAdmin,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Lending Market PDA (vault authority)
+    #[account(
+        seeds = [LENDING_MARKET_SEED, lending_market.mint.as_ref()],
+        bump = lending_market.bump,
+        has_one = loan_mint @ RwaError::InvalidMint,
+        has_one = loan_vault @ RwaError::InvalidLendingParams,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Stablecoin mint
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stablecoin vault
+    #[account(mut)]
+    pub loan_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Destination stablecoin account
+    #[account(
+        mut,
+        constraint = destination.mint == loan_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub loan_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for withdraw_lending_liquidity instruction
+pub fn handler_withdraw_lending_liquidity(
+    ctx: Context<WithdrawLendingLiquidity>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(
+        ctx.accounts.loan_vault.amount >= amount,
+        RwaError::InsufficientLiquidity
+    );
+
+    transfer_from_vault(
+        &ctx.accounts.lending_market,
+        &ctx.accounts.loan_mint,
+        &ctx.accounts.loan_vault,
+        &ctx.accounts.destination,
+        &ctx.accounts.loan_token_program,
+        amount,
+    )?;
+
+    emit!(LendingLiquidityWithdrawn {
+        property_mint: ctx.accounts.lending_market.mint,
+        market: ctx.accounts.lending_market.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ============================================================================
+// DEPOSIT COLLATERAL
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    /// Borrower depositing collateral
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, mint.key().as_ref()],
+        bump = lending_market.bump,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Loan Position PDA
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        space = 8 + LoanPosition::INIT_SPACE,
+        seeds = [LOAN_POSITION_SEED, mint.key().as_ref(), borrower.key().as_ref()],
+        bump,
+    )]
+    pub loan_position: Box<Account<'info, LoanPosition>>,
+
+    /// Borrower's property token account
+    #[account(
+        mut,
+        constraint = borrower_token_account.owner == borrower.key() @ RwaError::Unauthorized,
+        constraint = borrower_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// PlatformConfig PDA (global transfer pause)
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for deposit_collateral instruction
+pub fn handler_deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    // 1. Lock the collateral in program escrow
+    escrow_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.borrower_token_account,
+        &ctx.accounts.borrower,
+        &mut ctx.accounts.property_state,
+        amount,
+    )?;
+
+    // 2. Update the position
+    let clock = Clock::get()?;
+    let lending_market = &mut ctx.accounts.lending_market;
+    let loan_position = &mut ctx.accounts.loan_position;
+    if loan_position.borrower == Pubkey::default() {
+        loan_position.market = lending_market.key();
+        loan_position.borrower = ctx.accounts.borrower.key();
+        loan_position.last_accrued_at = clock.unix_timestamp;
+        loan_position.bump = ctx.bumps.loan_position;
+    }
+    loan_position.collateral_amount = loan_position
+        .collateral_amount
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    lending_market.total_collateral = lending_market
+        .total_collateral
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(CollateralDeposited {
+        property_mint: ctx.accounts.mint.key(),
+        borrower: loan_position.borrower,
+        amount,
+        collateral_amount: loan_position.collateral_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Deposited {} tokens of collateral for {}",
+        amount,
+        loan_position.borrower
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// WITHDRAW COLLATERAL
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawCollateral<'info> {
+    /// Borrower withdrawing collateral
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, mint.key().as_ref()],
+        bump = lending_market.bump,
+        has_one = loan_mint @ RwaError::InvalidMint,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Loan Position PDA
+    #[account(
+        mut,
+        seeds = [LOAN_POSITION_SEED, mint.key().as_ref(), borrower.key().as_ref()],
+        bump = loan_position.bump,
+        has_one = borrower @ RwaError::Unauthorized,
+    )]
+    pub loan_position: Box<Account<'info, LoanPosition>>,
+
+    /// Stablecoin mint (decimals for valuation)
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Borrower's property token account (receives the collateral)
+    #[account(
+        mut,
+        constraint = borrower_token_account.owner == borrower.key() @ RwaError::Unauthorized,
+        constraint = borrower_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub borrower_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for borrower KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub borrower_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for withdraw_collateral instruction
+pub fn handler_withdraw_collateral(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+    // Released tokens bypass the transfer hook - verify KYC here
+    verify_hub_credential(
+        &ctx.accounts.borrower_credential.to_account_info(),
+        &ctx.accounts.borrower.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    // 1. Check the remaining collateral still covers the debt
+    let clock = Clock::get()?;
+    accrue_position_interest(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.loan_position,
+        clock.unix_timestamp,
+    )?;
+
+    let loan_position = &mut ctx.accounts.loan_position;
+    let remaining_collateral = loan_position
+        .collateral_amount
+        .checked_sub(amount)
+        .ok_or(RwaError::InsufficientBalance)?;
+    if loan_position.debt > 0 {
+        ctx.accounts
+            .lending_market
+            .require_fresh_valuation(clock.unix_timestamp)?;
+    }
+    let remaining_value = LendingMarket::collateral_value(
+        remaining_collateral,
+        ctx.accounts.lending_market.valuation_usd,
+        ctx.accounts.property_state.total_supply,
+        ctx.accounts.loan_mint.decimals,
+    )?;
+    require!(
+        loan_position.debt <= ctx.accounts.lending_market.max_debt(remaining_value),
+        RwaError::BorrowLimitExceeded
+    );
+    loan_position.collateral_amount = remaining_collateral;
+
+    // 2. Release the collateral
+    release_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.borrower_token_account,
+        &mut ctx.accounts.property_state,
+        amount,
+    )?;
+
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.total_collateral = lending_market
+        .total_collateral
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(CollateralWithdrawn {
+        property_mint: ctx.accounts.mint.key(),
+        borrower: ctx.accounts.borrower.key(),
+        amount,
+        collateral_amount: remaining_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Withdrew {} tokens of collateral for {}",
+        amount,
+        ctx.accounts.borrower.key()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// BORROW
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    /// Borrower
+    pub borrower: Signer<'info>,
+
+    /// PropertyState PDA (valuation)
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Lending Market PDA (vault authority)
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, mint.key().as_ref()],
+        bump = lending_market.bump,
+        has_one = loan_mint @ RwaError::InvalidMint,
+        has_one = loan_vault @ RwaError::InvalidLendingParams,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Loan Position PDA
+    #[account(
+        mut,
+        seeds = [LOAN_POSITION_SEED, mint.key().as_ref(), borrower.key().as_ref()],
+        bump = loan_position.bump,
+        has_one = borrower @ RwaError::Unauthorized,
+    )]
+    pub loan_position: Box<Account<'info, LoanPosition>>,
+
+    /// Stablecoin mint
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stablecoin vault
+    #[account(mut)]
+    pub loan_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Borrower's stablecoin account
+    #[account(
+        mut,
+        constraint = borrower_loan_account.owner == borrower.key() @ RwaError::Unauthorized,
+        constraint = borrower_loan_account.mint == loan_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub borrower_loan_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// PlatformConfig PDA (global transfer pause)
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub loan_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for borrow instruction
+pub fn handler_borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+    require!(
+        ctx.accounts.loan_vault.amount >= amount,
+        RwaError::InsufficientLiquidity
+    );
+
+    // 1. Check the new debt against the maximum LTV
+    let clock = Clock::get()?;
+    accrue_position_interest(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.loan_position,
+        clock.unix_timestamp,
+    )?;
+    ctx.accounts
+        .lending_market
+        .require_fresh_valuation(clock.unix_timestamp)?;
+
+    let collateral_value = LendingMarket::collateral_value(
+        ctx.accounts.loan_position.collateral_amount,
+        ctx.accounts.lending_market.valuation_usd,
+        ctx.accounts.property_state.total_supply,
+        ctx.accounts.loan_mint.decimals,
+    )?;
+    let new_debt = ctx
+        .accounts
+        .loan_position
+        .debt
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    require!(
+        new_debt <= ctx.accounts.lending_market.max_debt(collateral_value),
+        RwaError::BorrowLimitExceeded
+    );
+
+    // 2. Pay out the loan
+    transfer_from_vault(
+        &ctx.accounts.lending_market,
+        &ctx.accounts.loan_mint,
+        &ctx.accounts.loan_vault,
+        &ctx.accounts.borrower_loan_account,
+        &ctx.accounts.loan_token_program,
+        amount,
+    )?;
+
+    ctx.accounts.loan_position.debt = new_debt;
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.total_borrowed = lending_market
+        .total_borrowed
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(LoanBorrowed {
+        property_mint: ctx.accounts.mint.key(),
+        borrower: ctx.accounts.borrower.key(),
+        amount,
+        debt: new_debt,
+        collateral_value,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Borrowed {} against {} collateral value - debt {}",
+        amount,
+        collateral_value,
+        new_debt
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// REPAY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    /// Borrower repaying
+    pub borrower: Signer<'info>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, lending_market.mint.as_ref()],
+        bump = lending_market.bump,
+        has_one = loan_mint @ RwaError::InvalidMint,
+        has_one = loan_vault @ RwaError::InvalidLendingParams,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Loan Position PDA
+    #[account(
+        mut,
+        seeds = [LOAN_POSITION_SEED, lending_market.mint.as_ref(), borrower.key().as_ref()],
+        bump = loan_position.bump,
+        has_one = borrower @ RwaError::Unauthorized,
+    )]
+    pub loan_position: Box<Account<'info, LoanPosition>>,
+
+    /// Stablecoin mint
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stablecoin vault
+    #[account(mut)]
+    pub loan_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Borrower's stablecoin account
+    #[account(
+        mut,
+        constraint = borrower_loan_account.owner == borrower.key() @ RwaError::Unauthorized,
+        constraint = borrower_loan_account.mint == loan_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub borrower_loan_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub loan_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for repay instruction
+///
+/// Repayments above the outstanding debt are capped at the debt.
+pub fn handler_repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    accrue_position_interest(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.loan_position,
+        clock.unix_timestamp,
+    )?;
+
+    let amount = amount.min(ctx.accounts.loan_position.debt);
+    require!(amount > 0, RwaError::InvalidAmount);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.loan_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.borrower_loan_account.to_account_info(),
+                mint: ctx.accounts.loan_mint.to_account_info(),
+                to: ctx.accounts.loan_vault.to_account_info(),
+                authority: ctx.accounts.borrower.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.loan_mint.decimals,
+    )?;
+
+    let loan_position = &mut ctx.accounts.loan_position;
+    loan_position.debt = loan_position
+        .debt
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.total_borrowed = lending_market.total_borrowed.saturating_sub(amount);
+
+    emit!(LoanRepaid {
+        property_mint: lending_market.mint,
+        borrower: loan_position.borrower,
+        amount,
+        debt: loan_position.debt,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Repaid {} - remaining debt {}",
+        amount,
+        loan_position.debt
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// LIQUIDATE LOAN
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct LiquidateLoan<'info> {
+    /// Liquidator (must hold a valid Hub Credential)
+    pub liquidator: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Lending Market PDA
+    #[account(
+        mut,
+        seeds = [LENDING_MARKET_SEED, mint.key().as_ref()],
+        bump = lending_market.bump,
+        has_one = loan_mint @ RwaError::InvalidMint,
+        has_one = loan_vault @ RwaError::InvalidLendingParams,
+    )]
+    pub lending_market: Box<Account<'info, LendingMarket>>,
+
+    /// Loan Position PDA being liquidated
+    #[account(
+        mut,
+        seeds = [LOAN_POSITION_SEED, mint.key().as_ref(), loan_position.borrower.as_ref()],
+        bump = loan_position.bump,
+    )]
+    pub loan_position: Box<Account<'info, LoanPosition>>,
+
+    /// Stablecoin mint
+    pub loan_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stablecoin vault
+    #[account(mut)]
+    pub loan_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidator's stablecoin account (pays the debt)
+    #[account(
+        mut,
+        constraint = liquidator_loan_account.owner == liquidator.key() @ RwaError::Unauthorized,
+        constraint = liquidator_loan_account.mint == loan_mint.key() @ RwaError::InvalidMint,
+    )]
+    pub liquidator_loan_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidator's property token account (receives the collateral)
+    #[account(
+        mut,
+        constraint = liquidator_token_account.owner == liquidator.key() @ RwaError::Unauthorized,
+        constraint = liquidator_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub liquidator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for liquidator KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub liquidator_credential: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions for the liquidator
+    /// CHECK: May not be initialized; read via ComplianceRules::enforce
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub loan_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handler for liquidate_loan instruction
+///
+/// The liquidator repays up to the full debt and receives collateral worth
+/// the repaid amount plus the liquidation bonus.
+pub fn handler_liquidate_loan(ctx: Context<LiquidateLoan>, repay_amount: u64) -> Result<()> {
+    // Seized collateral bypasses the transfer hook - verify KYC and
+    // eligibility here
+    let credential = verify_hub_credential(
+        &ctx.accounts.liquidator_credential.to_account_info(),
+        &ctx.accounts.liquidator.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+    ComplianceRules::enforce(&ctx.accounts.compliance_rules, &credential.attributes)?;
+
+    // 1. Check the position is past the liquidation threshold
+    let clock = Clock::get()?;
+    accrue_position_interest(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.loan_position,
+        clock.unix_timestamp,
+    )?;
+    // A stale valuation could mark a healthy position as liquidatable
+    ctx.accounts
+        .lending_market
+        .require_fresh_valuation(clock.unix_timestamp)?;
+
+    let collateral_amount = ctx.accounts.loan_position.collateral_amount;
+    let collateral_value = LendingMarket::collateral_value(
+        collateral_amount,
+        ctx.accounts.lending_market.valuation_usd,
+        ctx.accounts.property_state.total_supply,
+        ctx.accounts.loan_mint.decimals,
+    )?;
+    let debt = ctx.accounts.loan_position.debt;
+    require!(
+        ctx.accounts
+            .lending_market
+            .is_liquidatable(debt, collateral_value),
+        RwaError::PositionHealthy
+    );
+
+    let repaid = repay_amount.min(debt);
+    require!(repaid > 0, RwaError::InvalidAmount);
+    let seized = ctx.accounts.lending_market.seized_collateral(
+        repaid,
+        collateral_amount,
+        collateral_value,
+    )?;
+
+    // 2. Liquidator repays the debt
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.loan_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.liquidator_loan_account.to_account_info(),
+                mint: ctx.accounts.loan_mint.to_account_info(),
+                to: ctx.accounts.loan_vault.to_account_info(),
+                authority: ctx.accounts.liquidator.to_account_info(),
+            },
+        ),
+        repaid,
+        ctx.accounts.loan_mint.decimals,
+    )?;
+
+    // 3. Liquidator receives the seized collateral
+    if seized > 0 {
+        release_property_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.liquidator_token_account,
+            &mut ctx.accounts.property_state,
+            seized,
+        )?;
+    }
+
+    let loan_position = &mut ctx.accounts.loan_position;
+    loan_position.debt = debt.checked_sub(repaid).ok_or(RwaError::MathOverflow)?;
+    loan_position.collateral_amount = collateral_amount
+        .checked_sub(seized)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.total_borrowed = lending_market.total_borrowed.saturating_sub(repaid);
+    lending_market.total_collateral = lending_market
+        .total_collateral
+        .checked_sub(seized)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(LoanLiquidated {
+        property_mint: ctx.accounts.mint.key(),
+        borrower: loan_position.borrower,
+        liquidator: ctx.accounts.liquidator.key(),
+        repaid,
+        collateral_seized: seized,
+        debt: loan_position.debt,
+        collateral_amount: loan_position.collateral_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Liquidated {}: repaid {}, seized {} tokens",
+        loan_position.borrower,
+        repaid,
+        seized
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Accrues interest on a position and the market total
+fn accrue_position_interest(
+    lending_market: &mut LendingMarket,
+    loan_position: &mut LoanPosition,
+    current_timestamp: i64,
+) -> Result<()> {
+    let interest =
+        loan_position.accrue_interest(lending_market.interest_rate_bps, current_timestamp)?;
+    lending_market.total_borrowed = lending_market
+        .total_borrowed
+        .checked_add(interest)
+        .ok_or(RwaError::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Transfers stablecoin out of the market vault (Lending Market PDA signs)
+fn transfer_from_vault<'info>(
+    lending_market: &Account<'info, LendingMarket>,
+    loan_mint: &InterfaceAccount<'info, Mint>,
+    loan_vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    loan_token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        LENDING_MARKET_SEED,
+        lending_market.mint.as_ref(),
+        &[lending_market.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            loan_token_program.to_account_info(),
+            TransferChecked {
+                from: loan_vault.to_account_info(),
+                mint: loan_mint.to_account_info(),
+                to: destination.to_account_info(),
+                authority: lending_market.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        loan_mint.decimals,
+    )
+}
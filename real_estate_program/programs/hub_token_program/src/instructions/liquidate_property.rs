@@ -0,0 +1,373 @@
+/// Property Liquidation - Sale, Final Distribution and Token Retirement
+///
+/// When a property is sold the authority deposits the sale proceeds, the
+/// property enters a terminal liquidating state (no minting, no revenue,
+/// no transfers), holders burn their tokens for a pro-rata share of the
+/// proceeds, and once every token is retired - or the claim period has
+/// ended - the accounts can be closed.
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    constants::*, error::RwaError, events::*, instructions::burn_property_tokens::*, state::*,
+};
+
+// ============================================================================
+// LIQUIDATE PROPERTY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct LiquidateProperty<'info> {
+    /// Property authority depositing the sale proceeds
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status.allows_liquidation() @ RwaError::InvalidPropertyStatus,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidation PDA - holds the proceeds until redeemed
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PropertyLiquidation::INIT_SPACE,
+        seeds = [LIQUIDATION_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub property_liquidation: Box<Account<'info, PropertyLiquidation>>,
+
+    /// Reserve Fund PDA - swept into the final distribution
+    #[account(
+        mut,
+        seeds = [RESERVE_FUND_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub reserve_fund: SystemAccount<'info>,
+
+    /// Investment Vault PDA (optional, only if investments were accepted)
+    #[account(
+        mut,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+    )]
+    pub investment_vault: Option<Box<Account<'info, InvestmentVault>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for liquidate_property instruction
+pub fn handler_liquidate_property(
+    ctx: Context<LiquidateProperty>,
+    sale_proceeds: u64,
+) -> Result<()> {
+    require!(sale_proceeds > 0, RwaError::InvalidAmount);
+
+    let supply_at_liquidation = ctx.accounts.property_state.circulating_supply;
+    require!(supply_at_liquidation > 0, RwaError::NoTokenHolders);
+
+    let clock = Clock::get()?;
+    let mint_key = ctx.accounts.mint.key();
+
+    // 1. Deposit sale proceeds into the liquidation PDA
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.property_liquidation.to_account_info(),
+            },
+        ),
+        sale_proceeds,
+    )?;
+
+    // 2. Sweep the reserve fund - it belongs to the holders now
+    let reserve_swept = ctx.accounts.reserve_fund.lamports();
+    if reserve_swept > 0 {
+        let reserve_bump = ctx.bumps.reserve_fund;
+        let seeds = &[RESERVE_FUND_SEED, mint_key.as_ref(), &[reserve_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reserve_fund.to_account_info(),
+                    to: ctx.accounts.property_liquidation.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            reserve_swept,
+        )?;
+        msg!("Swept {} lamports from reserve fund", reserve_swept);
+    }
+
+    if let Some(investment_vault) = ctx.accounts.investment_vault.as_mut() {
+        investment_vault.reserve_balance = 0;
+        investment_vault.updated_at = clock.unix_timestamp;
+    }
+
+    // 3. Record the distribution
+    let total_distribution = sale_proceeds
+        .checked_add(reserve_swept)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let property_liquidation = &mut ctx.accounts.property_liquidation;
+    property_liquidation.property_state = ctx.accounts.property_state.key();
+    property_liquidation.mint = mint_key;
+    property_liquidation.sale_proceeds = sale_proceeds;
+    property_liquidation.reserve_swept = reserve_swept;
+    property_liquidation.remaining_proceeds = total_distribution;
+    property_liquidation.supply_at_liquidation = supply_at_liquidation;
+    property_liquidation.tokens_redeemed = 0;
+    property_liquidation.started_at = clock.unix_timestamp;
+    property_liquidation.bump = ctx.bumps.property_liquidation;
+
+    // 4. Enter the terminal liquidating state
+    let property_state = &mut ctx.accounts.property_state;
+    let previous_status = property_state.status;
+    property_state.status = PropertyStatus::Liquidating;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(PropertyStatusChanged {
+        mint: mint_key,
+        old_status: previous_status,
+        new_status: PropertyStatus::Liquidating,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(PropertyLiquidationStarted {
+        property_mint: mint_key,
+        authority: ctx.accounts.authority.key(),
+        sale_proceeds,
+        reserve_swept,
+        supply_at_liquidation,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Property {} liquidating: {} lamports for {} tokens",
+        property_state.property_name,
+        total_distribution,
+        supply_at_liquidation
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// REDEEM LIQUIDATION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RedeemLiquidation<'info> {
+    /// Token holder redeeming their tokens
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status == PropertyStatus::Liquidating @ RwaError::PropertyNotLiquidating,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Investor's token account
+    #[account(
+        mut,
+        constraint = investor_token_account.owner == investor.key() @ RwaError::Unauthorized,
+        constraint = investor_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidation PDA holding the proceeds
+    #[account(
+        mut,
+        seeds = [LIQUIDATION_SEED, mint.key().as_ref()],
+        bump = property_liquidation.bump,
+    )]
+    pub property_liquidation: Box<Account<'info, PropertyLiquidation>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for redeem_liquidation instruction
+pub fn handler_redeem_liquidation(ctx: Context<RedeemLiquidation>, amount: u64) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(
+        ctx.accounts.investor_token_account.amount >= amount,
+        RwaError::InsufficientBalance
+    );
+
+    let outstanding_supply = ctx.accounts.property_state.circulating_supply;
+    let payout = ctx
+        .accounts
+        .property_liquidation
+        .calculate_payout(amount, outstanding_supply)?;
+
+    // 1. Retire the tokens
+    burn_investor_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.investor_token_account,
+        &ctx.accounts.investor,
+        amount,
+    )?;
+
+    // 2. Pay the pro-rata share (liquidation PDA is owned by this program)
+    if payout > 0 {
+        **ctx
+            .accounts
+            .property_liquidation
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= payout;
+        **ctx.accounts.investor.to_account_info().try_borrow_mut_lamports()? += payout;
+    }
+
+    // 3. Update accounting
+    let clock = Clock::get()?;
+    let property_liquidation = &mut ctx.accounts.property_liquidation;
+    property_liquidation.remaining_proceeds = property_liquidation
+        .remaining_proceeds
+        .checked_sub(payout)
+        .ok_or(RwaError::MathOverflow)?;
+    property_liquidation.tokens_redeemed = property_liquidation
+        .tokens_redeemed
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(LiquidationRedeemed {
+        property_mint: ctx.accounts.mint.key(),
+        investor: ctx.accounts.investor.key(),
+        tokens_burned: amount,
+        payout,
+        remaining_proceeds: property_liquidation.remaining_proceeds,
+        circulating_supply: property_state.circulating_supply,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Redeemed {} tokens for {} lamports - Outstanding: {}",
+        amount,
+        payout,
+        property_state.circulating_supply
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLOSE LIQUIDATED PROPERTY
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CloseLiquidatedProperty<'info> {
+    /// Property authority (receives the rent of closed accounts)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA - closed
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status == PropertyStatus::Liquidating @ RwaError::PropertyNotLiquidating,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Liquidation PDA - closed, unclaimed proceeds go to the authority
+    #[account(
+        mut,
+        close = authority,
+        seeds = [LIQUIDATION_SEED, mint.key().as_ref()],
+        bump = property_liquidation.bump,
+    )]
+    pub property_liquidation: Box<Account<'info, PropertyLiquidation>>,
+
+    /// Investment Vault PDA - closed if it exists
+    #[account(
+        mut,
+        close = authority,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+    )]
+    pub investment_vault: Option<Box<Account<'info, InvestmentVault>>>,
+}
+
+/// Handler for close_liquidated_property instruction
+pub fn handler_close_liquidated_property(ctx: Context<CloseLiquidatedProperty>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.property_liquidation.can_close(
+            ctx.accounts.property_state.circulating_supply,
+            clock.unix_timestamp
+        ),
+        RwaError::TokensOutstanding
+    );
+
+    // Account is closed below; the event keeps the final transition indexable
+    ctx.accounts.property_state.status = PropertyStatus::Closed;
+
+    emit!(PropertyStatusChanged {
+        mint: ctx.accounts.mint.key(),
+        old_status: PropertyStatus::Liquidating,
+        new_status: PropertyStatus::Closed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    let total_distributed = ctx
+        .accounts
+        .property_liquidation
+        .total_distribution()
+        .saturating_sub(ctx.accounts.property_liquidation.remaining_proceeds);
+
+    emit!(PropertyClosed {
+        property_mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        total_distributed,
+        unclaimed_proceeds: ctx.accounts.property_liquidation.remaining_proceeds,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Property {} closed after distributing {} lamports",
+        ctx.accounts.property_state.property_name,
+        total_distributed
+    );
+
+    Ok(())
+}
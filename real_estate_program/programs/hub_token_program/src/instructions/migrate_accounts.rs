@@ -0,0 +1,210 @@
+/// Account migrations - Upgrade accounts created by earlier program versions
+///
+/// New PropertyState and InvestmentVault fields are appended after `bump`,
+/// so an old account keeps its layout and only needs to grow: the added
+/// bytes are zero, which is a valid default for every appended field.
+/// The transfer hook's ExtraAccountMetaList is grown and rewritten with the
+/// current entries: earlier lists held a credential entry Token-2022 could
+/// not resolve, and lacked the program PDA entries and the credential entries
+/// of types other than KycBasic.
+///
+/// Migrations are permissionless and idempotent; the payer funds the rent
+/// for the extra space.
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, instructions::transfer_hook::*, state::*};
+
+/// Size of an ExtraAccountMetaList holding all current entries
+pub const EXTRA_ACCOUNT_META_LIST_SIZE: usize =
+    8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE * EXTRA_ACCOUNT_META_COUNT;
+
+// ============================================================================
+// MIGRATE PROPERTY ACCOUNTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MigratePropertyAccounts<'info> {
+    /// Pays the rent for the added space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// PropertyState PDA (read raw: old accounts may not deserialize yet)
+    /// CHECK: Owner and seeds checked; discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub property_state: UncheckedAccount<'info>,
+
+    /// ExtraAccountMetaList PDA for the transfer hook
+    /// CHECK: Owner and seeds checked; rewritten by the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_property_accounts instruction
+pub fn handler_migrate_property_accounts(ctx: Context<MigratePropertyAccounts>) -> Result<()> {
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let property_state_info = ctx.accounts.property_state.to_account_info();
+    grow_account(
+        &property_state_info,
+        &payer,
+        &system_program,
+        8 + PropertyState::INIT_SPACE,
+    )?;
+    grow_account(
+        &ctx.accounts.extra_account_meta_list.to_account_info(),
+        &payer,
+        &system_program,
+        EXTRA_ACCOUNT_META_LIST_SIZE,
+    )?;
+
+    let mut property_state =
+        PropertyState::try_deserialize(&mut &property_state_info.try_borrow_data()?[..])?;
+    require_keys_eq!(property_state.mint, ctx.accounts.mint.key(), RwaError::InvalidMint);
+
+    let old_version = property_state.version;
+    property_state.version = PropertyState::CURRENT_VERSION;
+    property_state.try_serialize(&mut &mut property_state_info.try_borrow_mut_data()?[..])?;
+
+    // Rewrite the meta list with the current entries
+    {
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        require!(
+            data[0..8] == EXECUTE_DISCRIMINATOR,
+            RwaError::InvalidExtraAccountMetaList
+        );
+
+        write_extra_account_metas(&mut data);
+    }
+
+    emit!(AccountMigrated {
+        mint: ctx.accounts.mint.key(),
+        account: property_state_info.key(),
+        old_version,
+        new_version: PropertyState::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Property {} migrated: version {} -> {}",
+        ctx.accounts.mint.key(),
+        old_version,
+        PropertyState::CURRENT_VERSION
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// MIGRATE INVESTMENT VAULT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MigrateInvestmentVault<'info> {
+    /// Pays the rent for the added space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Investment Vault PDA (read raw: old vaults do not deserialize yet)
+    /// CHECK: Owner and seeds checked; discriminator checked in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub investment_vault: UncheckedAccount<'info>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for migrate_investment_vault instruction
+pub fn handler_migrate_investment_vault(ctx: Context<MigrateInvestmentVault>) -> Result<()> {
+    let vault_info = ctx.accounts.investment_vault.to_account_info();
+    grow_account(
+        &vault_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + InvestmentVault::INIT_SPACE,
+    )?;
+
+    let mut investment_vault =
+        InvestmentVault::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        investment_vault.property_mint,
+        ctx.accounts.mint.key(),
+        RwaError::InvalidMint
+    );
+
+    let old_version = investment_vault.version;
+    investment_vault.version = InvestmentVault::CURRENT_VERSION;
+    investment_vault.try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        mint: ctx.accounts.mint.key(),
+        account: vault_info.key(),
+        old_version,
+        new_version: InvestmentVault::CURRENT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Investment vault {} migrated: version {} -> {}",
+        vault_info.key(),
+        old_version,
+        InvestmentVault::CURRENT_VERSION
+    );
+
+    Ok(())
+}
+
+/// Resize a program-owned account to at least `new_len` bytes (zero-filled),
+/// topping up its lamports to stay rent exempt
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let required = Rent::get()?.minimum_balance(new_len);
+    let top_up = required.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
@@ -56,12 +56,15 @@ pub struct MintPropertyTokens<'info> {
 pub fn handler(ctx: Context<MintPropertyTokens>, amount: u64) -> Result<()> {
     let property_state = &mut ctx.accounts.property_state;
 
-    // 1. Verify property is active
-    require!(property_state.is_active, RwaError::PropertyNotActive);
+    // 1. Verify property status allows minting
+    require!(
+        property_state.status.allows_minting(),
+        RwaError::PropertyNotActive
+    );
 
     // 2. Verify minting won't exceed total supply
     require!(
-        property_state.circulating_supply + amount <= property_state.total_supply,
+        amount <= property_state.remaining_supply(),
         RwaError::ExceedsMaxSupply
     );
 
@@ -102,6 +105,15 @@ pub fn handler(ctx: Context<MintPropertyTokens>, amount: u64) -> Result<()> {
     property_state.circulating_supply += amount;
     property_state.updated_at = Clock::get()?.unix_timestamp;
 
+    if let Some(previous) = property_state.mark_funded_if_sold_out() {
+        emit!(PropertyStatusChanged {
+            mint: ctx.accounts.mint.key(),
+            old_status: previous,
+            new_status: property_state.status,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
     // 6. Emit tokens minted event
     emit!(TokensMinted {
         mint: ctx.accounts.mint.key(),
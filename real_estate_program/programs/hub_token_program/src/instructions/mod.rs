@@ -6,15 +6,49 @@ pub mod mint_property_tokens;
 pub mod burn_property_tokens;
 pub mod update_property_details;
 pub mod toggle_property_status;
+pub mod set_property_status;
 pub mod transfer_hook;
 pub mod revenue_vault;
 pub mod invest_in_property;
+pub mod liquidate_property;
+pub mod redemption;
+pub mod emergency_pause;
+pub mod voting;
+pub mod order_book;
+pub mod amm;
+pub mod transfer_fees;
+pub mod funding_rounds;
+pub mod set_investment_limits;
+pub mod set_compliance_rules;
+pub mod distributors;
+pub mod vesting;
+pub mod staking;
+pub mod bundles;
+pub mod lending;
+pub mod migrate_accounts;
 
 pub use create_property_mint::*;
 pub use mint_property_tokens::*;
 pub use burn_property_tokens::*;
 pub use update_property_details::*;
 pub use toggle_property_status::*;
+pub use set_property_status::*;
 pub use transfer_hook::*;
 pub use revenue_vault::*;
 pub use invest_in_property::*;
+pub use liquidate_property::*;
+pub use redemption::*;
+pub use emergency_pause::*;
+pub use voting::*;
+pub use order_book::*;
+pub use amm::*;
+pub use transfer_fees::*;
+pub use funding_rounds::*;
+pub use set_investment_limits::*;
+pub use set_compliance_rules::*;
+pub use distributors::*;
+pub use vesting::*;
+pub use staking::*;
+pub use bundles::*;
+pub use lending::*;
+pub use migrate_accounts::*;
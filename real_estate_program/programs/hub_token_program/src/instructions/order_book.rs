@@ -0,0 +1,632 @@
+/// Order Book - Secondary market limit orders
+///
+/// Makers rest sell orders (tokens escrowed by the program) or buy orders
+/// (lamports deposited into the order PDA); takers fill them partially or
+/// fully at the maker's price. Both sides must hold a valid Hub Credential,
+/// and fills respect the same status and pause checks as the transfer hook.
+///
+/// Fills move tokens by burn + mint, so Token-2022 withholds no transfer fee;
+/// when the mint has one, the fill charges it from the buyer's tokens into
+/// the fee collection account (see harvest_transfer_fees).
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+// ============================================================================
+// CREATE SELL ORDER
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct CreateSellOrder<'info> {
+    /// Seller placing the order
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Seller's token account (tokens are escrowed from here)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ RwaError::Unauthorized,
+        constraint = owner_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for seller KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub owner_credential: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    /// Market Order PDA
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MarketOrder::INIT_SPACE,
+        seeds = [
+            MARKET_ORDER_SEED,
+            mint.key().as_ref(),
+            owner.key().as_ref(),
+            &order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub order: Box<Account<'info, MarketOrder>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_sell_order instruction
+pub fn handler_create_sell_order(
+    ctx: Context<CreateSellOrder>,
+    order_id: u64,
+    price_per_token: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(price_per_token > 0 && amount > 0, RwaError::InvalidOrder);
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    verify_hub_credential(
+        &ctx.accounts.owner_credential.to_account_info(),
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    // 1. Escrow the tokens being sold
+    escrow_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.owner,
+        &mut ctx.accounts.property_state,
+        amount,
+    )?;
+
+    // 2. Record the order
+    let clock = Clock::get()?;
+    let order = &mut ctx.accounts.order;
+    order.property_state = ctx.accounts.property_state.key();
+    order.mint = ctx.accounts.mint.key();
+    order.owner = ctx.accounts.owner.key();
+    order.order_id = order_id;
+    order.side = OrderSide::Sell;
+    order.price_per_token = price_per_token;
+    order.amount = amount;
+    order.remaining = amount;
+    order.created_at = clock.unix_timestamp;
+    order.bump = ctx.bumps.order;
+
+    emit!(OrderPlaced {
+        property_mint: order.mint,
+        order: order.key(),
+        owner: order.owner,
+        order_id,
+        side: OrderSide::Sell,
+        price_per_token,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Sell order {} placed: {} tokens at {} lamports/token",
+        order_id,
+        amount,
+        price_per_token
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CREATE BUY ORDER
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct CreateBuyOrder<'info> {
+    /// Buyer placing the order
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Hub Credential account for buyer KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub owner_credential: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    /// Market Order PDA (holds the buyer's lamports)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + MarketOrder::INIT_SPACE,
+        seeds = [
+            MARKET_ORDER_SEED,
+            mint.key().as_ref(),
+            owner.key().as_ref(),
+            &order_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub order: Box<Account<'info, MarketOrder>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_buy_order instruction
+pub fn handler_create_buy_order(
+    ctx: Context<CreateBuyOrder>,
+    order_id: u64,
+    price_per_token: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(price_per_token > 0 && amount > 0, RwaError::InvalidOrder);
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    verify_hub_credential(
+        &ctx.accounts.owner_credential.to_account_info(),
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    // 1. Record the order
+    let clock = Clock::get()?;
+    let order = &mut ctx.accounts.order;
+    order.property_state = ctx.accounts.property_state.key();
+    order.mint = ctx.accounts.mint.key();
+    order.owner = ctx.accounts.owner.key();
+    order.order_id = order_id;
+    order.side = OrderSide::Buy;
+    order.price_per_token = price_per_token;
+    order.amount = amount;
+    order.remaining = amount;
+    order.created_at = clock.unix_timestamp;
+    order.bump = ctx.bumps.order;
+
+    // 2. Deposit the lamports for the full order into the PDA
+    let deposit = order.calculate_cost(amount, ctx.accounts.mint.decimals)?;
+    require!(deposit > 0, RwaError::TradeTooSmall);
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.order.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    emit!(OrderPlaced {
+        property_mint: ctx.accounts.mint.key(),
+        order: ctx.accounts.order.key(),
+        owner: ctx.accounts.owner.key(),
+        order_id,
+        side: OrderSide::Buy,
+        price_per_token,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Buy order {} placed: {} tokens at {} lamports/token ({} lamports deposited)",
+        order_id,
+        amount,
+        price_per_token,
+        deposit
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CANCEL ORDER
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    /// Order owner
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Market Order PDA - closed, rent and unfilled deposit returned to owner
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub order: Box<Account<'info, MarketOrder>>,
+
+    /// Owner's token account (required for sell orders)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ RwaError::Unauthorized,
+        constraint = owner_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub owner_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Hub Credential account for owner KYC (required for sell orders)
+    /// CHECK: Will be verified using Hub Credential program
+    pub owner_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for cancel_order instruction
+///
+/// Cancelling is always possible, even while transfers are paused, so
+/// makers can recover their escrowed funds.
+pub fn handler_cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let remaining = order.remaining;
+
+    if order.side == OrderSide::Sell && remaining > 0 {
+        let owner_token_account = ctx
+            .accounts
+            .owner_token_account
+            .as_ref()
+            .ok_or(RwaError::InvalidOrderAccounts)?;
+
+        // Released tokens bypass the transfer hook - verify KYC here
+        verify_hub_credential(
+            &ctx.accounts.owner_credential.to_account_info(),
+            &ctx.accounts.owner.key(),
+            &ctx.accounts.mint.key(),
+        )?;
+
+        release_property_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            owner_token_account,
+            &mut ctx.accounts.property_state,
+            remaining,
+        )?;
+    }
+
+    emit!(OrderCancelled {
+        property_mint: ctx.accounts.mint.key(),
+        order: ctx.accounts.order.key(),
+        owner: ctx.accounts.owner.key(),
+        order_id: ctx.accounts.order.order_id,
+        remaining,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Order {} cancelled with {} tokens unfilled",
+        ctx.accounts.order.order_id,
+        remaining
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// FILL ORDER
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    /// Taker filling the order
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    /// Order owner (receives lamports for sells, rent when fully filled)
+    #[account(
+        mut,
+        address = order.owner @ RwaError::InvalidOrderAccounts,
+    )]
+    pub maker: SystemAccount<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Market Order PDA
+    #[account(
+        mut,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub order: Box<Account<'info, MarketOrder>>,
+
+    /// Taker's token account (receives tokens on sells, pays tokens on buys)
+    #[account(
+        mut,
+        constraint = taker_token_account.owner == taker.key() @ RwaError::Unauthorized,
+        constraint = taker_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub taker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Maker's token account (required for buy orders)
+    #[account(
+        mut,
+        constraint = maker_token_account.owner == maker.key() @ RwaError::InvalidOrderAccounts,
+        constraint = maker_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub maker_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Fee collection account owned by the PropertyState PDA
+    /// (required when the mint charges a transfer fee)
+    #[account(
+        mut,
+        constraint = fee_collection_account.owner == property_state.key() @ RwaError::Unauthorized,
+        constraint = fee_collection_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub fee_collection_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Hub Credential account for taker KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub taker_credential: UncheckedAccount<'info>,
+
+    /// Hub Credential account for maker KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub maker_credential: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions for the buyer
+    /// CHECK: May not be initialized; read via ComplianceRules::enforce
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for fill_order instruction
+pub fn handler_fill_order(ctx: Context<FillOrder>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let order = &ctx.accounts.order;
+
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(amount <= order.remaining, RwaError::OrderAmountExceeded);
+    require_keys_neq!(
+        ctx.accounts.taker.key(),
+        ctx.accounts.maker.key(),
+        RwaError::SelfTrade
+    );
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
+    // 1. Both counterparties must be KYC-verified, and the buyer eligible
+    let taker_credential = verify_hub_credential(
+        &ctx.accounts.taker_credential.to_account_info(),
+        &ctx.accounts.taker.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+    let maker_credential = verify_hub_credential(
+        &ctx.accounts.maker_credential.to_account_info(),
+        &ctx.accounts.maker.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    let side = order.side;
+    let buyer_credential = match side {
+        OrderSide::Sell => &taker_credential,
+        OrderSide::Buy => &maker_credential,
+    };
+    ComplianceRules::enforce(&ctx.accounts.compliance_rules, &buyer_credential.attributes)?;
+
+    let price_per_token = order.price_per_token;
+    let cost = order.calculate_cost(amount, ctx.accounts.mint.decimals)?;
+    require!(cost > 0, RwaError::TradeTooSmall);
+
+    // 2. Royalty, as Token-2022 would withhold it on a transfer to the buyer
+    let royalty = transfer_fee_for(&ctx.accounts.mint, amount)?;
+    let delivered = amount.checked_sub(royalty).ok_or(RwaError::MathOverflow)?;
+    if royalty > 0 {
+        let fee_collection_account = ctx
+            .accounts
+            .fee_collection_account
+            .as_ref()
+            .ok_or(RwaError::InvalidOrderAccounts)?;
+
+        // Taken out of the escrowed tokens the buyer receives
+        if side == OrderSide::Buy {
+            escrow_property_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.mint,
+                &ctx.accounts.taker_token_account,
+                &ctx.accounts.taker,
+                &mut ctx.accounts.property_state,
+                royalty,
+            )?;
+        }
+        release_property_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            fee_collection_account,
+            &mut ctx.accounts.property_state,
+            royalty,
+        )?;
+
+        emit!(RoyaltyCharged {
+            mint: ctx.accounts.mint.key(),
+            order: ctx.accounts.order.key(),
+            buyer: match side {
+                OrderSide::Sell => ctx.accounts.taker.key(),
+                OrderSide::Buy => ctx.accounts.maker.key(),
+            },
+            amount,
+            royalty,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // 3. Settle
+    match side {
+        OrderSide::Sell => {
+            // Taker pays the maker, escrowed tokens go to the taker
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.taker.to_account_info(),
+                        to: ctx.accounts.maker.to_account_info(),
+                    },
+                ),
+                cost,
+            )?;
+
+            if delivered > 0 {
+                release_property_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.taker_token_account,
+                    &mut ctx.accounts.property_state,
+                    delivered,
+                )?;
+            }
+        }
+        OrderSide::Buy => {
+            // Taker's tokens go to the maker, deposit pays the taker
+            let maker_token_account = ctx
+                .accounts
+                .maker_token_account
+                .as_ref()
+                .ok_or(RwaError::InvalidOrderAccounts)?;
+
+            if delivered > 0 {
+                escrow_property_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.taker_token_account,
+                    &ctx.accounts.taker,
+                    &mut ctx.accounts.property_state,
+                    delivered,
+                )?;
+                release_property_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.mint,
+                    maker_token_account,
+                    &mut ctx.accounts.property_state,
+                    delivered,
+                )?;
+            }
+
+            // Order PDA is owned by this program
+            **ctx
+                .accounts
+                .order
+                .to_account_info()
+                .try_borrow_mut_lamports()? -= cost;
+            **ctx.accounts.taker.to_account_info().try_borrow_mut_lamports()? += cost;
+        }
+    }
+
+    // 4. Update the order
+    let order = &mut ctx.accounts.order;
+    order.remaining = order
+        .remaining
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    let remaining = order.remaining;
+
+    emit!(OrderFilled {
+        property_mint: ctx.accounts.mint.key(),
+        order: order.key(),
+        maker: ctx.accounts.maker.key(),
+        taker: ctx.accounts.taker.key(),
+        side,
+        price_per_token,
+        amount,
+        lamports: cost,
+        remaining,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Order {} filled: {} tokens for {} lamports ({} remaining)",
+        order.order_id,
+        amount,
+        cost,
+        remaining
+    );
+
+    // 5. Fully filled orders are closed (rent and deposit dust to maker)
+    if remaining == 0 {
+        ctx.accounts
+            .order
+            .close(ctx.accounts.maker.to_account_info())?;
+    }
+
+    Ok(())
+}
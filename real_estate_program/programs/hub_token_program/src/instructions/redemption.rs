@@ -0,0 +1,361 @@
+/// Redemption Window - Reserve-Funded Token Buyback
+///
+/// The property authority opens a window with a price per token and a
+/// budget drawn from the reserve fund. Investors redeem (burn) tokens for
+/// SOL first-come first-served until the budget or token cap runs out.
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    constants::*, error::RwaError, events::*, instructions::burn_property_tokens::*, state::*,
+};
+
+// ============================================================================
+// OPEN REDEMPTION WINDOW
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct OpenRedemptionWindow<'info> {
+    /// Property authority opening the window
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status.allows_transfers() @ RwaError::InvalidPropertyStatus,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Investment Vault PDA - tracks the reserve balance
+    #[account(
+        mut,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+        constraint = investment_vault.is_initialized @ RwaError::VaultNotInitialized,
+    )]
+    pub investment_vault: Box<Account<'info, InvestmentVault>>,
+
+    /// Reserve Fund PDA - source of the budget
+    #[account(
+        mut,
+        seeds = [RESERVE_FUND_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub reserve_fund: SystemAccount<'info>,
+
+    /// Redemption Window PDA - holds the budget while open
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RedemptionWindow::INIT_SPACE,
+        seeds = [REDEMPTION_WINDOW_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub redemption_window: Box<Account<'info, RedemptionWindow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for open_redemption_window instruction
+pub fn handler_open_redemption_window(
+    ctx: Context<OpenRedemptionWindow>,
+    price_per_token: u64,
+    budget: u64,
+    max_tokens: u64,
+    closes_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(price_per_token > 0, RwaError::InvalidRedemptionWindow);
+    require!(budget > 0, RwaError::InvalidAmount);
+    require!(max_tokens > 0, RwaError::InvalidRedemptionWindow);
+    require!(closes_at > clock.unix_timestamp, RwaError::InvalidRedemptionWindow);
+    require!(
+        ctx.accounts.investment_vault.reserve_balance >= budget
+            && ctx.accounts.reserve_fund.lamports() >= budget,
+        RwaError::InsufficientReserve
+    );
+
+    // 1. Move the budget from the reserve fund into the window PDA
+    let mint_key = ctx.accounts.mint.key();
+    let reserve_bump = ctx.bumps.reserve_fund;
+    let seeds = &[RESERVE_FUND_SEED, mint_key.as_ref(), &[reserve_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_fund.to_account_info(),
+                to: ctx.accounts.redemption_window.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        budget,
+    )?;
+
+    // 2. Update reserve accounting
+    let investment_vault = &mut ctx.accounts.investment_vault;
+    investment_vault.reserve_balance = investment_vault
+        .reserve_balance
+        .checked_sub(budget)
+        .ok_or(RwaError::MathOverflow)?;
+    investment_vault.updated_at = clock.unix_timestamp;
+
+    // 3. Initialize the window
+    let redemption_window = &mut ctx.accounts.redemption_window;
+    redemption_window.property_state = ctx.accounts.property_state.key();
+    redemption_window.mint = mint_key;
+    redemption_window.price_per_token = price_per_token;
+    redemption_window.budget = budget;
+    redemption_window.spent = 0;
+    redemption_window.max_tokens = max_tokens;
+    redemption_window.tokens_redeemed = 0;
+    redemption_window.opens_at = clock.unix_timestamp;
+    redemption_window.closes_at = closes_at;
+    redemption_window.bump = ctx.bumps.redemption_window;
+
+    emit!(RedemptionWindowOpened {
+        property_mint: mint_key,
+        price_per_token,
+        budget,
+        max_tokens,
+        closes_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Redemption window opened: {} lamports/token, budget {}, cap {} tokens",
+        price_per_token,
+        budget,
+        max_tokens
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// REDEEM TOKENS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RedeemTokens<'info> {
+    /// Investor redeeming their tokens
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status.allows_transfers() @ RwaError::InvalidPropertyStatus,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Investor's token account
+    #[account(
+        mut,
+        constraint = investor_token_account.owner == investor.key() @ RwaError::Unauthorized,
+        constraint = investor_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redemption Window PDA holding the budget
+    #[account(
+        mut,
+        seeds = [REDEMPTION_WINDOW_SEED, mint.key().as_ref()],
+        bump = redemption_window.bump,
+    )]
+    pub redemption_window: Box<Account<'info, RedemptionWindow>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for redeem_tokens instruction
+pub fn handler_redeem_tokens(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let redemption_window = &ctx.accounts.redemption_window;
+
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(
+        redemption_window.is_open(clock.unix_timestamp),
+        RwaError::RedemptionWindowNotOpen
+    );
+    require!(
+        amount <= redemption_window.remaining_tokens(),
+        RwaError::RedemptionCapExceeded
+    );
+    require!(
+        ctx.accounts.investor_token_account.amount >= amount,
+        RwaError::InsufficientBalance
+    );
+
+    // 1. Calculate payout against the remaining budget
+    let payout = redemption_window.calculate_payout(amount, ctx.accounts.mint.decimals)?;
+    require!(payout > 0, RwaError::ClaimTooSmall);
+    require!(
+        payout <= redemption_window.remaining_budget(),
+        RwaError::RedemptionBudgetExhausted
+    );
+
+    // 2. Burn the investor's tokens
+    burn_investor_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.investor_token_account,
+        &ctx.accounts.investor,
+        amount,
+    )?;
+
+    // 3. Pay the investor (window PDA is owned by this program)
+    **ctx
+        .accounts
+        .redemption_window
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.investor.to_account_info().try_borrow_mut_lamports()? += payout;
+
+    // 4. Update accounting
+    let redemption_window = &mut ctx.accounts.redemption_window;
+    redemption_window.spent = redemption_window
+        .spent
+        .checked_add(payout)
+        .ok_or(RwaError::MathOverflow)?;
+    redemption_window.tokens_redeemed = redemption_window
+        .tokens_redeemed
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(TokensRedeemed {
+        property_mint: ctx.accounts.mint.key(),
+        investor: ctx.accounts.investor.key(),
+        tokens_burned: amount,
+        payout,
+        remaining_budget: redemption_window.remaining_budget(),
+        circulating_supply: property_state.circulating_supply,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Redeemed {} tokens for {} lamports - Circulating: {}/{}",
+        amount,
+        payout,
+        property_state.circulating_supply,
+        property_state.total_supply
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLOSE REDEMPTION WINDOW
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CloseRedemptionWindow<'info> {
+    /// Property authority closing the window
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Investment Vault PDA - tracks the reserve balance
+    #[account(
+        mut,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+    )]
+    pub investment_vault: Box<Account<'info, InvestmentVault>>,
+
+    /// Reserve Fund PDA - receives the unspent budget
+    #[account(
+        mut,
+        seeds = [RESERVE_FUND_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub reserve_fund: SystemAccount<'info>,
+
+    /// Redemption Window PDA - closed, rent returned to authority
+    #[account(
+        mut,
+        close = authority,
+        seeds = [REDEMPTION_WINDOW_SEED, mint.key().as_ref()],
+        bump = redemption_window.bump,
+    )]
+    pub redemption_window: Box<Account<'info, RedemptionWindow>>,
+}
+
+/// Handler for close_redemption_window instruction
+pub fn handler_close_redemption_window(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+    let clock = Clock::get()?;
+    let unspent = ctx.accounts.redemption_window.remaining_budget();
+
+    // Return the unspent budget to the reserve fund
+    if unspent > 0 {
+        **ctx
+            .accounts
+            .redemption_window
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= unspent;
+        **ctx.accounts.reserve_fund.to_account_info().try_borrow_mut_lamports()? += unspent;
+
+        let investment_vault = &mut ctx.accounts.investment_vault;
+        investment_vault.reserve_balance = investment_vault
+            .reserve_balance
+            .checked_add(unspent)
+            .ok_or(RwaError::MathOverflow)?;
+        investment_vault.updated_at = clock.unix_timestamp;
+    }
+
+    let redemption_window = &ctx.accounts.redemption_window;
+
+    emit!(RedemptionWindowClosed {
+        property_mint: ctx.accounts.mint.key(),
+        tokens_redeemed: redemption_window.tokens_redeemed,
+        spent: redemption_window.spent,
+        returned_to_reserve: unspent,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Redemption window closed: {} tokens redeemed, {} lamports returned to reserve",
+        redemption_window.tokens_redeemed,
+        unspent
+    );
+
+    Ok(())
+}
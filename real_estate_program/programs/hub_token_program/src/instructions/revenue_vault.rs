@@ -6,7 +6,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
-use crate::{error::RwaError, state::*};
+use crate::{constants::STAKE_POSITION_SEED, error::RwaError, state::*};
 
 /// Revenue distribution epoch - tracks a single distribution period
 #[account]
@@ -18,7 +18,9 @@ pub struct RevenueEpoch {
     pub epoch_number: u64,
     /// Total revenue deposited for this epoch (in lamports)
     pub total_revenue: u64,
-    /// Total tokens eligible at time of deposit (snapshot)
+    /// Total revenue weight at time of deposit (snapshot)
+    /// Claimable circulating supply plus staking boosts
+    /// (`PropertyState::revenue_weight_supply`)
     pub eligible_supply: u64,
     /// Timestamp when revenue was deposited
     pub deposited_at: i64,
@@ -68,8 +70,9 @@ pub struct DepositRevenue<'info> {
 
     /// PropertyState - must be authority
     #[account(
+        mut,
         constraint = property_state.authority == authority.key() @ RwaError::Unauthorized,
-        constraint = property_state.is_active @ RwaError::PropertyNotActive,
+        constraint = property_state.status.allows_revenue() @ RwaError::InvalidPropertyStatus,
     )]
     pub property_state: Box<Account<'info, PropertyState>>,
 
@@ -109,19 +112,27 @@ pub fn handler_deposit_revenue(
 ) -> Result<()> {
     require!(amount > 0, RwaError::InvalidAmount);
 
-    let property_state = &ctx.accounts.property_state;
+    let clock = Clock::get()?;
+    let property_state = &mut ctx.accounts.property_state;
 
-    // Snapshot the current circulating supply for fair distribution
-    let eligible_supply = property_state.circulating_supply;
+    // Snapshot the current revenue weight (including staking boosts)
+    let eligible_supply = property_state.revenue_weight_supply();
     require!(eligible_supply > 0, RwaError::NoTokenHolders);
 
+    // Bundles use these to know which epochs they still have to collect
+    property_state.revenue_epoch_count = property_state
+        .revenue_epoch_count
+        .checked_add(1)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.last_revenue_at = clock.unix_timestamp;
+
     // Initialize the revenue epoch
     let revenue_epoch = &mut ctx.accounts.revenue_epoch;
     revenue_epoch.property_state = property_state.key();
     revenue_epoch.epoch_number = epoch_number;
     revenue_epoch.total_revenue = amount;
     revenue_epoch.eligible_supply = eligible_supply;
-    revenue_epoch.deposited_at = Clock::get()?.unix_timestamp;
+    revenue_epoch.deposited_at = clock.unix_timestamp;
     revenue_epoch.deposited_by = ctx.accounts.authority.key();
     revenue_epoch.is_finalized = true; // Single deposit per epoch
     revenue_epoch.bump = ctx.bumps.revenue_epoch;
@@ -210,6 +221,13 @@ pub struct ClaimRevenue<'info> {
     )]
     pub revenue_vault: SystemAccount<'info>,
 
+    /// Investor's stake position (optional) - adds boosted weight
+    #[account(
+        seeds = [STAKE_POSITION_SEED, mint.key().as_ref(), investor.key().as_ref()],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Option<Box<Account<'info, StakePosition>>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -217,14 +235,23 @@ pub struct ClaimRevenue<'info> {
 pub fn handler_claim_revenue(ctx: Context<ClaimRevenue>) -> Result<()> {
     let revenue_epoch = &ctx.accounts.revenue_epoch;
     let investor_balance = ctx.accounts.investor_token_account.amount;
-
-    // Must have tokens to claim
-    require!(investor_balance > 0, RwaError::InsufficientBalance);
+    let stake_weight = ctx
+        .accounts
+        .stake_position
+        .as_ref()
+        .map(|position| position.revenue_weight(revenue_epoch.deposited_at))
+        .unwrap_or(0);
+    let weight = investor_balance
+        .checked_add(stake_weight)
+        .ok_or(RwaError::MathOverflow)?;
+
+    // Must have tokens (held or staked) to claim
+    require!(weight > 0, RwaError::InsufficientBalance);
 
     // Calculate proportional share
-    // claim_amount = (investor_balance / eligible_supply) * total_revenue
+    // claim_amount = (weight / eligible_supply) * total_revenue
     // Using u128 to prevent overflow
-    let claim_amount = (investor_balance as u128)
+    let claim_amount = (weight as u128)
         .checked_mul(revenue_epoch.total_revenue as u128)
         .ok_or(RwaError::MathOverflow)?
         .checked_div(revenue_epoch.eligible_supply as u128)
@@ -252,10 +279,11 @@ pub fn handler_claim_revenue(ctx: Context<ClaimRevenue>) -> Result<()> {
     **ctx.accounts.investor.to_account_info().try_borrow_mut_lamports()? += claim_amount;
 
     msg!(
-        "Revenue claimed: {} lamports by {} (balance: {}, supply: {})",
+        "Revenue claimed: {} lamports by {} (balance: {}, staked weight: {}, supply: {})",
         claim_amount,
         ctx.accounts.investor.key(),
         investor_balance,
+        stake_weight,
         revenue_epoch.eligible_supply
     );
 
@@ -267,6 +295,7 @@ pub fn handler_claim_revenue(ctx: Context<ClaimRevenue>) -> Result<()> {
         investor: ctx.accounts.investor.key(),
         amount: claim_amount,
         investor_balance,
+        stake_weight,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -296,5 +325,6 @@ pub struct RevenueClaimed {
     pub investor: Pubkey,
     pub amount: u64,
     pub investor_balance: u64,
+    pub stake_weight: u64,
     pub timestamp: i64,
 }
@@ -0,0 +1,93 @@
+/// Set per-property compliance rules
+///
+/// Restricts which credential holders may invest by the compliance attributes
+/// of their Hub Credential (jurisdiction, investor tier, risk band, PEP flag),
+/// enforced by `invest_in_property`.
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+#[derive(Accounts)]
+pub struct SetComplianceRules<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// ComplianceRules PDA (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ComplianceRules::INIT_SPACE,
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: Box<Account<'info, ComplianceRules>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for set_compliance_rules instruction
+pub fn handler(
+    ctx: Context<SetComplianceRules>,
+    allowed_countries: Vec<[u8; 2]>,
+    blocked_countries: Vec<[u8; 2]>,
+    min_investor_tier: InvestorTier,
+    max_risk_band: RiskBand,
+    allow_pep: bool,
+) -> Result<()> {
+    require!(
+        allowed_countries.len() <= MAX_COMPLIANCE_COUNTRIES
+            && blocked_countries.len() <= MAX_COMPLIANCE_COUNTRIES,
+        RwaError::InvalidComplianceRules
+    );
+    require!(
+        allowed_countries
+            .iter()
+            .chain(blocked_countries.iter())
+            .all(is_country_code),
+        RwaError::InvalidComplianceRules
+    );
+
+    let clock = Clock::get()?;
+    let compliance_rules = &mut ctx.accounts.compliance_rules;
+    compliance_rules.property_state = ctx.accounts.property_state.key();
+    compliance_rules.allowed_countries = allowed_countries.clone();
+    compliance_rules.blocked_countries = blocked_countries.clone();
+    compliance_rules.min_investor_tier = min_investor_tier;
+    compliance_rules.max_risk_band = max_risk_band;
+    compliance_rules.allow_pep = allow_pep;
+    compliance_rules.updated_at = clock.unix_timestamp;
+    compliance_rules.bump = ctx.bumps.compliance_rules;
+
+    emit!(ComplianceRulesUpdated {
+        property_mint: ctx.accounts.mint.key(),
+        allowed_countries,
+        blocked_countries,
+        min_investor_tier,
+        max_risk_band,
+        allow_pep,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Compliance rules updated: min tier {:?}, max risk {:?}, PEP allowed: {}",
+        min_investor_tier,
+        max_risk_band,
+        allow_pep
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+/// Set per-property investment limits
+///
+/// Configures the minimum ticket and the per-credential-type caps enforced
+/// by `invest_in_property` (e.g. CVM 88 annual limits for retail investors).
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetInvestmentLimits<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// InvestmentLimits PDA (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + InvestmentLimits::INIT_SPACE,
+        seeds = [INVESTMENT_LIMITS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub investment_limits: Box<Account<'info, InvestmentLimits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for set_investment_limits instruction
+pub fn handler(
+    ctx: Context<SetInvestmentLimits>,
+    min_investment: u64,
+    max_investment_by_type: [u64; HUB_CREDENTIAL_TYPE_COUNT],
+    limit_period_seconds: i64,
+) -> Result<()> {
+    require!(limit_period_seconds >= 0, RwaError::InvalidInvestmentLimits);
+    require!(
+        max_investment_by_type
+            .iter()
+            .all(|&max| max == 0 || max >= min_investment),
+        RwaError::InvalidInvestmentLimits
+    );
+
+    let clock = Clock::get()?;
+    let investment_limits = &mut ctx.accounts.investment_limits;
+    investment_limits.property_state = ctx.accounts.property_state.key();
+    investment_limits.min_investment = min_investment;
+    investment_limits.max_investment_by_type = max_investment_by_type;
+    investment_limits.limit_period_seconds = limit_period_seconds;
+    investment_limits.updated_at = clock.unix_timestamp;
+    investment_limits.bump = ctx.bumps.investment_limits;
+
+    emit!(InvestmentLimitsUpdated {
+        property_mint: ctx.accounts.mint.key(),
+        min_investment,
+        max_investment_by_type,
+        limit_period_seconds,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Investment limits updated: min {} lamports, period {}s",
+        min_investment,
+        limit_period_seconds
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+/// Move a property through its lifecycle (Draft -> Fundraising -> ...)
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct SetPropertyStatus<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// Handler for set_property_status instruction
+pub fn handler(ctx: Context<SetPropertyStatus>, new_status: PropertyStatus) -> Result<()> {
+    let property_state = &mut ctx.accounts.property_state;
+
+    // Validates the transition (Liquidating/Closed have dedicated instructions)
+    let previous = property_state.transition_to(new_status)?;
+    property_state.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(PropertyStatusChanged {
+        mint: ctx.accounts.mint.key(),
+        old_status: previous,
+        new_status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Property {} status changed: {:?} -> {:?}",
+        property_state.property_name,
+        previous,
+        new_status
+    );
+
+    Ok(())
+}
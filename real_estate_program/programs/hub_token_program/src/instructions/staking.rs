@@ -0,0 +1,247 @@
+/// Staking - Lock property tokens for a boosted share of rent
+///
+/// Staked tokens move into program escrow for at least the chosen lock
+/// duration. Longer locks earn a larger revenue weight in `claim_revenue`,
+/// and stakes held at a proposal's snapshot are its voting weight in
+/// `cast_vote`.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+// ============================================================================
+// STAKE TOKENS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct StakeTokens<'info> {
+    /// Token holder staking
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stake Position PDA - one per holder per property
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [STAKE_POSITION_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    /// Owner's token account (tokens are locked from here)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ RwaError::Unauthorized,
+        constraint = owner_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for stake_tokens instruction
+///
+/// Adding to an existing stake restarts its lock, which may not be
+/// shorter than the current one.
+pub fn handler_stake_tokens(
+    ctx: Context<StakeTokens>,
+    amount: u64,
+    lock_duration: i64,
+) -> Result<()> {
+    let multiplier_bps = StakePosition::multiplier_for(lock_duration)?;
+
+    let stake_position = &ctx.accounts.stake_position;
+    if stake_position.amount > 0 {
+        require!(
+            lock_duration >= stake_position.lock_duration,
+            RwaError::InvalidStakeLock
+        );
+    }
+    let previous_boost = stake_position.boost_weight();
+
+    // 1. Lock the tokens in program escrow
+    escrow_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.owner_token_account,
+        &ctx.accounts.owner,
+        &mut ctx.accounts.property_state,
+        amount,
+    )?;
+
+    // 2. Update the position
+    let clock = Clock::get()?;
+    let stake_position = &mut ctx.accounts.stake_position;
+    if stake_position.amount == 0 {
+        stake_position.property_state = ctx.accounts.property_state.key();
+        stake_position.mint = ctx.accounts.mint.key();
+        stake_position.owner = ctx.accounts.owner.key();
+        stake_position.bump = ctx.bumps.stake_position;
+    }
+    stake_position.prior_amount = stake_position.amount;
+    stake_position.prior_staked_at = stake_position.staked_at;
+    stake_position.amount = stake_position
+        .amount
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    stake_position.lock_duration = lock_duration;
+    stake_position.multiplier_bps = multiplier_bps;
+    stake_position.staked_at = clock.unix_timestamp;
+    stake_position.unlock_at = clock
+        .unix_timestamp
+        .checked_add(lock_duration)
+        .ok_or(RwaError::MathOverflow)?;
+
+    // 3. Update property-wide staking totals
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.staked_supply = property_state
+        .staked_supply
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.staking_boost_weight = property_state
+        .staking_boost_weight
+        .saturating_sub(previous_boost)
+        .checked_add(stake_position.boost_weight())
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(TokensStaked {
+        property_mint: ctx.accounts.mint.key(),
+        stake_position: stake_position.key(),
+        owner: stake_position.owner,
+        amount,
+        total_staked: stake_position.amount,
+        multiplier_bps,
+        unlock_at: stake_position.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Staked {} tokens for {} ({} bps until {})",
+        amount,
+        stake_position.owner,
+        multiplier_bps,
+        stake_position.unlock_at
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// UNSTAKE TOKENS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UnstakeTokens<'info> {
+    /// Staker reclaiming their tokens
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Stake Position PDA - closed, rent returned to owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [STAKE_POSITION_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump = stake_position.bump,
+        has_one = owner @ RwaError::Unauthorized,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    /// Owner's token account (receives the unlocked tokens)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ RwaError::Unauthorized,
+        constraint = owner_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for owner KYC
+    /// CHECK: Will be verified using Hub Credential program
+    pub owner_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for unstake_tokens instruction
+pub fn handler_unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
+    let clock = Clock::get()?;
+    let stake_position = &ctx.accounts.stake_position;
+    require!(
+        stake_position.can_unstake(clock.unix_timestamp),
+        RwaError::StakeLocked
+    );
+    let amount = stake_position.amount;
+    let boost = stake_position.boost_weight();
+
+    // Released tokens bypass the transfer hook - verify KYC here
+    verify_hub_credential(
+        &ctx.accounts.owner_credential.to_account_info(),
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    release_property_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.owner_token_account,
+        &mut ctx.accounts.property_state,
+        amount,
+    )?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.staked_supply = property_state
+        .staked_supply
+        .checked_sub(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.staking_boost_weight = property_state
+        .staking_boost_weight
+        .saturating_sub(boost);
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(TokensUnstaked {
+        property_mint: ctx.accounts.mint.key(),
+        stake_position: ctx.accounts.stake_position.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Unstaked {} tokens for {}",
+        amount,
+        ctx.accounts.owner.key()
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-/// Toggle property active status (enable/disable minting)
+/// Toggle property pause (pause an active property / resume a paused one)
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
@@ -27,25 +27,29 @@ pub struct TogglePropertyStatus<'info> {
 pub fn handler(ctx: Context<TogglePropertyStatus>) -> Result<()> {
     let property_state = &mut ctx.accounts.property_state;
 
-    // Toggle active status
-    property_state.is_active = !property_state.is_active;
+    // Resume to the status the property was paused from, or pause it
+    let next = if property_state.status == PropertyStatus::Paused {
+        property_state.paused_from
+    } else {
+        PropertyStatus::Paused
+    };
+
+    let previous = property_state.transition_to(next)?;
     property_state.updated_at = Clock::get()?.unix_timestamp;
 
     // Emit status changed event
     emit!(PropertyStatusChanged {
         mint: ctx.accounts.mint.key(),
-        is_active: property_state.is_active,
+        old_status: previous,
+        new_status: property_state.status,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
     msg!(
-        "Property {} status changed to: {}",
+        "Property {} status changed: {:?} -> {:?}",
         property_state.property_name,
-        if property_state.is_active {
-            "ACTIVE"
-        } else {
-            "INACTIVE"
-        }
+        previous,
+        property_state.status
     );
 
     Ok(())
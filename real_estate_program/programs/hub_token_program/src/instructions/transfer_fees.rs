@@ -0,0 +1,276 @@
+/// Transfer Fees - Secondary-trade royalties
+///
+/// Token-2022 withholds the transfer fee in each recipient's token account.
+/// Order-book fills settle without a token transfer and charge the same fee
+/// into the fee collection account instead.
+///
+/// Harvesting sweeps the withheld amounts to the mint, withdraws them
+/// (PropertyState PDA is the withdraw authority) into the collection account
+/// and splits the whole collection balance:
+/// - Platform share: Re-issued to the platform treasury via burn + mint,
+///   since this program cannot transfer its own hooked mint
+/// - Retired share: Burned and removed from `total_supply` and
+///   `circulating_supply`, so it accrues to the remaining holders
+///
+/// The PropertyState PDA is also the fee config authority; the rate only
+/// changes through set_transfer_fee, which enforces MAX_TRANSFER_FEE_BPS.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_spl::{
+    token_2022::{self, spl_token_2022, Burn, MintTo, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    /// Property authority or platform admin
+    #[account(
+        constraint = caller.key() == property_state.authority
+            || caller.key() == platform_config.admin @ RwaError::Unauthorized,
+    )]
+    pub caller: Signer<'info>,
+
+    /// PlatformConfig PDA (stores the platform admin)
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// PropertyState PDA (withdraw withheld authority)
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.transfer_fee_enabled @ RwaError::TransferFeeNotEnabled,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Collection account owned by the PropertyState PDA
+    #[account(
+        mut,
+        constraint = fee_collection_account.owner == property_state.key() @ RwaError::Unauthorized,
+        constraint = fee_collection_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub fee_collection_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury token account (receives the platform share)
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == PLATFORM_TREASURY @ RwaError::InvalidPlatformTreasury,
+        constraint = treasury_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    // remaining_accounts: token accounts to harvest withheld fees from
+}
+
+/// Handler for harvest_transfer_fees instruction
+pub fn handler_harvest_transfer_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let property_state_key = ctx.accounts.property_state.key();
+    let seeds = &[
+        PROPERTY_STATE_SEED,
+        mint_key.as_ref(),
+        &[ctx.accounts.property_state.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // 1. Sweep withheld fees from token accounts into the mint (permissionless)
+    let sources = ctx.remaining_accounts;
+    if !sources.is_empty() {
+        let source_keys: Vec<&Pubkey> = sources.iter().map(|account| account.key).collect();
+        let harvest_ix = spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            &token_2022::ID,
+            &mint_key,
+            &source_keys,
+        )?;
+
+        let mut harvest_accounts = vec![ctx.accounts.mint.to_account_info()];
+        harvest_accounts.extend(sources.iter().cloned());
+        invoke(&harvest_ix, &harvest_accounts)?;
+    }
+
+    // 2. Withdraw everything withheld in the mint to the collection account
+    let withdraw_ix = spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+        &token_2022::ID,
+        &mint_key,
+        &ctx.accounts.fee_collection_account.key(),
+        &property_state_key,
+        &[],
+    )?;
+
+    invoke_signed(
+        &withdraw_ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.fee_collection_account.to_account_info(),
+            ctx.accounts.property_state.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    // Withheld fees plus royalties charged by order-book fills
+    ctx.accounts.fee_collection_account.reload()?;
+    let total_withdrawn = ctx.accounts.fee_collection_account.amount;
+    require!(total_withdrawn > 0, RwaError::NoFundsAvailable);
+
+    // 3. Burn the collection, re-issue the platform share, retire the rest
+    let (platform_share, retired_share) = TransferFeeParams::split(
+        total_withdrawn,
+        ctx.accounts.property_state.transfer_fee_retired_share_bps,
+    );
+
+    token_2022::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.fee_collection_account.to_account_info(),
+                authority: ctx.accounts.property_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        total_withdrawn,
+    )?;
+
+    if platform_share > 0 {
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.property_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            platform_share,
+        )?;
+    }
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.total_supply = property_state
+        .total_supply
+        .checked_sub(retired_share)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_sub(retired_share)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(TransferFeesHarvested {
+        mint: mint_key,
+        harvested_accounts: sources.len() as u32,
+        total_withdrawn,
+        platform_share,
+        retired_share,
+        total_supply: property_state.total_supply,
+        circulating_supply: property_state.circulating_supply,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Harvested {} tokens in transfer fees: {} to treasury, {} retired",
+        total_withdrawn,
+        platform_share,
+        retired_share
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// SET TRANSFER FEE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    /// Property authority
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA (transfer fee config authority)
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.transfer_fee_enabled @ RwaError::TransferFeeNotEnabled,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for set_transfer_fee instruction
+/// Token-2022 applies a new rate two epochs after it is set; the retired
+/// share applies from the next harvest
+pub fn handler_set_transfer_fee(
+    ctx: Context<SetTransferFee>,
+    params: TransferFeeParams,
+) -> Result<()> {
+    params.validate()?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let property_state_key = ctx.accounts.property_state.key();
+    let seeds = &[
+        PROPERTY_STATE_SEED,
+        mint_key.as_ref(),
+        &[ctx.accounts.property_state.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let set_fee_ix = spl_token_2022::extension::transfer_fee::instruction::set_transfer_fee(
+        &token_2022::ID,
+        &mint_key,
+        &property_state_key,
+        &[],
+        params.fee_bps,
+        params.max_fee,
+    )?;
+
+    invoke_signed(
+        &set_fee_ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.property_state.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    property_state.transfer_fee_retired_share_bps = params.retired_share_bps;
+    property_state.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(TransferFeeUpdated {
+        mint: mint_key,
+        fee_bps: params.fee_bps,
+        max_fee: params.max_fee,
+        retired_share_bps: params.retired_share_bps,
+        timestamp: property_state.updated_at,
+    });
+
+    msg!(
+        "Transfer fee set to {} bps (max {}), {} bps retired",
+        params.fee_bps,
+        params.max_fee,
+        params.retired_share_bps
+    );
+
+    Ok(())
+}
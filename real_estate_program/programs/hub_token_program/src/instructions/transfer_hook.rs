@@ -7,7 +7,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::{constants::HUB_CREDENTIAL_PROGRAM_ID, error::RwaError, events::*, utils::*};
+use crate::{
+    constants::{
+        ANY_HUB_CREDENTIAL_TYPE, COMPLIANCE_RULES_SEED, HUB_CREDENTIAL_PROGRAM_ID,
+        HUB_CREDENTIAL_TYPE_COUNT, PLATFORM_CONFIG_SEED, PROPERTY_STATE_SEED,
+    },
+    error::RwaError,
+    events::*,
+    state::*,
+    utils::*,
+};
 
 /// Seeds for the ExtraAccountMeta PDA
 pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
@@ -16,6 +25,20 @@ pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
 /// discriminator (1) + address_config (32) + is_signer (1) + is_writable (1)
 pub const EXTRA_ACCOUNT_META_SIZE: usize = 35;
 
+/// Number of extra accounts required by the Transfer Hook
+/// 1. Hub Credential program (owner of the credential PDAs below)
+/// 2. Hub Credential (KycBasic) for the destination wallet
+/// 3. PropertyState PDA (lifecycle and transfer pause checks)
+/// 4. PlatformConfig PDA (platform-wide circuit breaker)
+/// 5. ComplianceRules PDA (jurisdiction and risk restrictions)
+/// 6. Hub Credentials of the remaining types (KycFull to BrazilianCnpj),
+///    appended so earlier entries keep their positions
+pub const EXTRA_ACCOUNT_META_COUNT: usize = 4 + HUB_CREDENTIAL_TYPE_COUNT;
+
+/// Index of the first extra account in the Execute instruction
+/// (after source, mint, destination, owner and the ExtraAccountMetaList)
+const FIRST_EXTRA_ACCOUNT_INDEX: u8 = 5;
+
 /// Execute discriminator for Transfer Hook interface
 /// SHA256("spl-transfer-hook-interface:execute")[0..8]
 pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
@@ -29,7 +52,9 @@ pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26]
 /// 2. Destination token account
 /// 3. Owner/Authority
 /// 4. ExtraAccountMetaList PDA
-/// 5+ Additional accounts from ExtraAccountMetaList
+/// 5. Additional accounts from ExtraAccountMetaList (Hub Credential program,
+///    Hub Credential, PropertyState, PlatformConfig, ComplianceRules)
+/// 6. Hub Credentials of the other types, as remaining accounts
 #[derive(Accounts)]
 pub struct TransferHook<'info> {
     /// Source token account (sender)
@@ -57,15 +82,51 @@ pub struct TransferHook<'info> {
     )]
     pub extra_account_meta_list: UncheckedAccount<'info>,
 
-    /// Hub Credential for destination wallet (KYC verification)
-    /// This is the Hub Credential that proves the destination wallet has completed KYC.
+    /// Hub Credential program (resolves the credential PDA below)
+    /// CHECK: Address checked
+    #[account(address = HUB_CREDENTIAL_PROGRAM_ID)]
+    pub hub_credential_program: UncheckedAccount<'info>,
+
+    /// Hub Credential (KycBasic) for destination wallet (KYC verification)
+    /// Credentials of the other types follow as remaining accounts; any one
+    /// of them proves the destination wallet has completed KYC.
     /// CHECK: Will be verified using Hub Credential program
     pub hub_credential: UncheckedAccount<'info>,
+
+    /// PropertyState PDA - transfers are gated on the lifecycle status
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// PlatformConfig PDA - platform-wide transfer circuit breaker
+    /// CHECK: May not be initialized yet; read via PlatformConfig::global_transfers_paused
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump,
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    /// ComplianceRules PDA - jurisdiction and risk restrictions for the destination
+    /// CHECK: May not be initialized; read via ComplianceRules::enforce
+    #[account(
+        seeds = [COMPLIANCE_RULES_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub compliance_rules: UncheckedAccount<'info>,
 }
 
 /// Handler for transfer hook execute instruction
 /// This is called by Token-2022 during every transfer
-pub fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    ctx.accounts
+        .property_state
+        .ensure_transfers_allowed(&ctx.accounts.platform_config)?;
+
     msg!("Transfer Hook: Verifying Hub Credential for transfer of {} tokens", amount);
 
     // Get destination wallet from the token account
@@ -77,17 +138,30 @@ pub fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
     let destination_owner = Pubkey::try_from(&destination_data[32..64])
         .map_err(|_| RwaError::KycVerificationRequired)?;
 
-    // Verify Hub Credential for destination wallet
-    verify_hub_credential(
-        &ctx.accounts.hub_credential.to_account_info(),
-        &destination_owner,
-        &ctx.accounts.mint.key(),
-    )?;
-
-    msg!(
-        "Transfer Hook: Hub Credential verified for destination wallet: {}",
-        destination_owner
-    );
+    // The liquidity pool vault is a program account: traders selling into it
+    // were checked by the swap, and buyers are checked when tokens leave it
+    let (pool, _) = LiquidityPool::find_address(&ctx.accounts.mint.key());
+    if destination_owner == pool {
+        msg!("Transfer Hook: destination is the liquidity pool vault");
+    } else {
+        // Verify Hub Credential for destination wallet (any credential type)
+        let credential_accounts: Vec<AccountInfo> =
+            std::iter::once(ctx.accounts.hub_credential.to_account_info())
+                .chain(ctx.remaining_accounts.iter().cloned())
+                .collect();
+        let credential = verify_any_hub_credential(
+            &credential_accounts,
+            &destination_owner,
+            &ctx.accounts.mint.key(),
+            ANY_HUB_CREDENTIAL_TYPE,
+        )?;
+        ComplianceRules::enforce(&ctx.accounts.compliance_rules, &credential.attributes)?;
+
+        msg!(
+            "Transfer Hook: Hub Credential verified for destination wallet: {}",
+            destination_owner
+        );
+    }
 
     // Emit transfer verification event
     emit!(TransferKycVerified {
@@ -114,8 +188,8 @@ pub struct InitializeExtraAccountMetaList<'info> {
     #[account(
         init,
         payer = payer,
-        // Space: 8 (anchor discriminator) + 4 (length) + 4 (count) + 35 (1 extra account meta)
-        space = 8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE,
+        // Space: 8 (anchor discriminator) + 4 (length) + 4 (count) + 35 per extra account meta
+        space = 8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE * EXTRA_ACCOUNT_META_COUNT,
         seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
         bump,
     )]
@@ -130,73 +204,218 @@ pub struct InitializeExtraAccountMetaList<'info> {
 /// Handler to initialize the extra account meta list
 /// This stores the configuration for which additional accounts are needed during transfers
 ///
-/// For Hub Credential verification, we need the credential account to be passed.
-/// The credential PDA is derived from:
+/// For Hub Credential verification, the credential accounts must be passed.
+/// Each credential PDA is derived from:
 /// - "credential" literal
-/// - Destination wallet pubkey
+/// - Destination wallet pubkey (owner field of the destination token account)
+/// - Credential type byte (one entry per type)
 /// - Hub Credential Program ID
 pub fn handler_initialize_extra_account_metas(
     ctx: Context<InitializeExtraAccountMetaList>,
 ) -> Result<()> {
-    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    write_extra_account_metas(&mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?);
+
+    msg!(
+        "Initialized ExtraAccountMetaList for mint: {}",
+        ctx.accounts.mint.key()
+    );
+    msg!(
+        "Hub Credential Program: {}",
+        HUB_CREDENTIAL_PROGRAM_ID
+    );
 
-    // Write the ExtraAccountMetaList manually
-    // Format: ExecuteInstruction discriminator (8) + length (4) + count (4) + metas (35 * count)
+    emit!(ExtraAccountMetasInitialized {
+        mint: ctx.accounts.mint.key(),
+        extra_account_meta_list: ctx.accounts.extra_account_meta_list.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
 
+/// Write the whole ExtraAccountMetaList in the TLV layout read by Token-2022
+///
+/// Format: Execute discriminator (8) + length (4) + count (4) + metas (35 * count)
+pub fn write_extra_account_metas(data: &mut [u8]) {
     // Write Execute discriminator
     data[0..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
 
-    // Write length (total size of the account list data: 4 + 35*1 = 39)
-    let list_length: u32 = 4 + EXTRA_ACCOUNT_META_SIZE as u32;
+    // Write length (total size of the account list data: 4 + 35*10 = 354)
+    let list_length: u32 = 4 + (EXTRA_ACCOUNT_META_SIZE * EXTRA_ACCOUNT_META_COUNT) as u32;
     data[8..12].copy_from_slice(&list_length.to_le_bytes());
 
-    // Write count (1 extra account)
-    let count: u32 = 1;
+    // Write count (10 extra accounts)
+    let count: u32 = EXTRA_ACCOUNT_META_COUNT as u32;
     data[12..16].copy_from_slice(&count.to_le_bytes());
 
-    // Write ExtraAccountMeta for Hub Credential PDA
-    // We use discriminator = 2 (External PDA - PDA from another program)
-    data[16] = 2;
+    let metas = &mut data[16..];
 
-    // address_config: seeds configuration for Hub Credential PDA
-    // Credential PDA seeds: ["credential", wallet]
-    // We need to get the destination wallet from the destination token account's owner field
-    let mut address_config = [0u8; 32];
+    // Hub Credential program: fixed address (discriminator = 0)
+    metas[0] = 0;
+    metas[1..33].copy_from_slice(HUB_CREDENTIAL_PROGRAM_ID.as_ref());
+    metas[33] = 0; // is_signer
+    metas[34] = 0; // is_writable
 
-    // Seed 1: Literal "credential" (10 bytes)
-    address_config[0] = 10; // length
-    address_config[1] = 0;  // seed type: Literal
-    // "credential" bytes would go here in a proper implementation
+    // Hub Credential PDA (KycBasic) of the Hub Credential program
+    write_credential_meta(
+        &mut metas[EXTRA_ACCOUNT_META_SIZE..EXTRA_ACCOUNT_META_SIZE * 2],
+        HubCredentialType::KycBasic,
+    );
 
-    // Seed 2: AccountData - get owner from destination token account (index 2)
-    // Token account owner is at offset 32-64 (32 bytes)
-    address_config[12] = 32; // length
-    address_config[13] = 2;  // seed type: AccountData
-    address_config[14] = 2;  // account index (destination token account)
-    address_config[15] = 32; // data offset (owner field starts at byte 32)
+    // PropertyState, PlatformConfig and ComplianceRules PDAs
+    write_program_pda_metas(&mut metas[EXTRA_ACCOUNT_META_SIZE * 2..]);
+
+    // Hub Credential PDAs of the remaining types
+    let other_types = (1..HUB_CREDENTIAL_TYPE_COUNT as u8).filter_map(HubCredentialType::from_u8);
+    for (index, credential_type) in other_types.enumerate() {
+        let offset = EXTRA_ACCOUNT_META_SIZE * (5 + index);
+        write_credential_meta(
+            &mut metas[offset..offset + EXTRA_ACCOUNT_META_SIZE],
+            credential_type,
+        );
+    }
+}
 
-    // Store address config
-    data[17..49].copy_from_slice(&address_config);
+/// Write the ExtraAccountMeta for the destination wallet's Hub Credential of one type
+///
+/// Seeds: ["credential", wallet, credential_type] of the Hub Credential program
+fn write_credential_meta(meta: &mut [u8], credential_type: HubCredentialType) {
+    // discriminator = 128 + account index of the owning program (the first extra account)
+    meta[0] = 128 + FIRST_EXTRA_ACCOUNT_INDEX;
 
-    // is_signer = false
-    data[49] = 0;
-    // is_writable = false
-    data[50] = 0;
+    let mut address_config = [0u8; 32];
+    // Seed 1: Literal "credential"
+    address_config[0] = 1; // seed type: Literal
+    address_config[1] = 10; // length
+    address_config[2..12].copy_from_slice(b"credential");
+
+    // Seed 2: AccountData - owner of the destination token account (index 2)
+    address_config[12] = 4; // seed type: AccountData
+    address_config[13] = 2; // account index (destination token account)
+    address_config[14] = 32; // data offset (owner field starts at byte 32)
+    address_config[15] = 32; // length
+
+    // Seed 3: Literal credential type byte
+    address_config[16] = 1; // seed type: Literal
+    address_config[17] = 1; // length
+    address_config[18] = credential_type as u8;
+
+    meta[1..33].copy_from_slice(&address_config);
+    meta[33] = 0; // is_signer
+    meta[34] = 0; // is_writable
+}
 
-    msg!(
-        "Initialized ExtraAccountMetaList for mint: {}",
-        ctx.accounts.mint.key()
+/// Write the ExtraAccountMetas for this program's PDAs
+///
+/// - PropertyState: seeds ["property", mint] (lifecycle and pause checks)
+/// - PlatformConfig: seeds ["platform_config"] (platform circuit breaker)
+/// - ComplianceRules: seeds ["compliance_rules", mint] (destination eligibility)
+fn write_program_pda_metas(metas: &mut [u8]) {
+    write_program_pda_meta(
+        &mut metas[0..EXTRA_ACCOUNT_META_SIZE],
+        PROPERTY_STATE_SEED,
+        true,
     );
-    msg!(
-        "Hub Credential Program: {}",
-        HUB_CREDENTIAL_PROGRAM_ID
+    write_program_pda_meta(
+        &mut metas[EXTRA_ACCOUNT_META_SIZE..EXTRA_ACCOUNT_META_SIZE * 2],
+        PLATFORM_CONFIG_SEED,
+        false,
     );
+    write_program_pda_meta(
+        &mut metas[EXTRA_ACCOUNT_META_SIZE * 2..EXTRA_ACCOUNT_META_SIZE * 3],
+        COMPLIANCE_RULES_SEED,
+        true,
+    );
+}
 
-    emit!(ExtraAccountMetasInitialized {
-        mint: ctx.accounts.mint.key(),
-        extra_account_meta_list: ctx.accounts.extra_account_meta_list.key(),
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+/// Write a single ExtraAccountMeta for a PDA of this program
+///
+/// Seeds are a literal, optionally followed by the mint
+/// (account index 1 of the Execute instruction).
+fn write_program_pda_meta(meta: &mut [u8], literal: &[u8], with_mint: bool) {
+    // discriminator = 1 (PDA derived from the hook program)
+    meta[0] = 1;
 
-    Ok(())
+    let mut address_config = [0u8; 32];
+    // Seed 1: Literal
+    address_config[0] = 1; // seed type: Literal
+    address_config[1] = literal.len() as u8; // length
+    address_config[2..2 + literal.len()].copy_from_slice(literal);
+    // Seed 2: AccountKey index 1 (mint)
+    if with_mint {
+        let offset = 2 + literal.len();
+        address_config[offset] = 3; // seed type: AccountKey
+        address_config[offset + 1] = 1; // index
+    }
+
+    meta[1..33].copy_from_slice(&address_config);
+
+    // is_signer = false
+    meta[33] = 0;
+    // is_writable = false
+    meta[34] = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::instruction::AccountMeta;
+    use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+    use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+    use spl_type_length_value::state::TlvStateBorrowed;
+
+    #[test]
+    fn test_extra_account_metas_resolve() {
+        let mut data = vec![0u8; 8 + 4 + 4 + EXTRA_ACCOUNT_META_SIZE * EXTRA_ACCOUNT_META_COUNT];
+        assert_eq!(
+            data.len(),
+            ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_META_COUNT).unwrap()
+        );
+        write_extra_account_metas(&mut data);
+
+        let tlv_state = TlvStateBorrowed::unpack(&data).unwrap();
+        let metas =
+            ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state).unwrap();
+        assert_eq!(metas.data().len(), EXTRA_ACCOUNT_META_COUNT);
+
+        // Execute accounts: source, mint, destination (owner at 32..64), owner, meta list
+        let mint = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let mut destination_data = vec![0u8; 165];
+        destination_data[32..64].copy_from_slice(wallet.as_ref());
+        let mut accounts: Vec<(Pubkey, Option<Vec<u8>>)> = vec![
+            (Pubkey::new_unique(), None),
+            (mint, None),
+            (Pubkey::new_unique(), Some(destination_data)),
+            (Pubkey::new_unique(), None),
+            (Pubkey::new_unique(), None),
+        ];
+
+        let instruction_data = TransferHookInstruction::Execute { amount: 1 }.pack();
+        for meta in metas.data() {
+            let resolved: AccountMeta = meta
+                .resolve(&instruction_data, &crate::ID, |index| {
+                    accounts
+                        .get(index)
+                        .map(|(key, data)| (key, data.as_deref()))
+                })
+                .unwrap();
+            assert!(!resolved.is_signer && !resolved.is_writable);
+            accounts.push((resolved.pubkey, None));
+        }
+
+        let resolved: Vec<Pubkey> = accounts[5..].iter().map(|(key, _)| *key).collect();
+        let mut expected = vec![
+            HUB_CREDENTIAL_PROGRAM_ID,
+            derive_hub_credential_pda(&wallet, HubCredentialType::KycBasic).0,
+            Pubkey::find_program_address(&[PROPERTY_STATE_SEED, mint.as_ref()], &crate::ID).0,
+            Pubkey::find_program_address(&[PLATFORM_CONFIG_SEED], &crate::ID).0,
+            Pubkey::find_program_address(&[COMPLIANCE_RULES_SEED, mint.as_ref()], &crate::ID).0,
+        ];
+        expected.extend((1..HUB_CREDENTIAL_TYPE_COUNT as u8).map(|credential_type| {
+            let credential_type = HubCredentialType::from_u8(credential_type).unwrap();
+            derive_hub_credential_pda(&wallet, credential_type).0
+        }));
+        assert_eq!(resolved, expected);
+    }
 }
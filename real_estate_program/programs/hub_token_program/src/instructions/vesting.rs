@@ -0,0 +1,236 @@
+/// Vesting - Sponsor/team token allocations
+///
+/// While a property is still in Draft, the authority reserves part of
+/// `total_supply` for sponsors in a VestingSchedule PDA. Reserved tokens
+/// are not minted up front; `claim_vested_tokens` mints the vested portion
+/// to the beneficiary, who must hold a valid Hub Credential like any holder.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, MintTo, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::*, error::RwaError, events::*, state::*, utils::*};
+
+// ============================================================================
+// CREATE VESTING SCHEDULE
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey)]
+pub struct CreateVestingSchedule<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status == PropertyStatus::Draft @ RwaError::InvalidPropertyStatus,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vesting Schedule PDA (one per beneficiary)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [VESTING_SCHEDULE_SEED, mint.key().as_ref(), beneficiary.as_ref()],
+        bump,
+    )]
+    pub vesting_schedule: Box<Account<'info, VestingSchedule>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_vesting_schedule instruction
+pub fn handler_create_vesting_schedule(
+    ctx: Context<CreateVestingSchedule>,
+    beneficiary: Pubkey,
+    total_amount: u64,
+    start_at: i64,
+    cliff_at: i64,
+    end_at: i64,
+) -> Result<()> {
+    VestingSchedule::validate(total_amount, start_at, cliff_at, end_at)?;
+
+    let property_state = &mut ctx.accounts.property_state;
+    require!(
+        total_amount <= property_state.remaining_supply(),
+        RwaError::ExceedsMaxSupply
+    );
+
+    let clock = Clock::get()?;
+    property_state.sponsor_allocated_supply = property_state
+        .sponsor_allocated_supply
+        .checked_add(total_amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.updated_at = clock.unix_timestamp;
+
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    vesting_schedule.property_state = property_state.key();
+    vesting_schedule.mint = ctx.accounts.mint.key();
+    vesting_schedule.beneficiary = beneficiary;
+    vesting_schedule.total_amount = total_amount;
+    vesting_schedule.claimed_amount = 0;
+    vesting_schedule.start_at = start_at;
+    vesting_schedule.cliff_at = cliff_at;
+    vesting_schedule.end_at = end_at;
+    vesting_schedule.created_at = clock.unix_timestamp;
+    vesting_schedule.bump = ctx.bumps.vesting_schedule;
+
+    emit!(VestingScheduleCreated {
+        property_mint: ctx.accounts.mint.key(),
+        vesting_schedule: vesting_schedule.key(),
+        beneficiary,
+        total_amount,
+        start_at,
+        cliff_at,
+        end_at,
+        sponsor_allocated_supply: property_state.sponsor_allocated_supply,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Reserved {} tokens for sponsor {} (cliff {}, fully vested {})",
+        total_amount,
+        beneficiary,
+        cliff_at,
+        end_at
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM VESTED TOKENS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimVestedTokens<'info> {
+    /// Sponsor claiming vested tokens
+    pub beneficiary: Signer<'info>,
+
+    /// PropertyState PDA (mint authority)
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vesting Schedule PDA
+    #[account(
+        mut,
+        seeds = [VESTING_SCHEDULE_SEED, mint.key().as_ref(), beneficiary.key().as_ref()],
+        bump = vesting_schedule.bump,
+        has_one = beneficiary @ RwaError::Unauthorized,
+    )]
+    pub vesting_schedule: Box<Account<'info, VestingSchedule>>,
+
+    /// Beneficiary's token account
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary.key() @ RwaError::Unauthorized,
+        constraint = beneficiary_token_account.mint == mint.key() @ RwaError::InvalidMint,
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Hub Credential account for beneficiary KYC
+    /// CHECK: Verified using Hub Credential program
+    pub beneficiary_credential: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Handler for claim_vested_tokens instruction
+pub fn handler_claim_vested_tokens(ctx: Context<ClaimVestedTokens>) -> Result<()> {
+    let property_state = &mut ctx.accounts.property_state;
+    require!(
+        property_state.status.allows_minting(),
+        RwaError::PropertyNotActive
+    );
+
+    // 1. Verify Hub Credential for KYC compliance
+    verify_hub_credential(
+        &ctx.accounts.beneficiary_credential.to_account_info(),
+        &ctx.accounts.beneficiary.key(),
+        &ctx.accounts.mint.key(),
+    )?;
+
+    // 2. Calculate the vested, unclaimed amount
+    let clock = Clock::get()?;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let amount = vesting_schedule.claimable_amount(clock.unix_timestamp);
+    require!(amount > 0, RwaError::NoVestedTokens);
+
+    // 3. Mint the vested tokens to the beneficiary
+    let mint_key = property_state.mint;
+    let seeds = &[
+        PROPERTY_STATE_SEED,
+        mint_key.as_ref(),
+        &[property_state.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: property_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    // 4. Update schedule and supply counters
+    vesting_schedule.claimed_amount = vesting_schedule
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    property_state.circulating_supply = property_state
+        .circulating_supply
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.sponsor_claimed_supply = property_state
+        .sponsor_claimed_supply
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+    property_state.updated_at = clock.unix_timestamp;
+
+    emit!(VestedTokensClaimed {
+        property_mint: mint_key,
+        vesting_schedule: vesting_schedule.key(),
+        beneficiary: vesting_schedule.beneficiary,
+        amount,
+        claimed_amount: vesting_schedule.claimed_amount,
+        total_amount: vesting_schedule.total_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Sponsor {} claimed {} vested tokens ({}/{})",
+        vesting_schedule.beneficiary,
+        amount,
+        vesting_schedule.claimed_amount,
+        vesting_schedule.total_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,526 @@
+/// Governance - Token-Holder Proposals and Voting
+///
+/// Holders vote on major decisions (selling the building, reserve spends,
+/// changing the manager or the governance rules themselves). Votes are
+/// weighted by the tokens the voter had staked when the proposal was
+/// created; quorum is measured against the staked supply at the same
+/// snapshot, since only staked tokens can vote. Proposers need the
+/// threshold in stake too, locked until voting ends. Passed proposals wait
+/// out a timelock before they can be executed.
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::RwaError, events::*, state::*};
+
+// ============================================================================
+// INITIALIZE GOVERNANCE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    /// Property authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = authority @ RwaError::Unauthorized,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// GovernanceConfig PDA
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceConfig::INIT_SPACE,
+        seeds = [GOVERNANCE_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub governance_config: Box<Account<'info, GovernanceConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for initialize_governance instruction
+///
+/// Afterwards the parameters can only change through an UpdateConfig proposal.
+pub fn handler_initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    params: GovernanceParams,
+) -> Result<()> {
+    GovernanceConfig::validate(&params)?;
+
+    let governance_config = &mut ctx.accounts.governance_config;
+    governance_config.property_state = ctx.accounts.property_state.key();
+    governance_config.apply(&params);
+    governance_config.proposal_count = 0;
+    governance_config.bump = ctx.bumps.governance_config;
+
+    emit!(GovernanceConfigured {
+        property_mint: ctx.accounts.mint.key(),
+        quorum_bps: params.quorum_bps,
+        approval_threshold_bps: params.approval_threshold_bps,
+        voting_period_seconds: params.voting_period_seconds,
+        execution_delay_seconds: params.execution_delay_seconds,
+        proposal_threshold: params.proposal_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Governance initialized: quorum {} bps, approval {} bps",
+        params.quorum_bps,
+        params.approval_threshold_bps
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CREATE PROPOSAL
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Proposer (property authority or holder above the threshold)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+        constraint = property_state.status.allows_transfers() @ RwaError::InvalidPropertyStatus,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// GovernanceConfig PDA
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, mint.key().as_ref()],
+        bump = governance_config.bump,
+    )]
+    pub governance_config: Box<Account<'info, GovernanceConfig>>,
+
+    /// Proposal PDA
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            PROPOSAL_SEED,
+            mint.key().as_ref(),
+            &governance_config.proposal_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// Proposer's stake position (required unless proposer is the authority)
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, mint.key().as_ref(), proposer.key().as_ref()],
+        bump = proposer_stake_position.bump,
+    )]
+    pub proposer_stake_position: Option<Box<Account<'info, StakePosition>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for create_proposal instruction
+pub fn handler_create_proposal(
+    ctx: Context<CreateProposal>,
+    action: ProposalAction,
+    description_uri: String,
+) -> Result<()> {
+    require!(
+        description_uri.len() <= MAX_PROPOSAL_URI_LEN,
+        RwaError::MetadataUriTooLong
+    );
+    match action {
+        ProposalAction::WithdrawReserve { amount, .. } => {
+            require!(amount > 0, RwaError::InvalidAmount);
+        }
+        ProposalAction::UpdateConfig { params } => {
+            GovernanceConfig::validate(&params)?;
+        }
+        _ => {}
+    }
+
+    let clock = Clock::get()?;
+    let governance_config = &mut ctx.accounts.governance_config;
+    let proposal_id = governance_config.proposal_count;
+    let voting_ends_at = clock
+        .unix_timestamp
+        .checked_add(governance_config.voting_period_seconds)
+        .ok_or(RwaError::MathOverflow)?;
+
+    // Holders need a minimum stake, held before this transaction, to
+    // propose; it stays locked until voting ends like a vote
+    let is_authority = ctx.accounts.proposer.key() == ctx.accounts.property_state.authority;
+    if !is_authority {
+        let stake_position = ctx
+            .accounts
+            .proposer_stake_position
+            .as_mut()
+            .ok_or(RwaError::ProposalThresholdNotMet)?;
+        require!(
+            stake_position.voting_weight(clock.unix_timestamp)
+                >= governance_config.proposal_threshold,
+            RwaError::ProposalThresholdNotMet
+        );
+        stake_position.votes_locked_until = stake_position.votes_locked_until.max(voting_ends_at);
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.property_state = ctx.accounts.property_state.key();
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.description_uri = description_uri;
+    proposal.snapshot_supply = ctx.accounts.property_state.staked_supply;
+    proposal.quorum_bps = governance_config.quorum_bps;
+    proposal.approval_threshold_bps = governance_config.approval_threshold_bps;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.voting_starts_at = clock.unix_timestamp;
+    proposal.voting_ends_at = voting_ends_at;
+    proposal.executable_at = proposal
+        .voting_ends_at
+        .checked_add(governance_config.execution_delay_seconds)
+        .ok_or(RwaError::MathOverflow)?;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance_config.proposal_count = proposal_id
+        .checked_add(1)
+        .ok_or(RwaError::MathOverflow)?;
+
+    emit!(ProposalCreated {
+        property_mint: ctx.accounts.mint.key(),
+        proposal: proposal.key(),
+        proposal_id,
+        proposer: proposal.proposer,
+        action,
+        snapshot_supply: proposal.snapshot_supply,
+        voting_ends_at: proposal.voting_ends_at,
+        executable_at: proposal.executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Proposal {} created by {} - voting ends at {}",
+        proposal_id,
+        proposal.proposer,
+        proposal.voting_ends_at
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// CAST VOTE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    /// Token holder voting
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Proposal being voted on
+    #[account(
+        mut,
+        constraint = proposal.property_state == property_state.key() @ RwaError::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// Vote Record PDA - one vote per wallet per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [VOTE_RECORD_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+
+    /// Voter's stake position - the stake held at the snapshot is the weight
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, mint.key().as_ref(), voter.key().as_ref()],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for cast_vote instruction
+///
+/// Wallet balances have no history on-chain, so only tokens staked before
+/// the proposal was created vote. The stake cannot be withdrawn before the
+/// vote closes.
+pub fn handler_cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.is_voting_open(clock.unix_timestamp),
+        RwaError::VotingClosed
+    );
+
+    // 1. Weight by the stake at the snapshot and lock it until voting ends
+    let stake_position = &mut ctx.accounts.stake_position;
+    let weight = stake_position.voting_weight(proposal.voting_starts_at);
+    require!(weight > 0, RwaError::NoVotingWeight);
+    stake_position.votes_locked_until = stake_position
+        .votes_locked_until
+        .max(proposal.voting_ends_at);
+
+    // 2. Tally the vote
+    if support {
+        proposal.votes_for = proposal
+            .votes_for
+            .checked_add(weight)
+            .ok_or(RwaError::MathOverflow)?;
+    } else {
+        proposal.votes_against = proposal
+            .votes_against
+            .checked_add(weight)
+            .ok_or(RwaError::MathOverflow)?;
+    }
+
+    // 3. Record the vote
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.support = support;
+    vote_record.weight = weight;
+    vote_record.voted_at = clock.unix_timestamp;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit!(VoteCast {
+        property_mint: ctx.accounts.mint.key(),
+        proposal: proposal.key(),
+        voter: vote_record.voter,
+        support,
+        weight,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vote cast on proposal {}: {} with weight {}",
+        proposal.proposal_id,
+        if support { "FOR" } else { "AGAINST" },
+        weight
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// EXECUTE PROPOSAL
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// Anyone can execute a passed proposal
+    pub executor: Signer<'info>,
+
+    /// PropertyState PDA
+    #[account(
+        mut,
+        seeds = [PROPERTY_STATE_SEED, mint.key().as_ref()],
+        bump = property_state.bump,
+        has_one = mint @ RwaError::InvalidMint,
+    )]
+    pub property_state: Box<Account<'info, PropertyState>>,
+
+    /// The property token mint (Token-2022)
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Proposal being executed
+    #[account(
+        mut,
+        constraint = proposal.property_state == property_state.key() @ RwaError::InvalidProposal,
+        constraint = !proposal.executed @ RwaError::ProposalAlreadyExecuted,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// GovernanceConfig PDA (required for UpdateConfig)
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, mint.key().as_ref()],
+        bump = governance_config.bump,
+    )]
+    pub governance_config: Option<Box<Account<'info, GovernanceConfig>>>,
+
+    /// Investment Vault PDA (required for WithdrawReserve)
+    #[account(
+        mut,
+        seeds = [INVESTMENT_VAULT_SEED, mint.key().as_ref()],
+        bump = investment_vault.bump,
+    )]
+    pub investment_vault: Option<Box<Account<'info, InvestmentVault>>>,
+
+    /// Reserve Fund PDA (source for WithdrawReserve)
+    #[account(
+        mut,
+        seeds = [RESERVE_FUND_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub reserve_fund: SystemAccount<'info>,
+
+    /// Recipient of a reserve withdrawal
+    /// CHECK: Verified against the proposal action
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handler for execute_proposal instruction
+pub fn handler_execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        clock.unix_timestamp >= proposal.voting_ends_at,
+        RwaError::VotingNotEnded
+    );
+    require!(proposal.has_passed(), RwaError::ProposalNotPassed);
+    require!(
+        clock.unix_timestamp >= proposal.executable_at,
+        RwaError::ProposalTimelocked
+    );
+
+    let mint_key = ctx.accounts.mint.key();
+
+    match proposal.action {
+        ProposalAction::Signal => {
+            msg!("Signal proposal {} approved", proposal.proposal_id);
+        }
+        ProposalAction::TransferAuthority { new_authority } => {
+            let property_state = &mut ctx.accounts.property_state;
+            let old_authority = property_state.authority;
+            property_state.authority = new_authority;
+            property_state.updated_at = clock.unix_timestamp;
+
+            msg!(
+                "Property authority changed: {} -> {}",
+                old_authority,
+                new_authority
+            );
+        }
+        ProposalAction::WithdrawReserve { amount, recipient } => {
+            let recipient_account = ctx
+                .accounts
+                .recipient
+                .as_ref()
+                .ok_or(RwaError::InvalidProposalAccounts)?;
+            require_keys_eq!(
+                recipient_account.key(),
+                recipient,
+                RwaError::InvalidProposalAccounts
+            );
+            let investment_vault = ctx
+                .accounts
+                .investment_vault
+                .as_mut()
+                .ok_or(RwaError::InvalidProposalAccounts)?;
+            require!(
+                investment_vault.reserve_balance >= amount
+                    && ctx.accounts.reserve_fund.lamports() >= amount,
+                RwaError::InsufficientReserve
+            );
+
+            let reserve_bump = ctx.bumps.reserve_fund;
+            let seeds = &[RESERVE_FUND_SEED, mint_key.as_ref(), &[reserve_bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.reserve_fund.to_account_info(),
+                        to: recipient_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+
+            investment_vault.reserve_balance = investment_vault
+                .reserve_balance
+                .checked_sub(amount)
+                .ok_or(RwaError::MathOverflow)?;
+            investment_vault.updated_at = clock.unix_timestamp;
+
+            msg!("Withdrew {} lamports from reserve to {}", amount, recipient);
+        }
+        ProposalAction::UpdateConfig { params } => {
+            let governance_config = ctx
+                .accounts
+                .governance_config
+                .as_mut()
+                .ok_or(RwaError::InvalidProposalAccounts)?;
+            GovernanceConfig::validate(&params)?;
+            governance_config.apply(&params);
+
+            emit!(GovernanceConfigured {
+                property_mint: mint_key,
+                quorum_bps: params.quorum_bps,
+                approval_threshold_bps: params.approval_threshold_bps,
+                voting_period_seconds: params.voting_period_seconds,
+                execution_delay_seconds: params.execution_delay_seconds,
+                proposal_threshold: params.proposal_threshold,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!(
+                "Governance updated: quorum {} bps, approval {} bps",
+                params.quorum_bps,
+                params.approval_threshold_bps
+            );
+        }
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+
+    emit!(ProposalExecuted {
+        property_mint: mint_key,
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        action: proposal.action,
+        executor: ctx.accounts.executor.key(),
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
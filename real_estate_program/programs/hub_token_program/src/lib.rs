@@ -58,6 +58,8 @@ pub mod hub_token_program {
     /// * `total_supply` - Maximum tokens that can be minted
     /// * `seller_wallet` - Wallet that receives 90% of investments
     /// * `property_details` - Metadata about the property
+    /// * `transfer_fee` - Optional secondary-trade fee (TransferFeeConfig extension)
+    #[allow(clippy::too_many_arguments)]
     pub fn create_property_mint(
         ctx: Context<CreatePropertyMint>,
         property_name: String,
@@ -66,6 +68,7 @@ pub mod hub_token_program {
         total_supply: u64,
         seller_wallet: Pubkey,
         property_details: PropertyDetails,
+        transfer_fee: Option<TransferFeeParams>,
     ) -> Result<()> {
         instructions::create_property_mint::handler(
             ctx,
@@ -75,6 +78,7 @@ pub mod hub_token_program {
             total_supply,
             seller_wallet,
             property_details,
+            transfer_fee,
         )
     }
 
@@ -89,7 +93,7 @@ pub mod hub_token_program {
     /// # Security
     /// - Verifies SAS attestation exists and is valid
     /// - Checks attestation has not expired
-    /// - Ensures property is active
+    /// - Ensures property status allows minting
     /// - Validates total supply limit
     pub fn mint_property_tokens(
         ctx: Context<MintPropertyTokens>,
@@ -124,16 +128,34 @@ pub mod hub_token_program {
         instructions::update_property_details::handler(ctx, new_details)
     }
 
-    /// Toggle property active status
+    /// Toggle property pause
     ///
-    /// Enables or disables token minting for this property.
-    /// Only the property authority can toggle status.
+    /// Pauses an active property, or resumes a paused one to the status
+    /// it was paused from. Only the property authority can toggle status.
     pub fn toggle_property_status(
         ctx: Context<TogglePropertyStatus>,
     ) -> Result<()> {
         instructions::toggle_property_status::handler(ctx)
     }
 
+    /// Move a property to a new lifecycle status
+    ///
+    /// Properties are created as Draft and must be moved to Fundraising
+    /// before accepting investments.
+    ///
+    /// # Arguments
+    /// * `new_status` - Target status (see `PropertyStatus` for allowed transitions)
+    ///
+    /// # Access Control
+    /// - Only property authority can change status
+    /// - Liquidating/Closed are reached via the liquidation instructions
+    pub fn set_property_status(
+        ctx: Context<SetPropertyStatus>,
+        new_status: PropertyStatus,
+    ) -> Result<()> {
+        instructions::set_property_status::handler(ctx, new_status)
+    }
+
     /// Initialize the ExtraAccountMetaList for Transfer Hook
     ///
     /// This must be called after creating a mint with the TransferHook extension.
@@ -147,7 +169,8 @@ pub mod hub_token_program {
 
     /// Transfer Hook Execute - KYC verification on transfers
     ///
-    /// This instruction is automatically called by Token-2022 during every transfer.
+    /// This instruction is automatically called by Token-2022 during every transfer
+    /// (it answers to the SPL Transfer Hook interface `Execute` discriminator).
     /// It verifies that the destination wallet has valid KYC (SAS attestation).
     ///
     /// # Security
@@ -155,8 +178,11 @@ pub mod hub_token_program {
     /// - Checks attestation has not expired
     /// - Ensures destination is not a sanctioned wallet
     /// - Blocks transfers to non-compliant wallets
-    pub fn transfer_hook_execute(
-        ctx: Context<TransferHook>,
+    /// - Blocks transfers while paused by the guardian (property or platform-wide)
+    /// - Any one of the destination's Hub Credential types is accepted
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook_execute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TransferHook<'info>>,
         amount: u64,
     ) -> Result<()> {
         instructions::transfer_hook::handler(ctx, amount)
@@ -177,7 +203,7 @@ pub mod hub_token_program {
     ///
     /// # Access Control
     /// - Only property authority can deposit revenue
-    /// - Property must be active
+    /// - Property must be Funded or Operating
     /// - Must have token holders (circulating supply > 0)
     pub fn deposit_revenue(
         ctx: Context<DepositRevenue>,
@@ -230,17 +256,818 @@ pub mod hub_token_program {
     ///
     /// # Arguments
     /// * `sol_amount` - Amount of SOL to invest (in lamports)
-    /// * `expected_tokens` - Expected tokens to receive (slippage protection)
+    /// * `expected_tokens` - Expected tokens to receive (minimum when priced by a funding round)
+    /// * `allowlist_proof` - Merkle proof for allow-listed funding rounds (empty otherwise)
     ///
     /// # Security
     /// - Atomic transaction: payment + mint happen together
     /// - KYC verification required
+    /// - Additional investor credentials may be passed as remaining accounts
     /// - Slippage protection via expected_tokens
-    pub fn invest_in_property(
-        ctx: Context<InvestInProperty>,
+    pub fn invest_in_property<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InvestInProperty<'info>>,
         sol_amount: u64,
         expected_tokens: u64,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::invest_in_property::handler(ctx, sol_amount, expected_tokens, allowlist_proof)
+    }
+
+    // ========================================================================
+    // LIQUIDATION - Property Sale and Token Retirement
+    // ========================================================================
+
+    /// Liquidate a property after it has been sold
+    ///
+    /// Deposits the sale proceeds, sweeps the reserve fund into the final
+    /// distribution and puts the property into a terminal liquidating state.
+    ///
+    /// # Arguments
+    /// * `sale_proceeds` - Amount of SOL (in lamports) from the sale
+    ///
+    /// # Access Control
+    /// - Only property authority can liquidate
+    /// - Must have token holders (circulating supply > 0)
+    /// - Blocks minting, revenue deposits and transfers afterwards
+    pub fn liquidate_property(
+        ctx: Context<LiquidateProperty>,
+        sale_proceeds: u64,
+    ) -> Result<()> {
+        instructions::liquidate_property::handler_liquidate_property(ctx, sale_proceeds)
+    }
+
+    /// Redeem property tokens for a share of the sale proceeds
+    ///
+    /// Burns the investor's tokens and pays out their pro-rata share.
+    ///
+    /// # Calculation
+    /// `payout = (amount / circulating_supply) * remaining_proceeds`
+    ///
+    /// # Arguments
+    /// * `amount` - Number of tokens to redeem
+    pub fn redeem_liquidation(ctx: Context<RedeemLiquidation>, amount: u64) -> Result<()> {
+        instructions::liquidate_property::handler_redeem_liquidation(ctx, amount)
+    }
+
+    /// Close a fully liquidated property
+    ///
+    /// Closes the PropertyState, liquidation and investment vault accounts
+    /// once every token has been redeemed, or once the claim period has ended.
+    /// Rent and any unclaimed proceeds are returned to the authority.
+    pub fn close_liquidated_property(ctx: Context<CloseLiquidatedProperty>) -> Result<()> {
+        instructions::liquidate_property::handler_close_liquidated_property(ctx)
+    }
+
+    // ========================================================================
+    // REDEMPTION WINDOW - Reserve-Funded Buyback
+    // ========================================================================
+
+    /// Open a redemption window funded by the reserve fund
+    ///
+    /// Moves `budget` lamports from the reserve fund into a window PDA.
+    /// Investors can then redeem tokens at `price_per_token` until the
+    /// budget, the token cap or the window time runs out.
+    ///
+    /// # Arguments
+    /// * `price_per_token` - Lamports paid per whole token
+    /// * `budget` - Lamports drawn from the reserve fund
+    /// * `max_tokens` - Maximum tokens (base units) bought back
+    /// * `closes_at` - Unix timestamp when the window closes
+    ///
+    /// # Access Control
+    /// - Only property authority can open a window
+    /// - One window per property at a time
+    pub fn open_redemption_window(
+        ctx: Context<OpenRedemptionWindow>,
+        price_per_token: u64,
+        budget: u64,
+        max_tokens: u64,
+        closes_at: i64,
+    ) -> Result<()> {
+        instructions::redemption::handler_open_redemption_window(
+            ctx,
+            price_per_token,
+            budget,
+            max_tokens,
+            closes_at,
+        )
+    }
+
+    /// Redeem tokens during an open redemption window
+    ///
+    /// Burns the investor's tokens and pays them from the window budget,
+    /// first-come first-served.
+    ///
+    /// # Arguments
+    /// * `amount` - Number of tokens to redeem
+    pub fn redeem_tokens(ctx: Context<RedeemTokens>, amount: u64) -> Result<()> {
+        instructions::redemption::handler_redeem_tokens(ctx, amount)
+    }
+
+    /// Close the redemption window
+    ///
+    /// Returns any unspent budget to the reserve fund.
+    ///
+    /// # Access Control
+    /// - Only property authority can close the window
+    pub fn close_redemption_window(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+        instructions::redemption::handler_close_redemption_window(ctx)
+    }
+
+    // ========================================================================
+    // EMERGENCY PAUSE - Guardian Circuit Breakers
+    // ========================================================================
+
+    /// Initialize the platform config
+    ///
+    /// # Arguments
+    /// * `guardian` - Key allowed to pause transfers
+    ///
+    /// # Access Control
+    /// - Only the bootstrap platform admin key can initialize
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::emergency_pause::handler_initialize_platform_config(ctx, guardian)
+    }
+
+    /// Appoint a new platform guardian
+    ///
+    /// # Access Control
+    /// - Only the platform admin can change the guardian
+    pub fn update_platform_guardian(
+        ctx: Context<UpdatePlatformGuardian>,
+        new_guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::emergency_pause::handler_update_platform_guardian(ctx, new_guardian)
+    }
+
+    /// Hand the platform admin role to a new key
+    ///
+    /// # Access Control
+    /// - Only the current platform admin
+    pub fn update_platform_admin(
+        ctx: Context<UpdatePlatformAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::emergency_pause::handler_update_platform_admin(ctx, new_admin)
+    }
+
+    /// Pause or resume secondary transfers of a single property
+    ///
+    /// Does not affect minting or investments.
+    ///
+    /// # Access Control
+    /// - Only the platform guardian (not the property authority)
+    pub fn set_property_transfer_pause(
+        ctx: Context<SetPropertyTransferPause>,
+        paused: bool,
     ) -> Result<()> {
-        instructions::invest_in_property::handler(ctx, sol_amount, expected_tokens)
+        instructions::emergency_pause::handler_set_property_transfer_pause(ctx, paused)
+    }
+
+    /// Pause or resume secondary transfers of every property
+    ///
+    /// # Access Control
+    /// - Only the platform guardian
+    pub fn set_global_transfer_pause(
+        ctx: Context<SetGlobalTransferPause>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::emergency_pause::handler_set_global_transfer_pause(ctx, paused)
+    }
+
+    // ========================================================================
+    // GOVERNANCE - Token-Holder Voting
+    // ========================================================================
+
+    /// Enable governance for a property
+    ///
+    /// # Arguments
+    /// * `params` - Quorum, approval threshold, voting period, execution
+    ///   timelock and proposal threshold
+    ///
+    /// # Access Control
+    /// - Only property authority can initialize governance
+    /// - Later changes require a passed UpdateConfig proposal
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        params: GovernanceParams,
+    ) -> Result<()> {
+        instructions::voting::handler_initialize_governance(ctx, params)
+    }
+
+    /// Create a governance proposal
+    ///
+    /// # Arguments
+    /// * `action` - Action executed if the proposal passes
+    /// * `description_uri` - URI with the full proposal text
+    ///
+    /// # Access Control
+    /// - Property authority, or a holder with at least `proposal_threshold`
+    ///   tokens staked before the proposal (locked until voting ends)
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        description_uri: String,
+    ) -> Result<()> {
+        instructions::voting::handler_create_proposal(ctx, action, description_uri)
+    }
+
+    /// Vote on a proposal with the stake held at its snapshot
+    ///
+    /// # Arguments
+    /// * `support` - Vote for (true) or against (false)
+    pub fn cast_vote(ctx: Context<CastVote>, support: bool) -> Result<()> {
+        instructions::voting::handler_cast_vote(ctx, support)
+    }
+
+    /// Execute a passed proposal
+    ///
+    /// # Access Control
+    /// - Anyone, once voting has ended with quorum and approval reached
+    ///   and the execution timelock has passed
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::voting::handler_execute_proposal(ctx)
+    }
+
+    // ========================================================================
+    // ORDER BOOK - Secondary Market
+    // ========================================================================
+
+    /// Place a limit order to sell tokens (tokens are escrowed)
+    ///
+    /// # Arguments
+    /// * `order_id` - Maker-chosen id, unique per owner and mint
+    /// * `price_per_token` - Limit price per whole token (in lamports)
+    /// * `amount` - Tokens to sell
+    pub fn create_sell_order(
+        ctx: Context<CreateSellOrder>,
+        order_id: u64,
+        price_per_token: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::order_book::handler_create_sell_order(ctx, order_id, price_per_token, amount)
+    }
+
+    /// Place a limit order to buy tokens (lamports are deposited)
+    ///
+    /// # Arguments
+    /// * `order_id` - Maker-chosen id, unique per owner and mint
+    /// * `price_per_token` - Limit price per whole token (in lamports)
+    /// * `amount` - Tokens to buy
+    pub fn create_buy_order(
+        ctx: Context<CreateBuyOrder>,
+        order_id: u64,
+        price_per_token: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::order_book::handler_create_buy_order(ctx, order_id, price_per_token, amount)
+    }
+
+    /// Cancel an order and return the unfilled tokens or lamports
+    ///
+    /// # Access Control
+    /// - Only the order owner can cancel
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        instructions::order_book::handler_cancel_order(ctx)
+    }
+
+    /// Fill a resting order at its limit price
+    ///
+    /// # Arguments
+    /// * `amount` - Tokens to fill (partial fills allowed)
+    pub fn fill_order(ctx: Context<FillOrder>, amount: u64) -> Result<()> {
+        instructions::order_book::handler_fill_order(ctx, amount)
+    }
+
+    // ========================================================================
+    // AMM - Constant-Product Liquidity Pool
+    // ========================================================================
+
+    /// Create the liquidity pool, LP mint and token vault for a property
+    ///
+    /// The platform fee starts at `DEFAULT_POOL_PLATFORM_FEE_BPS`.
+    ///
+    /// # Arguments
+    /// * `lp_fee_bps` - Swap fee kept by liquidity providers
+    ///
+    /// # Access Control
+    /// - Only property authority can create the pool
+    pub fn initialize_pool(ctx: Context<InitializePool>, lp_fee_bps: u16) -> Result<()> {
+        instructions::amm::handler_initialize_pool(ctx, lp_fee_bps)
+    }
+
+    /// Update the pool's LP fee
+    ///
+    /// # Access Control
+    /// - Only property authority can update the LP fee
+    pub fn update_pool_fees(ctx: Context<UpdatePoolFees>, lp_fee_bps: u16) -> Result<()> {
+        instructions::amm::handler_update_pool_fees(ctx, lp_fee_bps)
+    }
+
+    /// Update the pool's platform fee (charged in SOL, sent to the treasury)
+    ///
+    /// # Access Control
+    /// - Only the platform admin (PlatformConfig) can update the platform fee
+    pub fn update_pool_platform_fee(
+        ctx: Context<UpdatePoolPlatformFee>,
+        platform_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::amm::handler_update_pool_platform_fee(ctx, platform_fee_bps)
+    }
+
+    /// Deposit tokens and SOL at the pool ratio for LP tokens
+    ///
+    /// # Arguments
+    /// * `token_amount` - Property tokens to deposit
+    /// * `max_sol_amount` - Maximum SOL to deposit (sets the price on first deposit)
+    /// * `min_lp_out` - Minimum LP tokens to receive
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        token_amount: u64,
+        max_sol_amount: u64,
+        min_lp_out: u64,
+    ) -> Result<()> {
+        instructions::amm::handler_add_liquidity(ctx, token_amount, max_sol_amount, min_lp_out)
+    }
+
+    /// Burn LP tokens for a pro-rata share of both reserves
+    ///
+    /// Allowed while transfers are paused and during liquidation.
+    ///
+    /// # Arguments
+    /// * `lp_amount` - LP tokens to burn
+    /// * `min_token_out` - Minimum property tokens to receive
+    /// * `min_sol_out` - Minimum lamports to receive
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_amount: u64,
+        min_token_out: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        instructions::amm::handler_remove_liquidity(ctx, lp_amount, min_token_out, min_sol_out)
+    }
+
+    /// Swap SOL for property tokens or property tokens for SOL
+    ///
+    /// The token leg goes through the transfer hook as the trader's own
+    /// `TransferChecked` in the same transaction: right before the swap when
+    /// selling tokens, right after it (as the approved delegate of the pool
+    /// vault) when buying.
+    ///
+    /// # Arguments
+    /// * `direction` - Swap direction
+    /// * `amount_in` - Amount paid (lamports or token base units)
+    /// * `min_amount_out` - Minimum amount received after fees
+    pub fn swap(
+        ctx: Context<Swap>,
+        direction: SwapDirection,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        instructions::amm::handler_swap(ctx, direction, amount_in, min_amount_out)
+    }
+
+    // ========================================================================
+    // TRANSFER FEES - Secondary-Trade Royalty
+    // ========================================================================
+
+    /// Harvest transfer fees: the platform share goes to the treasury and
+    /// the retired share is burned out of the supply
+    ///
+    /// Pass the token accounts to sweep as remaining accounts; fees already
+    /// harvested to the mint and order-book royalties are always included.
+    ///
+    /// # Access Control
+    /// - Property authority or platform admin
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        instructions::transfer_fees::handler_harvest_transfer_fees(ctx)
+    }
+
+    /// Update the transfer fee and its retired share
+    ///
+    /// The fee is capped at MAX_TRANSFER_FEE_BPS; Token-2022 applies a new
+    /// rate two epochs later.
+    ///
+    /// # Access Control
+    /// - Only property authority (the PropertyState PDA signs for the mint)
+    pub fn set_transfer_fee(ctx: Context<SetTransferFee>, params: TransferFeeParams) -> Result<()> {
+        instructions::transfer_fees::handler_set_transfer_fee(ctx, params)
+    }
+
+    // ========================================================================
+    // FUNDING ROUNDS - Tiered Presale Pricing
+    // ========================================================================
+
+    /// Create the next funding round for a property
+    ///
+    /// Once a round exists, `invest_in_property` must be called with the
+    /// active round and sells at its price. Rounds cannot overlap: a round
+    /// starts no earlier than the previous one ends.
+    ///
+    /// # Arguments
+    /// * `price_per_token` - Price per whole token (in lamports)
+    /// * `token_cap` - Maximum tokens sold in the round
+    /// * `starts_at` / `ends_at` - Round window (unix timestamps)
+    /// * `allowlist_root` - Optional Merkle root of eligible wallets
+    /// * `required_credential_type` - Optional Hub Credential type required
+    ///
+    /// # Access Control
+    /// - Only property authority can create rounds
+    pub fn create_funding_round(
+        ctx: Context<CreateFundingRound>,
+        price_per_token: u64,
+        token_cap: u64,
+        starts_at: i64,
+        ends_at: i64,
+        allowlist_root: Option<[u8; 32]>,
+        required_credential_type: Option<u8>,
+    ) -> Result<()> {
+        instructions::funding_rounds::handler(
+            ctx,
+            price_per_token,
+            token_cap,
+            starts_at,
+            ends_at,
+            allowlist_root,
+            required_credential_type,
+        )
+    }
+
+    // ========================================================================
+    // INVESTMENT LIMITS - Per-Investor Caps by Credential Tier
+    // ========================================================================
+
+    /// Set the minimum ticket and per-credential-type investment caps
+    ///
+    /// # Arguments
+    /// * `min_investment` - Minimum investment per transaction (lamports)
+    /// * `max_investment_by_type` - Cap per period indexed by HubCredentialType (0 = uncapped)
+    /// * `limit_period_seconds` - Cap period (0 = lifetime)
+    ///
+    /// # Access Control
+    /// - Only property authority can set limits
+    pub fn set_investment_limits(
+        ctx: Context<SetInvestmentLimits>,
+        min_investment: u64,
+        max_investment_by_type: [u64; HUB_CREDENTIAL_TYPE_COUNT],
+        limit_period_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_investment_limits::handler(
+            ctx,
+            min_investment,
+            max_investment_by_type,
+            limit_period_seconds,
+        )
+    }
+
+    /// Restrict investors by the compliance attributes of their Hub Credential
+    ///
+    /// # Arguments
+    /// * `allowed_countries` - Accepted ISO 3166-1 alpha-2 countries of residence (empty = any)
+    /// * `blocked_countries` - Rejected countries of residence or tax residency
+    /// * `min_investor_tier` - Minimum investor classification
+    /// * `max_risk_band` - Highest accepted AML risk band
+    /// * `allow_pep` - Whether politically exposed persons may invest
+    ///
+    /// # Access Control
+    /// - Only property authority can set rules
+    pub fn set_compliance_rules(
+        ctx: Context<SetComplianceRules>,
+        allowed_countries: Vec<[u8; 2]>,
+        blocked_countries: Vec<[u8; 2]>,
+        min_investor_tier: InvestorTier,
+        max_risk_band: RiskBand,
+        allow_pep: bool,
+    ) -> Result<()> {
+        instructions::set_compliance_rules::handler(
+            ctx,
+            allowed_countries,
+            blocked_countries,
+            min_investor_tier,
+            max_risk_band,
+            allow_pep,
+        )
+    }
+
+    // ========================================================================
+    // DISTRIBUTORS - Referral Commissions
+    // ========================================================================
+
+    /// Register a partner broker
+    ///
+    /// # Arguments
+    /// * `wallet` - Distributor wallet
+    /// * `commission_bps` - Share of the platform fee paid as commission
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn register_distributor(
+        ctx: Context<RegisterDistributor>,
+        wallet: Pubkey,
+        commission_bps: u16,
+    ) -> Result<()> {
+        instructions::distributors::handler_register_distributor(ctx, wallet, commission_bps)
+    }
+
+    /// Update a distributor's commission rate or active flag
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn update_distributor(
+        ctx: Context<UpdateDistributor>,
+        commission_bps: u16,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::distributors::handler_update_distributor(ctx, commission_bps, is_active)
+    }
+
+    /// Withdraw accrued commissions
+    ///
+    /// # Access Control
+    /// - Only the distributor wallet
+    pub fn withdraw_distributor_commission(
+        ctx: Context<WithdrawDistributorCommission>,
+    ) -> Result<()> {
+        instructions::distributors::handler_withdraw_distributor_commission(ctx)
+    }
+
+    // ========================================================================
+    // VESTING - Sponsor Allocations
+    // ========================================================================
+
+    /// Reserve part of the total supply for a sponsor with cliff and linear vesting
+    ///
+    /// # Arguments
+    /// * `beneficiary` - Sponsor wallet
+    /// * `total_amount` - Tokens reserved (base units)
+    /// * `start_at` - Linear vesting start
+    /// * `cliff_at` - Nothing claimable before this time
+    /// * `end_at` - Fully vested from this time
+    ///
+    /// # Access Control
+    /// - Only the property authority, while the property is in Draft
+    pub fn create_vesting_schedule(
+        ctx: Context<CreateVestingSchedule>,
+        beneficiary: Pubkey,
+        total_amount: u64,
+        start_at: i64,
+        cliff_at: i64,
+        end_at: i64,
+    ) -> Result<()> {
+        instructions::vesting::handler_create_vesting_schedule(
+            ctx,
+            beneficiary,
+            total_amount,
+            start_at,
+            cliff_at,
+            end_at,
+        )
+    }
+
+    /// Mint vested sponsor tokens to the beneficiary
+    ///
+    /// # Access Control
+    /// - Only the beneficiary, with a valid Hub Credential
+    pub fn claim_vested_tokens(ctx: Context<ClaimVestedTokens>) -> Result<()> {
+        instructions::vesting::handler_claim_vested_tokens(ctx)
+    }
+
+    // ========================================================================
+    // STAKING - Boosted Revenue Share
+    // ========================================================================
+
+    /// Lock property tokens for a boosted share of rental revenue
+    ///
+    /// # Arguments
+    /// * `amount` - Tokens to stake
+    /// * `lock_duration` - Lock in seconds (longer locks earn a larger multiplier)
+    ///
+    /// # Access Control
+    /// - Any token holder
+    pub fn stake_tokens(
+        ctx: Context<StakeTokens>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        instructions::staking::handler_stake_tokens(ctx, amount, lock_duration)
+    }
+
+    /// Unlock a stake once its lock and any open votes have expired
+    ///
+    /// # Access Control
+    /// - Only the staker, with a valid Hub Credential
+    pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
+        instructions::staking::handler_unstake_tokens(ctx)
+    }
+
+    // ========================================================================
+    // BUNDLES - Multi-Property Index Tokens
+    // ========================================================================
+
+    /// Create a bundle mint backed by a basket of property tokens
+    ///
+    /// # Arguments
+    /// * `components` - Property mints and tokens per bundle token
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn create_bundle(
+        ctx: Context<CreateBundle>,
+        components: Vec<BundleComponent>,
+    ) -> Result<()> {
+        instructions::bundles::handler_create_bundle(ctx, components)
+    }
+
+    /// Deposit the basket and receive bundle tokens
+    ///
+    /// # Arguments
+    /// * `units` - Bundle tokens to mint
+    ///
+    /// # Access Control
+    /// - Any holder with a valid Hub Credential
+    pub fn mint_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintBundle<'info>>,
+        units: u64,
+    ) -> Result<()> {
+        instructions::bundles::handler_mint_bundle(ctx, units)
+    }
+
+    /// Burn bundle tokens and receive the basket back
+    ///
+    /// # Arguments
+    /// * `units` - Bundle tokens to redeem
+    ///
+    /// # Access Control
+    /// - Only the bundle holder, with a valid Hub Credential
+    /// - Allowed while the properties are paused or liquidating
+    pub fn redeem_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemBundle<'info>>,
+        units: u64,
+    ) -> Result<()> {
+        instructions::bundles::handler_redeem_bundle(ctx, units)
+    }
+
+    /// Collect a bundle's share of an underlying revenue epoch
+    ///
+    /// # Access Control
+    /// - Anyone (permissionless crank)
+    pub fn collect_bundle_revenue(ctx: Context<CollectBundleRevenue>) -> Result<()> {
+        instructions::bundles::handler_collect_bundle_revenue(ctx)
+    }
+
+    /// Claim collected bundle revenue
+    ///
+    /// # Access Control
+    /// - Only the bundle holder
+    pub fn claim_bundle_revenue(ctx: Context<ClaimBundleRevenue>) -> Result<()> {
+        instructions::bundles::handler_claim_bundle_revenue(ctx)
+    }
+
+    // ========================================================================
+    // LENDING - Borrow Against Property Tokens
+    // ========================================================================
+
+    /// Create a stablecoin lending market for a property
+    ///
+    /// # Arguments
+    /// * `max_ltv_bps` - Maximum loan-to-value when borrowing
+    /// * `liquidation_threshold_bps` - LTV above which positions can be liquidated
+    /// * `liquidation_bonus_bps` - Extra collateral paid to liquidators
+    /// * `interest_rate_bps` - Simple annual interest rate
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn initialize_lending_market(
+        ctx: Context<InitializeLendingMarket>,
+        max_ltv_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        interest_rate_bps: u16,
+    ) -> Result<()> {
+        instructions::lending::handler_initialize_lending_market(
+            ctx,
+            max_ltv_bps,
+            liquidation_threshold_bps,
+            liquidation_bonus_bps,
+            interest_rate_bps,
+        )
+    }
+
+    /// Update lending market risk parameters
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn update_lending_market(
+        ctx: Context<UpdateLendingMarket>,
+        max_ltv_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        interest_rate_bps: u16,
+    ) -> Result<()> {
+        instructions::lending::handler_update_lending_market(
+            ctx,
+            max_ltv_bps,
+            liquidation_threshold_bps,
+            liquidation_bonus_bps,
+            interest_rate_bps,
+        )
+    }
+
+    /// Move a lending market's collateral valuation (limited per update)
+    ///
+    /// # Arguments
+    /// * `valuation_usd` - Property valuation in USD cents
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn update_lending_valuation(
+        ctx: Context<UpdateLendingValuation>,
+        valuation_usd: u64,
+    ) -> Result<()> {
+        instructions::lending::handler_update_lending_valuation(ctx, valuation_usd)
+    }
+
+    /// Withdraw idle stablecoin liquidity from a lending market
+    ///
+    /// # Access Control
+    /// - Only the platform admin
+    pub fn withdraw_lending_liquidity(
+        ctx: Context<WithdrawLendingLiquidity>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::lending::handler_withdraw_lending_liquidity(ctx, amount)
+    }
+
+    /// Deposit property tokens as collateral
+    ///
+    /// # Access Control
+    /// - Any token holder
+    pub fn deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+        instructions::lending::handler_deposit_collateral(ctx, amount)
+    }
+
+    /// Withdraw collateral not needed to cover the debt
+    ///
+    /// # Access Control
+    /// - Only the borrower, with a valid Hub Credential
+    /// - Allowed while the property is paused or liquidating
+    pub fn withdraw_collateral(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+        instructions::lending::handler_withdraw_collateral(ctx, amount)
+    }
+
+    /// Borrow stablecoin up to the maximum LTV
+    ///
+    /// # Access Control
+    /// - Only the borrower
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        instructions::lending::handler_borrow(ctx, amount)
+    }
+
+    /// Repay stablecoin debt
+    ///
+    /// # Access Control
+    /// - Only the borrower
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        instructions::lending::handler_repay(ctx, amount)
+    }
+
+    /// Liquidate a position past the liquidation threshold
+    ///
+    /// # Arguments
+    /// * `repay_amount` - Debt to repay (capped at the outstanding debt)
+    ///
+    /// # Access Control
+    /// - Anyone with a valid Hub Credential
+    /// - Allowed while the property is paused or liquidating
+    pub fn liquidate_loan(ctx: Context<LiquidateLoan>, repay_amount: u64) -> Result<()> {
+        instructions::lending::handler_liquidate_loan(ctx, repay_amount)
+    }
+
+    // ========================================================================
+    // MIGRATIONS - Upgrade accounts from earlier program versions
+    // ========================================================================
+
+    /// Grow a property's PropertyState and transfer hook meta list
+    ///
+    /// Appended PropertyState fields start zeroed; the meta list is rewritten
+    /// with every entry the transfer hook requires.
+    ///
+    /// # Access Control
+    /// - Anyone (the payer funds the extra rent)
+    pub fn migrate_property_accounts(ctx: Context<MigratePropertyAccounts>) -> Result<()> {
+        instructions::migrate_accounts::handler_migrate_property_accounts(ctx)
+    }
+
+    /// Grow a property's InvestmentVault to the current layout
+    ///
+    /// # Access Control
+    /// - Anyone (the payer funds the extra rent)
+    pub fn migrate_investment_vault(ctx: Context<MigrateInvestmentVault>) -> Result<()> {
+        instructions::migrate_accounts::handler_migrate_investment_vault(ctx)
     }
 }
@@ -0,0 +1,258 @@
+/// Multi-property bundle (index) state
+use anchor_lang::prelude::*;
+
+use super::PropertyState;
+use crate::{constants::*, error::RwaError};
+
+/// One property in a bundle basket
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct BundleComponent {
+    /// Property token mint
+    pub property_mint: Pubkey,
+
+    /// Property tokens (base units) backing one bundle token
+    pub amount_per_unit: u64,
+}
+
+/// A basket of property tokens represented by a bundle mint
+/// - Basket: Held in program escrow of each property
+/// - Bundle mint: Token-2022, non-transferable, 0 decimals (1 token = 1 basket)
+/// - Revenue: Underlying revenue epochs are collected into this PDA and
+///   shared per bundle token via `revenue_per_unit`
+///
+/// `total_units` only changes once every epoch deposited since the last
+/// change has been collected, and epochs deposited before the last change
+/// are refused, so an epoch is always collected with the units outstanding
+/// when it was deposited.
+///
+/// Bundle tokens cannot move between wallets, so every balance change goes
+/// through `mint_bundle` / `redeem_bundle`, which settle holder revenue
+/// and verify the holder's Hub Credential.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Bundle {
+    /// Creator (platform admin)
+    pub authority: Pubkey,
+
+    /// Bundle token mint (authority: this PDA)
+    pub bundle_mint: Pubkey,
+
+    /// Number of used entries in `components`
+    pub component_count: u8,
+
+    /// Basket composition
+    pub components: [BundleComponent; MAX_BUNDLE_COMPONENTS],
+
+    /// Bundle tokens outstanding
+    pub total_units: u64,
+
+    /// Cumulative revenue per bundle token (scaled by BUNDLE_REVENUE_PRECISION)
+    pub revenue_per_unit: u128,
+
+    /// Total revenue collected from underlying properties (in lamports)
+    pub total_revenue_collected: u64,
+
+    /// Timestamp of the last `total_units` change
+    pub units_changed_at: i64,
+
+    /// Per component: property `revenue_epoch_count` at the last units change
+    pub epochs_seen: [u64; MAX_BUNDLE_COMPONENTS],
+
+    /// Per component: epochs collected since the last units change
+    pub epochs_collected: [u64; MAX_BUNDLE_COMPONENTS],
+
+    /// Timestamp when the bundle was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Bundle {
+    /// Validates a basket composition
+    pub fn validate_components(components: &[BundleComponent]) -> Result<()> {
+        require!(
+            !components.is_empty() && components.len() <= MAX_BUNDLE_COMPONENTS,
+            RwaError::InvalidBundle
+        );
+
+        for (index, component) in components.iter().enumerate() {
+            require!(
+                component.amount_per_unit > 0 && component.property_mint != Pubkey::default(),
+                RwaError::InvalidBundle
+            );
+            require!(
+                !components[..index]
+                    .iter()
+                    .any(|other| other.property_mint == component.property_mint),
+                RwaError::InvalidBundle
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Active basket entries
+    pub fn active_components(&self) -> &[BundleComponent] {
+        &self.components[..self.component_count as usize]
+    }
+
+    /// Basket entry for a property mint, with its index
+    pub fn component(&self, property_mint: &Pubkey) -> Option<(usize, &BundleComponent)> {
+        self.active_components()
+            .iter()
+            .enumerate()
+            .find(|(_, component)| component.property_mint == *property_mint)
+    }
+
+    /// Whether the units may change for the component at `index`: every
+    /// revenue epoch deposited since the last change has been collected, and
+    /// none was deposited in the same second (its order would be ambiguous)
+    pub fn revenue_settled(&self, index: usize, property_state: &PropertyState, now: i64) -> bool {
+        if property_state.last_revenue_at >= now {
+            return false;
+        }
+
+        self.total_units == 0
+            || self.epochs_seen[index].saturating_add(self.epochs_collected[index])
+                == property_state.revenue_epoch_count
+    }
+
+    /// Restarts epoch tracking for the component at `index` on a units change
+    pub fn checkpoint_component(&mut self, index: usize, property_state: &PropertyState) {
+        self.epochs_seen[index] = property_state.revenue_epoch_count;
+        self.epochs_collected[index] = 0;
+    }
+
+    /// Adds collected revenue to the per-token accumulator
+    pub fn distribute_revenue(&mut self, amount: u64) -> Result<()> {
+        require!(self.total_units > 0, RwaError::NoTokenHolders);
+
+        let increment = (amount as u128)
+            .checked_mul(BUNDLE_REVENUE_PRECISION)
+            .ok_or(RwaError::MathOverflow)?
+            / self.total_units as u128;
+
+        self.revenue_per_unit = self
+            .revenue_per_unit
+            .checked_add(increment)
+            .ok_or(RwaError::MathOverflow)?;
+        self.total_revenue_collected = self
+            .total_revenue_collected
+            .checked_add(amount)
+            .ok_or(RwaError::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+impl BundleComponent {
+    /// Property tokens backing `units` bundle tokens
+    pub fn basket_amount(&self, units: u64) -> Result<u64> {
+        self.amount_per_unit
+            .checked_mul(units)
+            .ok_or(error!(RwaError::MathOverflow))
+    }
+}
+
+/// A holder's bundle position and revenue checkpoint
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct BundleHolder {
+    /// The bundle
+    pub bundle: Pubkey,
+
+    /// Holder wallet
+    pub holder: Pubkey,
+
+    /// Bundle tokens held
+    pub units: u64,
+
+    /// `units * revenue_per_unit` at the last settlement
+    pub revenue_debt: u128,
+
+    /// Revenue settled but not yet claimed (in lamports)
+    pub pending_revenue: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BundleHolder {
+    /// Moves revenue accrued since the last checkpoint into `pending_revenue`
+    pub fn settle(&mut self, revenue_per_unit: u128) -> Result<()> {
+        let accrued = (self.units as u128)
+            .checked_mul(revenue_per_unit)
+            .ok_or(RwaError::MathOverflow)?
+            .saturating_sub(self.revenue_debt)
+            / BUNDLE_REVENUE_PRECISION;
+
+        self.pending_revenue = self
+            .pending_revenue
+            .checked_add(accrued as u64)
+            .ok_or(RwaError::MathOverflow)?;
+        self.revenue_debt = self.units as u128 * revenue_per_unit;
+
+        Ok(())
+    }
+
+    /// Changes the unit balance after settling
+    pub fn set_units(&mut self, units: u64, revenue_per_unit: u128) -> Result<()> {
+        self.settle(revenue_per_unit)?;
+        self.units = units;
+        self.revenue_debt = (units as u128)
+            .checked_mul(revenue_per_unit)
+            .ok_or(RwaError::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holder(units: u64) -> BundleHolder {
+        BundleHolder {
+            bundle: Pubkey::new_unique(),
+            holder: Pubkey::new_unique(),
+            units,
+            revenue_debt: 0,
+            pending_revenue: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_component_validation() {
+        let mint = Pubkey::new_unique();
+        let component = BundleComponent {
+            property_mint: mint,
+            amount_per_unit: 100,
+        };
+
+        assert!(Bundle::validate_components(&[component]).is_ok());
+        assert!(Bundle::validate_components(&[]).is_err());
+        assert!(Bundle::validate_components(&[component, component]).is_err());
+        assert_eq!(component.basket_amount(3).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_revenue_follows_units_held() {
+        let mut early = holder(0);
+        let mut late = holder(0);
+
+        // Early holder alone for the first distribution
+        early.set_units(10, 0).unwrap();
+        let mut revenue_per_unit = 1_000 * BUNDLE_REVENUE_PRECISION / 10;
+
+        // Late holder joins, then both share the second distribution
+        late.set_units(30, revenue_per_unit).unwrap();
+        revenue_per_unit += 4_000 * BUNDLE_REVENUE_PRECISION / 40;
+
+        early.settle(revenue_per_unit).unwrap();
+        late.settle(revenue_per_unit).unwrap();
+        assert_eq!(early.pending_revenue, 2_000);
+        assert_eq!(late.pending_revenue, 3_000);
+    }
+}
@@ -0,0 +1,173 @@
+/// Compliance rule state (jurisdiction and risk restrictions)
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::RwaError,
+    utils::{CredentialAttributes, InvestorTier, RiskBand},
+};
+
+/// Per-property investor eligibility based on credential attributes
+/// - Jurisdiction: Allowed countries of residence (empty = any) and blocked
+///   countries (matched against residence and tax residency)
+/// - Tier: Minimum investor classification
+/// - Risk: Maximum AML risk band and whether PEPs are accepted
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct ComplianceRules {
+    /// The PropertyState these rules belong to
+    pub property_state: Pubkey,
+
+    /// ISO 3166-1 alpha-2 countries of residence accepted (empty = any)
+    #[max_len(MAX_COMPLIANCE_COUNTRIES)]
+    pub allowed_countries: Vec<[u8; 2]>,
+
+    /// ISO 3166-1 alpha-2 countries rejected for residence or tax residency
+    #[max_len(MAX_COMPLIANCE_COUNTRIES)]
+    pub blocked_countries: Vec<[u8; 2]>,
+
+    /// Minimum investor classification
+    pub min_investor_tier: InvestorTier,
+
+    /// Highest accepted AML risk band
+    pub max_risk_band: RiskBand,
+
+    /// Whether politically exposed persons may invest
+    pub allow_pep: bool,
+
+    /// Timestamp of last update
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ComplianceRules {
+    /// Load rules from a (possibly uninitialized) account
+    ///
+    /// Properties without configured rules accept any credential attributes.
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        Ok(Some(ComplianceRules::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Check a credential holder's attributes against the rules stored in
+    /// `account`, if any (see `load`)
+    ///
+    /// Called wherever property tokens reach a new holder: primary investment,
+    /// transfers (transfer hook) and every program-settled acquisition.
+    pub fn enforce(account: &AccountInfo, attributes: &CredentialAttributes) -> Result<()> {
+        match Self::load(account)? {
+            Some(rules) => rules.check(attributes),
+            None => Ok(()),
+        }
+    }
+
+    /// Check a credential holder's attributes against these rules
+    pub fn check(&self, attributes: &CredentialAttributes) -> Result<()> {
+        let residence = attributes.country_of_residence;
+        require!(
+            self.allowed_countries.is_empty() || self.allowed_countries.contains(&residence),
+            RwaError::JurisdictionNotAllowed
+        );
+        require!(
+            !self.blocked_countries.contains(&residence)
+                && !self.blocked_countries.contains(&attributes.tax_residency),
+            RwaError::JurisdictionNotAllowed
+        );
+        require!(
+            attributes.investor_tier >= self.min_investor_tier,
+            RwaError::InvestorTierNotAllowed
+        );
+        require!(
+            attributes.risk_band <= self.max_risk_band,
+            RwaError::RiskBandNotAllowed
+        );
+        require!(self.allow_pep || !attributes.is_pep, RwaError::PepNotAllowed);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> ComplianceRules {
+        ComplianceRules {
+            property_state: Pubkey::new_unique(),
+            allowed_countries: vec![*b"BR", *b"PT"],
+            blocked_countries: vec![*b"US"],
+            min_investor_tier: InvestorTier::Retail,
+            max_risk_band: RiskBand::Medium,
+            allow_pep: false,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    fn attributes() -> CredentialAttributes {
+        CredentialAttributes {
+            country_of_residence: *b"BR",
+            tax_residency: *b"BR",
+            investor_tier: InvestorTier::Qualified,
+            risk_band: RiskBand::Low,
+            is_pep: false,
+            investment_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_compliance_rules_accept_eligible_investor() {
+        assert!(rules().check(&attributes()).is_ok());
+    }
+
+    #[test]
+    fn test_compliance_rules_jurisdiction() {
+        let mut attributes = attributes();
+        attributes.country_of_residence = *b"AR";
+        assert_eq!(
+            rules().check(&attributes).err(),
+            Some(RwaError::JurisdictionNotAllowed.into())
+        );
+
+        // Blocked tax residency rejects an allowed resident
+        attributes.country_of_residence = *b"PT";
+        attributes.tax_residency = *b"US";
+        assert_eq!(
+            rules().check(&attributes).err(),
+            Some(RwaError::JurisdictionNotAllowed.into())
+        );
+    }
+
+    #[test]
+    fn test_compliance_rules_tier_risk_and_pep() {
+        let mut rules = rules();
+        rules.min_investor_tier = InvestorTier::Professional;
+        assert_eq!(
+            rules.check(&attributes()).err(),
+            Some(RwaError::InvestorTierNotAllowed.into())
+        );
+
+        rules.min_investor_tier = InvestorTier::Retail;
+        let mut attributes = attributes();
+        attributes.risk_band = RiskBand::High;
+        assert_eq!(
+            rules.check(&attributes).err(),
+            Some(RwaError::RiskBandNotAllowed.into())
+        );
+
+        attributes.risk_band = RiskBand::Low;
+        attributes.is_pep = true;
+        assert_eq!(
+            rules.check(&attributes).err(),
+            Some(RwaError::PepNotAllowed.into())
+        );
+        rules.allow_pep = true;
+        assert!(rules.check(&attributes).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+/// Distributor (partner broker) state for referral commissions
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// A registered distributor earning commissions on primary investments
+/// - Commission: Share of the platform fee (never the seller escrow)
+/// - Accrued commissions are held by this PDA until withdrawn
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Distributor {
+    /// Distributor wallet (withdraws commissions)
+    pub wallet: Pubkey,
+
+    /// Commission as a share of the platform fee in basis points
+    /// Example: 4000 = 40% of the 2.5% platform fee
+    pub commission_bps: u16,
+
+    /// Whether the distributor can earn new commissions
+    pub is_active: bool,
+
+    /// Total investment volume referred (in lamports)
+    pub total_referred: u64,
+
+    /// Total commissions accrued (in lamports)
+    pub total_accrued: u64,
+
+    /// Total commissions withdrawn (in lamports)
+    pub total_paid: u64,
+
+    /// Timestamp when the distributor was registered
+    pub created_at: i64,
+
+    /// Timestamp of last update
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Distributor {
+    /// Validates a commission rate
+    pub fn validate_commission(commission_bps: u16) -> Result<()> {
+        require!(
+            commission_bps as u64 <= BPS_DIVISOR,
+            RwaError::InvalidCommissionRate
+        );
+
+        Ok(())
+    }
+
+    /// Calculates the commission carved out of a platform fee
+    pub fn calculate_commission(&self, platform_fee: u64) -> Result<u64> {
+        platform_fee
+            .checked_mul(self.commission_bps as u64)
+            .and_then(|v| v.checked_div(BPS_DIVISOR))
+            .ok_or(error!(RwaError::MathOverflow))
+    }
+
+    /// Commissions accrued but not yet withdrawn
+    pub fn unpaid_commission(&self) -> u64 {
+        self.total_accrued.saturating_sub(self.total_paid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commission_is_share_of_platform_fee() {
+        let distributor = Distributor {
+            wallet: Pubkey::new_unique(),
+            commission_bps: 4_000,
+            is_active: true,
+            total_referred: 0,
+            total_accrued: 1_000,
+            total_paid: 400,
+            created_at: 0,
+            updated_at: 0,
+            bump: 255,
+        };
+
+        // 2.5% platform fee on 10 SOL = 0.25 SOL, 40% of which is commission
+        let platform_fee = 250_000_000;
+        assert_eq!(distributor.calculate_commission(platform_fee).unwrap(), 100_000_000);
+        assert_eq!(distributor.unpaid_commission(), 600);
+
+        assert!(Distributor::validate_commission(10_000).is_ok());
+        assert!(Distributor::validate_commission(10_001).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+/// Funding round state for tiered presale pricing
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// A priced sale round with its own cap and time window
+/// - Price: Lamports per whole token (earlier rounds are usually cheaper)
+/// - Cap: Maximum tokens sold in the round (sold totals live in `InvestmentVault`)
+/// - Allow-list: Optional Merkle root of eligible investor wallets
+/// - Credential: Optional Hub Credential type required to invest
+///
+/// Once a property has at least one round, every investment must go
+/// through an active round. Rounds run back to back without overlapping,
+/// so at most one is active at a time and investors cannot pick the price.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct FundingRound {
+    /// The PropertyState this round belongs to
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Round index (position in `InvestmentVault` per-round totals)
+    pub round_index: u8,
+
+    /// Price per whole token (in lamports)
+    pub price_per_token: u64,
+
+    /// Maximum tokens sold in this round (base units)
+    pub token_cap: u64,
+
+    /// Timestamp when the round opens
+    pub starts_at: i64,
+
+    /// Timestamp when the round closes
+    pub ends_at: i64,
+
+    /// Merkle root of allowed investor wallets (sha256 of the wallet key)
+    pub allowlist_root: Option<[u8; 32]>,
+
+    /// Hub Credential type required to invest (HubCredentialType as u8)
+    pub required_credential_type: Option<u8>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl FundingRound {
+    /// Whether the round accepts investments at the given time
+    pub fn is_active(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.starts_at && current_timestamp < self.ends_at
+    }
+
+    /// Calculates the tokens bought with `sol_amount` lamports
+    pub fn calculate_tokens(&self, sol_amount: u64, decimals: u8) -> Result<u64> {
+        let unit = 10u128
+            .checked_pow(decimals as u32)
+            .ok_or(RwaError::MathOverflow)?;
+
+        let tokens = (sol_amount as u128)
+            .checked_mul(unit)
+            .and_then(|v| v.checked_div(self.price_per_token as u128))
+            .ok_or(RwaError::MathOverflow)?;
+
+        u64::try_from(tokens).map_err(|_| error!(RwaError::MathOverflow))
+    }
+
+    /// Whether another round can be created
+    pub fn can_add_round(round_count: u8) -> bool {
+        (round_count as usize) < MAX_FUNDING_ROUNDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_tokens_at_round_price() {
+        let round = FundingRound {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            round_index: 0,
+            price_per_token: 500_000_000,
+            token_cap: 1_000_000_000,
+            starts_at: 0,
+            ends_at: 100,
+            allowlist_root: None,
+            required_credential_type: None,
+            bump: 255,
+        };
+
+        // 1 SOL at 0.5 SOL/token = 2 tokens (6 decimals)
+        assert_eq!(round.calculate_tokens(1_000_000_000, 6).unwrap(), 2_000_000);
+        assert!(round.is_active(0));
+        assert!(!round.is_active(100));
+    }
+}
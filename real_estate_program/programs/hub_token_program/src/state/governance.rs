@@ -0,0 +1,248 @@
+/// Token-holder governance state (config, proposals and votes)
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Per-property governance parameters
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct GovernanceConfig {
+    /// The PropertyState this config belongs to
+    pub property_state: Pubkey,
+
+    /// Minimum participation (votes cast / staked supply at the snapshot)
+    /// in basis points
+    pub quorum_bps: u16,
+
+    /// Minimum share of "for" votes among votes cast in basis points
+    pub approval_threshold_bps: u16,
+
+    /// Voting period length in seconds
+    pub voting_period_seconds: i64,
+
+    /// Timelock between the end of voting and execution in seconds
+    pub execution_delay_seconds: i64,
+
+    /// Minimum stake, held before proposing, for a holder to create a
+    /// proposal (the property authority can always propose)
+    pub proposal_threshold: u64,
+
+    /// Number of proposals created (next proposal id)
+    pub proposal_count: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GovernanceConfig {
+    /// Validates governance parameters
+    pub fn validate(params: &GovernanceParams) -> Result<()> {
+        require!(
+            (MIN_QUORUM_BPS..=BPS_DIVISOR as u16).contains(&params.quorum_bps)
+                && (MIN_APPROVAL_THRESHOLD_BPS..=BPS_DIVISOR as u16)
+                    .contains(&params.approval_threshold_bps),
+            crate::error::RwaError::InvalidGovernanceConfig
+        );
+        require!(
+            params.voting_period_seconds >= MIN_VOTING_PERIOD_SECONDS
+                && params.execution_delay_seconds >= MIN_EXECUTION_DELAY_SECONDS,
+            crate::error::RwaError::InvalidGovernanceConfig
+        );
+
+        Ok(())
+    }
+
+    /// Apply validated parameters
+    pub fn apply(&mut self, params: &GovernanceParams) {
+        self.quorum_bps = params.quorum_bps;
+        self.approval_threshold_bps = params.approval_threshold_bps;
+        self.voting_period_seconds = params.voting_period_seconds;
+        self.execution_delay_seconds = params.execution_delay_seconds;
+        self.proposal_threshold = params.proposal_threshold;
+    }
+}
+
+/// Governance parameters set at initialization or by an UpdateConfig proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct GovernanceParams {
+    pub quorum_bps: u16,
+    pub approval_threshold_bps: u16,
+    pub voting_period_seconds: i64,
+    pub execution_delay_seconds: i64,
+    pub proposal_threshold: u64,
+}
+
+/// Action executed when a proposal passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ProposalAction {
+    /// Non-binding decision recorded on-chain (e.g. approve a sale)
+    Signal,
+    /// Replace the property authority (manager)
+    TransferAuthority { new_authority: Pubkey },
+    /// Withdraw lamports from the reserve fund
+    WithdrawReserve { amount: u64, recipient: Pubkey },
+    /// Replace the governance parameters (the only way to change them)
+    UpdateConfig { params: GovernanceParams },
+}
+
+/// A governance proposal for a property
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Proposal {
+    /// The PropertyState this proposal belongs to
+    pub property_state: Pubkey,
+
+    /// Sequential proposal id
+    pub proposal_id: u64,
+
+    /// Wallet that created the proposal
+    pub proposer: Pubkey,
+
+    /// Action executed if the proposal passes
+    pub action: ProposalAction,
+
+    /// URI with the full proposal text (IPFS/Arweave)
+    #[max_len(200)]
+    pub description_uri: String,
+
+    /// Staked supply snapshot at creation (quorum base)
+    /// Votes are weighted by tokens staked before `voting_starts_at`
+    pub snapshot_supply: u64,
+
+    /// Quorum copied from the config at creation
+    pub quorum_bps: u16,
+
+    /// Approval threshold copied from the config at creation
+    pub approval_threshold_bps: u16,
+
+    /// Total weight voting for
+    pub votes_for: u64,
+
+    /// Total weight voting against
+    pub votes_against: u64,
+
+    /// Timestamp when voting started
+    pub voting_starts_at: i64,
+
+    /// Timestamp when voting ends
+    pub voting_ends_at: i64,
+
+    /// Timestamp from which a passed proposal can be executed (timelock)
+    pub executable_at: i64,
+
+    /// Whether the action has been executed
+    pub executed: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Whether votes are accepted at the given time
+    pub fn is_voting_open(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.voting_starts_at && current_timestamp < self.voting_ends_at
+    }
+
+    /// Whether the proposal reached quorum and the approval threshold
+    pub fn has_passed(&self) -> bool {
+        let votes_cast = self.votes_for as u128 + self.votes_against as u128;
+        if votes_cast == 0 || self.snapshot_supply == 0 {
+            return false;
+        }
+
+        let participation_bps = votes_cast * BPS_DIVISOR as u128 / self.snapshot_supply as u128;
+        let approval_bps = self.votes_for as u128 * BPS_DIVISOR as u128 / votes_cast;
+
+        participation_bps >= self.quorum_bps as u128
+            && approval_bps >= self.approval_threshold_bps as u128
+    }
+}
+
+/// A holder's vote on a proposal
+/// The weight is the voter's stake at the proposal snapshot; the stake stays
+/// locked until voting ends.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct VoteRecord {
+    /// The proposal voted on
+    pub proposal: Pubkey,
+
+    /// The voter
+    pub voter: Pubkey,
+
+    /// Whether the vote supports the proposal
+    pub support: bool,
+
+    /// Voting weight (stake held at the snapshot)
+    pub weight: u64,
+
+    /// Timestamp of the vote
+    pub voted_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proposal(votes_for: u64, votes_against: u64) -> Proposal {
+        Proposal {
+            property_state: Pubkey::new_unique(),
+            proposal_id: 0,
+            proposer: Pubkey::new_unique(),
+            action: ProposalAction::Signal,
+            description_uri: String::new(),
+            snapshot_supply: 1_000,
+            quorum_bps: 2_000,
+            approval_threshold_bps: 5_001,
+            votes_for,
+            votes_against,
+            voting_starts_at: 0,
+            voting_ends_at: 100,
+            executable_at: 200,
+            executed: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_proposal_requires_quorum() {
+        assert!(!proposal(150, 0).has_passed());
+        assert!(proposal(200, 0).has_passed());
+    }
+
+    #[test]
+    fn test_proposal_requires_approval_threshold() {
+        assert!(!proposal(150, 150).has_passed());
+        assert!(proposal(151, 149).has_passed());
+    }
+
+    #[test]
+    fn test_config_requires_quorum_and_timelock() {
+        let params = GovernanceParams {
+            quorum_bps: MIN_QUORUM_BPS,
+            approval_threshold_bps: MIN_APPROVAL_THRESHOLD_BPS,
+            voting_period_seconds: MIN_VOTING_PERIOD_SECONDS,
+            execution_delay_seconds: MIN_EXECUTION_DELAY_SECONDS,
+            proposal_threshold: 0,
+        };
+        assert!(GovernanceConfig::validate(&params).is_ok());
+
+        assert!(GovernanceConfig::validate(&GovernanceParams {
+            quorum_bps: 0,
+            ..params
+        })
+        .is_err());
+        assert!(GovernanceConfig::validate(&GovernanceParams {
+            approval_threshold_bps: 5_000,
+            ..params
+        })
+        .is_err());
+        assert!(GovernanceConfig::validate(&GovernanceParams {
+            execution_delay_seconds: 0,
+            ..params
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,133 @@
+/// Investment limit state (minimum ticket and per-credential caps)
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError, utils::HubCredentialType};
+
+/// Per-property investment limits
+/// - Minimum ticket: Smallest accepted investment (in lamports)
+/// - Caps: Maximum invested per investor per period, indexed by
+///   `HubCredentialType` (0 = uncapped, e.g. accredited investors)
+/// - Period: Rolling window for caps (0 = lifetime)
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct InvestmentLimits {
+    /// The PropertyState these limits belong to
+    pub property_state: Pubkey,
+
+    /// Minimum investment per transaction (in lamports)
+    pub min_investment: u64,
+
+    /// Maximum investment per period by credential type (in lamports, 0 = uncapped)
+    pub max_investment_by_type: [u64; HUB_CREDENTIAL_TYPE_COUNT],
+
+    /// Cap period in seconds (e.g. 365 days for annual limits, 0 = lifetime)
+    pub limit_period_seconds: i64,
+
+    /// Timestamp of last update
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InvestmentLimits {
+    /// Load limits from a (possibly uninitialized) account
+    ///
+    /// Properties without configured limits accept any investment.
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        Ok(Some(InvestmentLimits::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Maximum investment per period for a credential type (None = uncapped)
+    pub fn max_for(&self, credential_type: HubCredentialType) -> Option<u64> {
+        match self.max_investment_by_type[credential_type as usize] {
+            0 => None,
+            max => Some(max),
+        }
+    }
+}
+
+/// Cumulative investment of one investor in one property
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct InvestorRecord {
+    /// The PropertyState invested in
+    pub property_state: Pubkey,
+
+    /// The investor wallet
+    pub investor: Pubkey,
+
+    /// Total invested over the lifetime of the property (in lamports)
+    pub total_invested: u64,
+
+    /// Invested during the current limit period (in lamports)
+    pub period_invested: u64,
+
+    /// Timestamp when the current limit period started
+    pub period_started_at: i64,
+
+    /// Timestamp of the last investment
+    pub last_invested_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl InvestorRecord {
+    /// Records an investment, rolling the period window if it has elapsed
+    /// Returns the amount invested in the current period including `amount`
+    pub fn record_investment(
+        &mut self,
+        amount: u64,
+        current_timestamp: i64,
+        limit_period_seconds: i64,
+    ) -> Result<u64> {
+        let period_elapsed = limit_period_seconds > 0
+            && current_timestamp.saturating_sub(self.period_started_at) >= limit_period_seconds;
+        if self.period_invested == 0 || period_elapsed {
+            self.period_invested = 0;
+            self.period_started_at = current_timestamp;
+        }
+
+        self.period_invested = self
+            .period_invested
+            .checked_add(amount)
+            .ok_or(RwaError::MathOverflow)?;
+        self.total_invested = self
+            .total_invested
+            .checked_add(amount)
+            .ok_or(RwaError::MathOverflow)?;
+        self.last_invested_at = current_timestamp;
+
+        Ok(self.period_invested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period_window_resets() {
+        let mut record = InvestorRecord {
+            property_state: Pubkey::new_unique(),
+            investor: Pubkey::new_unique(),
+            total_invested: 0,
+            period_invested: 0,
+            period_started_at: 0,
+            last_invested_at: 0,
+            bump: 255,
+        };
+
+        assert_eq!(record.record_investment(100, 10, 1_000).unwrap(), 100);
+        assert_eq!(record.record_investment(50, 500, 1_000).unwrap(), 150);
+        assert_eq!(record.record_investment(70, 1_010, 1_000).unwrap(), 70);
+        assert_eq!(record.total_invested, 220);
+        assert_eq!(record.period_started_at, 1_010);
+    }
+}
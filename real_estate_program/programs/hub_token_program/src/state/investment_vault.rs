@@ -46,9 +46,34 @@ pub struct InvestmentVault {
 
     /// PDA bump seed
     pub bump: u8,
+
+    // Fields below were appended after the first release; vaults created
+    // earlier read them as zero once reallocated by migrate_investment_vault
+
+    /// Layout version (0 = created before the appended fields)
+    pub version: u8,
+
+    /// Number of funding rounds created
+    /// Once non-zero, every investment must go through an active round
+    pub round_count: u8,
+
+    /// Tokens sold per funding round (indexed by round_index)
+    pub round_tokens_sold: [u64; MAX_FUNDING_ROUNDS],
+
+    /// SOL raised per funding round (indexed by round_index, in lamports)
+    pub round_sol_raised: [u64; MAX_FUNDING_ROUNDS],
+
+    /// Index of the round investments go through (only moves forward)
+    pub current_round: u8,
+
+    /// End of the latest round; the next round cannot start before it
+    pub rounds_end_at: i64,
 }
 
 impl InvestmentVault {
+    /// Layout version written on vault creation and by migrations
+    pub const CURRENT_VERSION: u8 = 1;
+
     /// Calculate platform fee from investment amount
     pub fn calculate_platform_fee(amount: u64) -> Result<u64> {
         amount
@@ -0,0 +1,283 @@
+/// Collateralized lending state (borrow a stablecoin against property tokens)
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// Lending market for one property mint
+/// - Collateral: Property tokens held in program escrow (`PropertyState::escrowed_supply`)
+/// - Loans: Paid out of `loan_vault` in `loan_mint` (a USD stablecoin)
+/// - Valuation: `valuation_usd` spread over `total_supply`. It starts at the
+///   property's `total_value_usd` when the market is created and only the
+///   platform admin moves it, by at most MAX_VALUATION_CHANGE_BPS per
+///   update. The property authority's own valuation is never used, and a
+///   valuation older than MAX_VALUATION_AGE backs no new debt.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct LendingMarket {
+    /// The PropertyState this market lends against
+    pub property_state: Pubkey,
+
+    /// The property mint (collateral)
+    pub mint: Pubkey,
+
+    /// Stablecoin mint lent out (1 whole token = 1 USD)
+    pub loan_mint: Pubkey,
+
+    /// Stablecoin vault (authority: this PDA)
+    pub loan_vault: Pubkey,
+
+    /// Maximum loan-to-value when borrowing or withdrawing collateral (basis points)
+    pub max_ltv_bps: u16,
+
+    /// LTV above which a position can be liquidated (basis points)
+    pub liquidation_threshold_bps: u16,
+
+    /// Extra collateral paid to liquidators (basis points of the repaid debt)
+    pub liquidation_bonus_bps: u16,
+
+    /// Simple annual interest rate on debt (basis points)
+    pub interest_rate_bps: u16,
+
+    /// Collateral deposited across all positions (base units)
+    pub total_collateral: u64,
+
+    /// Debt outstanding across all positions (loan mint base units, as of last accrual)
+    pub total_borrowed: u64,
+
+    /// Property valuation used for collateral (USD cents)
+    pub valuation_usd: u64,
+
+    /// Timestamp the valuation was last set
+    pub valuation_updated_at: i64,
+
+    /// Timestamp when the market was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LendingMarket {
+    /// Validates the risk parameters
+    pub fn validate_params(
+        max_ltv_bps: u16,
+        liquidation_threshold_bps: u16,
+        liquidation_bonus_bps: u16,
+        interest_rate_bps: u16,
+    ) -> Result<()> {
+        require!(
+            max_ltv_bps > 0
+                && max_ltv_bps < liquidation_threshold_bps
+                && liquidation_threshold_bps <= MAX_LIQUIDATION_THRESHOLD_BPS
+                && liquidation_bonus_bps <= MAX_LIQUIDATION_BONUS_BPS
+                && interest_rate_bps <= MAX_INTEREST_RATE_BPS,
+            RwaError::InvalidLendingParams
+        );
+
+        Ok(())
+    }
+
+    /// Checks a new valuation against the per-update change and interval limits
+    pub fn validate_valuation_update(
+        &self,
+        valuation_usd: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        let max_change =
+            self.valuation_usd as u128 * MAX_VALUATION_CHANGE_BPS as u128 / BPS_DIVISOR as u128;
+        let change = self.valuation_usd.abs_diff(valuation_usd) as u128;
+
+        require!(
+            valuation_usd > 0
+                && change <= max_change
+                && current_timestamp
+                    >= self
+                        .valuation_updated_at
+                        .saturating_add(MIN_VALUATION_UPDATE_INTERVAL),
+            RwaError::InvalidValuationUpdate
+        );
+
+        Ok(())
+    }
+
+    /// Requires a valuation recent enough to back new debt or a liquidation
+    pub fn require_fresh_valuation(&self, current_timestamp: i64) -> Result<()> {
+        require!(
+            current_timestamp <= self.valuation_updated_at.saturating_add(MAX_VALUATION_AGE),
+            RwaError::StaleValuation
+        );
+
+        Ok(())
+    }
+
+    /// Value of `collateral` property tokens in loan mint base units
+    pub fn collateral_value(
+        collateral: u64,
+        total_value_usd_cents: u64,
+        total_supply: u64,
+        loan_decimals: u8,
+    ) -> Result<u64> {
+        require!(total_supply > 0, RwaError::InvalidTotalSupply);
+
+        let unit = 10u128
+            .checked_pow(loan_decimals as u32)
+            .ok_or(RwaError::MathOverflow)?;
+        let value = (collateral as u128)
+            .checked_mul(total_value_usd_cents as u128)
+            .and_then(|v| v.checked_mul(unit))
+            .ok_or(RwaError::MathOverflow)?
+            / (total_supply as u128 * 100);
+
+        u64::try_from(value).map_err(|_| error!(RwaError::MathOverflow))
+    }
+
+    /// Largest debt allowed against `collateral_value`
+    pub fn max_debt(&self, collateral_value: u64) -> u64 {
+        (collateral_value as u128 * self.max_ltv_bps as u128 / BPS_DIVISOR as u128) as u64
+    }
+
+    /// Whether `debt` against `collateral_value` is past the liquidation threshold
+    pub fn is_liquidatable(&self, debt: u64, collateral_value: u64) -> bool {
+        debt as u128 * BPS_DIVISOR as u128
+            > collateral_value as u128 * self.liquidation_threshold_bps as u128
+    }
+
+    /// Collateral seized for repaying `repay_amount`, including the bonus
+    pub fn seized_collateral(
+        &self,
+        repay_amount: u64,
+        collateral: u64,
+        collateral_value: u64,
+    ) -> Result<u64> {
+        require!(collateral_value > 0, RwaError::InsufficientBalance);
+
+        let with_bonus = repay_amount as u128
+            * (BPS_DIVISOR as u128 + self.liquidation_bonus_bps as u128)
+            / BPS_DIVISOR as u128;
+        let seized = with_bonus * collateral as u128 / collateral_value as u128;
+
+        Ok(seized.min(collateral as u128) as u64)
+    }
+}
+
+/// A borrower's collateral and debt in one lending market
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct LoanPosition {
+    /// The lending market
+    pub market: Pubkey,
+
+    /// Borrower wallet
+    pub borrower: Pubkey,
+
+    /// Property tokens deposited as collateral (base units)
+    pub collateral_amount: u64,
+
+    /// Debt including accrued interest (loan mint base units)
+    pub debt: u64,
+
+    /// Timestamp interest was last accrued
+    pub last_accrued_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LoanPosition {
+    /// Adds simple interest since the last accrual
+    /// Returns the interest added
+    pub fn accrue_interest(&mut self, interest_rate_bps: u16, current_timestamp: i64) -> Result<u64> {
+        let elapsed = current_timestamp.saturating_sub(self.last_accrued_at).max(0) as u128;
+        let interest = (self.debt as u128)
+            .checked_mul(interest_rate_bps as u128)
+            .and_then(|v| v.checked_mul(elapsed))
+            .ok_or(RwaError::MathOverflow)?
+            / (BPS_DIVISOR as u128 * SECONDS_PER_YEAR as u128);
+        let interest = u64::try_from(interest).map_err(|_| RwaError::MathOverflow)?;
+
+        self.debt = self
+            .debt
+            .checked_add(interest)
+            .ok_or(RwaError::MathOverflow)?;
+        self.last_accrued_at = current_timestamp;
+
+        Ok(interest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market() -> LendingMarket {
+        LendingMarket {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            loan_mint: Pubkey::new_unique(),
+            loan_vault: Pubkey::new_unique(),
+            max_ltv_bps: 5_000,
+            liquidation_threshold_bps: 7_500,
+            liquidation_bonus_bps: 500,
+            interest_rate_bps: 1_000,
+            total_collateral: 0,
+            total_borrowed: 0,
+            valuation_usd: 100_000_000,
+            valuation_updated_at: 0,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_ltv_and_liquidation() {
+        let market = market();
+
+        // $1,000,000 property, 1,000,000 tokens -> 1,000 tokens = $1,000 (6-decimal stablecoin)
+        let value = LendingMarket::collateral_value(1_000, 100_000_000, 1_000_000, 6).unwrap();
+        assert_eq!(value, 1_000_000_000);
+        assert_eq!(market.max_debt(value), 500_000_000);
+
+        assert!(!market.is_liquidatable(750_000_000, value));
+        assert!(market.is_liquidatable(750_000_001, value));
+
+        // Repaying $400 seizes $420 of collateral
+        assert_eq!(market.seized_collateral(400_000_000, 1_000, value).unwrap(), 420);
+        assert_eq!(market.seized_collateral(u64::MAX / 2, 1_000, value).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_valuation_update_limits() {
+        let market = market();
+        let day = MIN_VALUATION_UPDATE_INTERVAL;
+
+        // $1,000,000 may move by $100,000 once a day
+        assert!(market.validate_valuation_update(110_000_000, day).is_ok());
+        assert!(market.validate_valuation_update(90_000_000, day).is_ok());
+        assert!(market.validate_valuation_update(110_000_001, day).is_err());
+        assert!(market
+            .validate_valuation_update(110_000_000, day - 1)
+            .is_err());
+        assert!(market.validate_valuation_update(0, day).is_err());
+
+        assert!(market.require_fresh_valuation(MAX_VALUATION_AGE).is_ok());
+        assert!(market
+            .require_fresh_valuation(MAX_VALUATION_AGE + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_interest_accrual() {
+        let mut position = LoanPosition {
+            market: Pubkey::new_unique(),
+            borrower: Pubkey::new_unique(),
+            collateral_amount: 1_000,
+            debt: 1_000_000,
+            last_accrued_at: 0,
+            bump: 255,
+        };
+
+        let interest = position.accrue_interest(1_000, SECONDS_PER_YEAR / 2).unwrap();
+        assert_eq!(interest, 50_000);
+        assert_eq!(position.debt, 1_050_000);
+    }
+}
@@ -0,0 +1,256 @@
+/// Constant-product liquidity pool state (property token / SOL)
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// Direction of a pool swap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SwapDirection {
+    /// Pay SOL, receive property tokens
+    SolToToken,
+    /// Pay property tokens, receive SOL
+    TokenToSol,
+}
+
+/// AMM pool for one property mint
+/// - Token reserve: Held in `token_vault` (`PropertyState::pool_supply`)
+/// - SOL reserve: Lamports held by this PDA on top of its rent
+/// - LP fee: Left in the reserves, accruing to liquidity providers
+/// - Platform fee: Always charged in SOL and sent to the platform treasury
+///
+/// Reserves are tracked explicitly rather than read from balances, so
+/// direct donations to the PDA do not move the price.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct LiquidityPool {
+    /// The PropertyState this pool belongs to
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// LP token mint (authority: this PDA)
+    pub lp_mint: Pubkey,
+
+    /// Property token vault (authority: this PDA)
+    pub token_vault: Pubkey,
+
+    /// Property tokens in the pool (base units)
+    pub token_reserve: u64,
+
+    /// SOL in the pool (lamports, excluding rent)
+    pub sol_reserve: u64,
+
+    /// Fee kept by liquidity providers (in basis points of the input)
+    pub lp_fee_bps: u16,
+
+    /// Fee sent to the platform treasury (in basis points of the SOL leg)
+    pub platform_fee_bps: u16,
+
+    /// Timestamp when the pool was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Result of a swap quote
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SwapQuote {
+    /// Amount added to the pool reserve
+    pub net_in: u64,
+    /// Amount leaving the pool reserve
+    pub reserve_out: u64,
+    /// Amount received by the user
+    pub amount_out: u64,
+    /// SOL sent to the platform treasury
+    pub platform_fee: u64,
+}
+
+impl LiquidityPool {
+    /// Derive the pool PDA for a property mint
+    pub fn find_address(mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[LIQUIDITY_POOL_SEED, mint.as_ref()], &crate::ID)
+    }
+
+    /// Validates the fee configuration
+    pub fn validate_fees(lp_fee_bps: u16, platform_fee_bps: u16) -> Result<()> {
+        require!(
+            lp_fee_bps as u64 + platform_fee_bps as u64 <= MAX_POOL_FEE_BPS as u64,
+            RwaError::InvalidPoolFees
+        );
+
+        Ok(())
+    }
+
+    /// Quotes a swap against the current reserves
+    pub fn quote_swap(&self, direction: SwapDirection, amount_in: u64) -> Result<SwapQuote> {
+        require!(
+            self.token_reserve > 0 && self.sol_reserve > 0,
+            RwaError::InsufficientLiquidity
+        );
+
+        match direction {
+            SwapDirection::SolToToken => {
+                let platform_fee = bps_of(amount_in, self.platform_fee_bps)?;
+                let net_in = amount_in
+                    .checked_sub(platform_fee)
+                    .ok_or(RwaError::MathOverflow)?;
+                let out =
+                    constant_product_out(net_in, self.sol_reserve, self.token_reserve, self.lp_fee_bps)?;
+
+                Ok(SwapQuote {
+                    net_in,
+                    reserve_out: out,
+                    amount_out: out,
+                    platform_fee,
+                })
+            }
+            SwapDirection::TokenToSol => {
+                let out =
+                    constant_product_out(amount_in, self.token_reserve, self.sol_reserve, self.lp_fee_bps)?;
+                let platform_fee = bps_of(out, self.platform_fee_bps)?;
+
+                Ok(SwapQuote {
+                    net_in: amount_in,
+                    reserve_out: out,
+                    amount_out: out.checked_sub(platform_fee).ok_or(RwaError::MathOverflow)?,
+                    platform_fee,
+                })
+            }
+        }
+    }
+
+    /// Calculates LP tokens minted and SOL required for a token deposit
+    /// The first deposit sets the price and mints sqrt(token * sol) LP tokens.
+    pub fn quote_add_liquidity(
+        &self,
+        token_amount: u64,
+        max_sol_amount: u64,
+        lp_supply: u64,
+    ) -> Result<(u64, u64)> {
+        if lp_supply == 0 {
+            let lp = integer_sqrt(token_amount as u128 * max_sol_amount as u128);
+            return Ok((lp as u64, max_sol_amount));
+        }
+
+        require!(self.token_reserve > 0, RwaError::InsufficientLiquidity);
+
+        // Round SOL up so depositors never dilute existing providers
+        let sol_required = (token_amount as u128 * self.sol_reserve as u128)
+            .div_ceil(self.token_reserve as u128);
+        let lp = token_amount as u128 * lp_supply as u128 / self.token_reserve as u128;
+
+        Ok((
+            u64::try_from(lp).map_err(|_| RwaError::MathOverflow)?,
+            u64::try_from(sol_required).map_err(|_| RwaError::MathOverflow)?,
+        ))
+    }
+
+    /// Calculates the tokens and SOL returned for burning LP tokens
+    pub fn quote_remove_liquidity(&self, lp_amount: u64, lp_supply: u64) -> Result<(u64, u64)> {
+        require!(lp_supply > 0 && lp_amount <= lp_supply, RwaError::InsufficientLiquidity);
+
+        let token_out = lp_amount as u128 * self.token_reserve as u128 / lp_supply as u128;
+        let sol_out = lp_amount as u128 * self.sol_reserve as u128 / lp_supply as u128;
+
+        Ok((token_out as u64, sol_out as u64))
+    }
+}
+
+/// `amount * bps / 10000`
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    Ok((amount as u128 * bps as u128 / BPS_DIVISOR as u128) as u64)
+}
+
+/// x * y = k output amount with the LP fee taken from the input
+fn constant_product_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    let amount_in_after_fee =
+        amount_in as u128 * (BPS_DIVISOR as u128 - fee_bps as u128) / BPS_DIVISOR as u128;
+    let denominator = (reserve_in as u128)
+        .checked_add(amount_in_after_fee)
+        .ok_or(RwaError::MathOverflow)?;
+    require!(denominator > 0, RwaError::InsufficientLiquidity);
+
+    Ok((reserve_out as u128 * amount_in_after_fee / denominator) as u64)
+}
+
+/// Integer square root (floor)
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> LiquidityPool {
+        LiquidityPool {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            lp_mint: Pubkey::new_unique(),
+            token_vault: Pubkey::new_unique(),
+            token_reserve: 1_000_000,
+            sol_reserve: 1_000_000,
+            lp_fee_bps: 30,
+            platform_fee_bps: 0,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_swap_preserves_constant_product() {
+        let pool = pool();
+        let quote = pool.quote_swap(SwapDirection::SolToToken, 10_000).unwrap();
+
+        // 10_000 * 0.997 = 9_970 in; 1_000_000 * 9_970 / 1_009_970
+        assert_eq!(quote.amount_out, 9_871);
+        let k_before = pool.token_reserve as u128 * pool.sol_reserve as u128;
+        let k_after = (pool.token_reserve - quote.reserve_out) as u128
+            * (pool.sol_reserve + 10_000) as u128;
+        assert!(k_after >= k_before);
+    }
+
+    #[test]
+    fn test_platform_fee_is_charged_in_sol() {
+        let mut pool = pool();
+        pool.platform_fee_bps = 100;
+
+        let buy = pool.quote_swap(SwapDirection::SolToToken, 10_000).unwrap();
+        assert_eq!(buy.platform_fee, 100);
+
+        let sell = pool.quote_swap(SwapDirection::TokenToSol, 10_000).unwrap();
+        assert_eq!(sell.platform_fee, sell.reserve_out / 100);
+        assert_eq!(sell.amount_out, sell.reserve_out - sell.platform_fee);
+    }
+
+    #[test]
+    fn test_liquidity_round_trip() {
+        let pool = pool();
+
+        let (first_lp, _) = pool.quote_add_liquidity(4_000, 9_000, 0).unwrap();
+        assert_eq!(first_lp, 6_000);
+
+        let (lp, sol_required) = pool.quote_add_liquidity(1_000, u64::MAX, 1_000_000).unwrap();
+        assert_eq!((lp, sol_required), (1_000, 1_000));
+
+        let (token_out, sol_out) = pool.quote_remove_liquidity(500_000, 1_000_000).unwrap();
+        assert_eq!((token_out, sol_out), (500_000, 500_000));
+    }
+}
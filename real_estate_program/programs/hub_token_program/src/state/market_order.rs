@@ -0,0 +1,97 @@
+/// Secondary market limit orders for property tokens
+use anchor_lang::prelude::*;
+
+/// Side of a limit order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum OrderSide {
+    /// Maker sells tokens (tokens held in program escrow)
+    Sell,
+    /// Maker buys tokens (lamports held in the order PDA)
+    Buy,
+}
+
+/// A resting limit order
+/// - Sell: Tokens escrowed via `escrow_property_tokens`, released to the taker
+/// - Buy: Lamports for the full amount deposited into this PDA
+///
+/// Orders are partially fillable; the PDA is closed to the maker once
+/// fully filled or cancelled.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct MarketOrder {
+    /// The PropertyState this order trades
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Order creator
+    pub owner: Pubkey,
+
+    /// Maker-chosen order id (unique per owner and mint)
+    pub order_id: u64,
+
+    /// Buy or sell
+    pub side: OrderSide,
+
+    /// Limit price per whole token (in lamports)
+    pub price_per_token: u64,
+
+    /// Original order size (in base units)
+    pub amount: u64,
+
+    /// Unfilled size (in base units)
+    pub remaining: u64,
+
+    /// Timestamp when the order was placed
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MarketOrder {
+    /// Calculates the lamports owed for `amount` base units at the order price
+    pub fn calculate_cost(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let unit = 10u128
+            .checked_pow(decimals as u32)
+            .ok_or(error!(crate::error::RwaError::MathOverflow))?;
+
+        let cost = (amount as u128)
+            .checked_mul(self.price_per_token as u128)
+            .and_then(|v| v.checked_div(unit))
+            .ok_or(error!(crate::error::RwaError::MathOverflow))?;
+
+        u64::try_from(cost).map_err(|_| error!(crate::error::RwaError::MathOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_fill_costs_never_exceed_deposit() {
+        let order = MarketOrder {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            order_id: 0,
+            side: OrderSide::Buy,
+            price_per_token: 1_000_000_001,
+            amount: 1_000_000_000,
+            remaining: 1_000_000_000,
+            created_at: 0,
+            bump: 255,
+        };
+
+        let deposit = order.calculate_cost(order.amount, 6).unwrap();
+        let fills: u64 = (0..3)
+            .map(|_| order.calculate_cost(333_333_333, 6).unwrap())
+            .sum::<u64>()
+            + order.calculate_cost(1, 6).unwrap();
+
+        assert_eq!(deposit, 1_000_000_001_000);
+        assert!(fills <= deposit);
+    }
+}
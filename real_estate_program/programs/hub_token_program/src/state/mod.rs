@@ -1,7 +1,37 @@
+pub mod bundle;
+pub mod compliance_rules;
+pub mod distributor;
+pub mod funding_round;
+pub mod governance;
+pub mod investment_limits;
 pub mod investment_vault;
+pub mod lending_market;
+pub mod liquidity_pool;
+pub mod market_order;
+pub mod platform_config;
 pub mod property_details;
+pub mod property_liquidation;
 pub mod property_state;
+pub mod redemption_window;
+pub mod stake_position;
+pub mod transfer_fee;
+pub mod vesting_schedule;
 
+pub use bundle::*;
+pub use compliance_rules::*;
+pub use distributor::*;
+pub use funding_round::*;
+pub use governance::*;
+pub use investment_limits::*;
 pub use investment_vault::*;
+pub use lending_market::*;
+pub use liquidity_pool::*;
+pub use market_order::*;
+pub use platform_config::*;
 pub use property_details::*;
+pub use property_liquidation::*;
 pub use property_state::*;
+pub use redemption_window::*;
+pub use stake_position::*;
+pub use transfer_fee::*;
+pub use vesting_schedule::*;
@@ -0,0 +1,42 @@
+/// Platform-wide configuration (guardian and emergency circuit breaker)
+use anchor_lang::prelude::*;
+
+/// Global settings shared by every property
+/// - Admin: Governs platform-level settings, separate from the fee treasury
+/// - Guardian: Key allowed to pause transfers, distinct from property authorities
+/// - Circuit breaker: Stops secondary transfers of every property at once
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct PlatformConfig {
+    /// Platform admin (appoints the guardian, gates admin instructions)
+    pub admin: Pubkey,
+
+    /// Guardian key allowed to pause transfers
+    pub guardian: Pubkey,
+
+    /// Platform-wide transfer pause
+    pub transfers_paused: bool,
+
+    /// Timestamp of last update
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    /// Read the platform-wide pause flag from a (possibly uninitialized) account
+    ///
+    /// The transfer hook resolves the PlatformConfig PDA for every transfer,
+    /// so a missing config must not block transfers.
+    pub fn global_transfers_paused(account: &AccountInfo) -> Result<bool> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(false);
+        }
+
+        let data = account.try_borrow_data()?;
+        let config = PlatformConfig::try_deserialize(&mut &data[..])?;
+
+        Ok(config.transfers_paused)
+    }
+}
@@ -0,0 +1,120 @@
+/// Liquidation state for a property that has been sold
+use anchor_lang::prelude::*;
+
+use crate::constants::LIQUIDATION_CLAIM_PERIOD_SECONDS;
+
+/// Tracks the final distribution of sale proceeds to token holders
+/// - Proceeds: Sale price deposited by the authority + swept reserve fund
+/// - Redemption: Holders burn tokens for their pro-rata share of what is left
+///
+/// The account itself holds the proceeds (in lamports) until they are redeemed.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct PropertyLiquidation {
+    /// The PropertyState being liquidated
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Sale proceeds deposited by the authority (in lamports)
+    pub sale_proceeds: u64,
+
+    /// Reserve fund balance swept into the distribution (in lamports)
+    pub reserve_swept: u64,
+
+    /// Proceeds not yet paid out to holders (in lamports)
+    pub remaining_proceeds: u64,
+
+    /// Circulating supply snapshot when liquidation started
+    pub supply_at_liquidation: u64,
+
+    /// Tokens redeemed (burned) so far
+    pub tokens_redeemed: u64,
+
+    /// Timestamp when liquidation started
+    pub started_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PropertyLiquidation {
+    /// Total amount available for distribution (proceeds + reserve)
+    pub fn total_distribution(&self) -> u64 {
+        self.sale_proceeds.saturating_add(self.reserve_swept)
+    }
+
+    /// Calculate the payout for burning `amount` tokens
+    ///
+    /// Uses the remaining proceeds over the remaining supply, so the last
+    /// redeemer receives any rounding dust and the pool always empties.
+    pub fn calculate_payout(&self, amount: u64, outstanding_supply: u64) -> Result<u64> {
+        require!(outstanding_supply > 0, crate::error::RwaError::NoTokenHolders);
+
+        let payout = (amount as u128)
+            .checked_mul(self.remaining_proceeds as u128)
+            .and_then(|v| v.checked_div(outstanding_supply as u128))
+            .ok_or(error!(crate::error::RwaError::MathOverflow))?;
+
+        u64::try_from(payout).map_err(|_| error!(crate::error::RwaError::MathOverflow))
+    }
+
+    /// Whether the property can be closed
+    ///
+    /// Normally every token must be redeemed first. Tokens burned outside the
+    /// program or held by owners who never redeem would block the close
+    /// forever, so once the claim period has ended the authority may close
+    /// with supply outstanding and recover the unclaimed proceeds.
+    pub fn can_close(&self, outstanding_supply: u64, now: i64) -> bool {
+        let claim_deadline = self
+            .started_at
+            .saturating_add(LIQUIDATION_CLAIM_PERIOD_SECONDS);
+        outstanding_supply == 0 || now >= claim_deadline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liquidation(remaining_proceeds: u64) -> PropertyLiquidation {
+        PropertyLiquidation {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            sale_proceeds: remaining_proceeds,
+            reserve_swept: 0,
+            remaining_proceeds,
+            supply_at_liquidation: 3,
+            tokens_redeemed: 0,
+            started_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_calculate_payout_pro_rata() {
+        let liq = liquidation(1_000);
+        assert_eq!(liq.calculate_payout(1, 4).unwrap(), 250);
+        assert_eq!(liq.calculate_payout(4, 4).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_last_redeemer_receives_dust() {
+        let mut liq = liquidation(100);
+        let first = liq.calculate_payout(1, 3).unwrap();
+        liq.remaining_proceeds -= first;
+        let second = liq.calculate_payout(1, 2).unwrap();
+        liq.remaining_proceeds -= second;
+        let last = liq.calculate_payout(1, 1).unwrap();
+        assert_eq!(first + second + last, 100);
+    }
+
+    #[test]
+    fn test_close_after_claim_period() {
+        let liq = liquidation(100);
+        assert!(liq.can_close(0, 0));
+        assert!(!liq.can_close(1, LIQUIDATION_CLAIM_PERIOD_SECONDS - 1));
+        assert!(liq.can_close(1, LIQUIDATION_CLAIM_PERIOD_SECONDS));
+    }
+}
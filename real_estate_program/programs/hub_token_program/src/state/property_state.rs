@@ -2,6 +2,93 @@
 use anchor_lang::prelude::*;
 use super::PropertyDetails;
 
+/// Property lifecycle status
+///
+/// Allowed transitions:
+/// - Draft -> Fundraising | Closed
+/// - Fundraising -> Funded | Operating | Paused
+/// - Funded -> Operating | Paused
+/// - Operating -> Paused
+/// - Paused -> Fundraising | Funded | Operating
+/// - Fundraising | Funded | Operating | Paused -> Liquidating (liquidate_property)
+/// - Liquidating -> Closed (close_liquidated_property)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PropertyStatus {
+    /// Created, not yet open for investment
+    Draft,
+    /// Primary sale open (invest_in_property accepted)
+    Fundraising,
+    /// Primary sale complete, awaiting operation
+    Funded,
+    /// Generating revenue for token holders
+    Operating,
+    /// Temporarily halted by the authority
+    Paused,
+    /// Sold, distributing proceeds (terminal)
+    Liquidating,
+    /// Lifecycle finished
+    Closed,
+}
+
+impl PropertyStatus {
+    /// Whether the authority may move a property from `self` to `next`
+    ///
+    /// Liquidating is only entered through `liquidate_property`, and
+    /// Liquidating -> Closed only through `close_liquidated_property`.
+    pub fn can_transition_to(&self, next: PropertyStatus) -> bool {
+        use PropertyStatus::*;
+
+        matches!(
+            (self, next),
+            (Draft, Fundraising)
+                | (Draft, Closed)
+                | (Fundraising, Funded)
+                | (Fundraising, Operating)
+                | (Fundraising, Paused)
+                | (Funded, Operating)
+                | (Funded, Paused)
+                | (Operating, Paused)
+                | (Paused, Fundraising)
+                | (Paused, Funded)
+                | (Paused, Operating)
+        )
+    }
+
+    /// Primary investments via invest_in_property
+    pub fn allows_investment(&self) -> bool {
+        *self == PropertyStatus::Fundraising
+    }
+
+    /// Authority minting via mint_property_tokens
+    pub fn allows_minting(&self) -> bool {
+        matches!(
+            self,
+            PropertyStatus::Fundraising | PropertyStatus::Funded | PropertyStatus::Operating
+        )
+    }
+
+    /// Rental revenue deposits via deposit_revenue
+    pub fn allows_revenue(&self) -> bool {
+        matches!(self, PropertyStatus::Funded | PropertyStatus::Operating)
+    }
+
+    /// Secondary transfers via the transfer hook
+    pub fn allows_transfers(&self) -> bool {
+        matches!(
+            self,
+            PropertyStatus::Fundraising
+                | PropertyStatus::Funded
+                | PropertyStatus::Operating
+                | PropertyStatus::Paused
+        )
+    }
+
+    /// Whether the property can be put into liquidation
+    pub fn allows_liquidation(&self) -> bool {
+        self.allows_transfers()
+    }
+}
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct PropertyState {
@@ -34,8 +121,9 @@ pub struct PropertyState {
     /// Detailed property information
     pub details: PropertyDetails,
 
-    /// Whether minting is currently enabled
-    pub is_active: bool,
+    /// Current lifecycle status
+    /// Occupies the former `is_active` byte (0 = Draft, 1 = Fundraising)
+    pub status: PropertyStatus,
 
     /// Timestamp when property was tokenized
     pub created_at: i64,
@@ -45,17 +133,167 @@ pub struct PropertyState {
 
     /// PDA bump seed
     pub bump: u8,
+
+    // Fields below were appended after the first release; accounts created
+    // earlier read them as zero once reallocated by migrate_property_accounts
+
+    /// Layout version (0 = created before the appended fields)
+    pub version: u8,
+
+    /// Status to resume to when unpaused (only meaningful while Paused)
+    pub paused_from: PropertyStatus,
+
+    /// Emergency stop for secondary transfers (set by the platform guardian)
+    /// Independent of `status`: minting and investments are unaffected
+    pub transfers_paused: bool,
+
+    /// Tokens held in program escrow (burned from holders, re-minted on release)
+    /// Included in `circulating_supply`
+    pub escrowed_supply: u64,
+
+    /// Whether the mint has the TransferFeeConfig extension (secondary royalty)
+    pub transfer_fee_enabled: bool,
+
+    /// Share of harvested transfer fees retired from the supply (basis points)
+    pub transfer_fee_retired_share_bps: u16,
+
+    /// Tokens reserved for sponsor vesting schedules (claimed or not)
+    /// Not for sale to investors
+    pub sponsor_allocated_supply: u64,
+
+    /// Sponsor tokens already claimed (included in `circulating_supply`)
+    pub sponsor_claimed_supply: u64,
+
+    /// Tokens locked in staking positions (included in `escrowed_supply`)
+    pub staked_supply: u64,
+
+    /// Sum of the staking boosts (extra revenue weight above `staked_supply`)
+    pub staking_boost_weight: u64,
+
+    /// Tokens held in the liquidity pool vault (included in `circulating_supply`)
+    pub pool_supply: u64,
+
+    /// Tokens held in bundle baskets (included in `escrowed_supply`)
+    pub bundled_supply: u64,
+
+    /// Revenue epochs deposited so far
+    pub revenue_epoch_count: u64,
+
+    /// Timestamp of the latest revenue deposit
+    pub last_revenue_at: i64,
 }
 
 impl PropertyState {
+    /// Layout version written by create_property_mint and migrations
+    pub const CURRENT_VERSION: u8 = 1;
+
     /// Checks if more tokens can be minted
     pub fn can_mint(&self, amount: u64) -> bool {
-        self.is_active && self.circulating_supply + amount <= self.total_supply
+        self.status.allows_minting() && amount <= self.remaining_supply()
+    }
+
+    /// Move to a new lifecycle status, validating the transition
+    /// Returns the previous status
+    pub fn transition_to(&mut self, next: PropertyStatus) -> Result<PropertyStatus> {
+        require!(
+            self.status.can_transition_to(next),
+            crate::error::RwaError::InvalidStatusTransition
+        );
+
+        let previous = self.status;
+        if next == PropertyStatus::Paused {
+            self.paused_from = previous;
+        }
+        self.status = next;
+
+        Ok(previous)
+    }
+
+    /// Move from Fundraising to Funded once the whole supply is sold
+    /// Returns the previous status if a transition happened
+    pub fn mark_funded_if_sold_out(&mut self) -> Option<PropertyStatus> {
+        if self.status == PropertyStatus::Fundraising && self.remaining_supply() == 0 {
+            let previous = self.status;
+            self.status = PropertyStatus::Funded;
+            Some(previous)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that secondary transfers are currently allowed
+    ///
+    /// Applies the lifecycle status and both emergency pauses. Used by the
+    /// transfer hook and by program paths that move tokens without it.
+    pub fn ensure_transfers_allowed(&self, platform_config: &AccountInfo) -> Result<()> {
+        require!(
+            self.status.allows_transfers(),
+            crate::error::RwaError::TransfersNotAllowed
+        );
+
+        // Emergency pauses set by the platform guardian
+        require!(
+            !super::PlatformConfig::global_transfers_paused(platform_config)?,
+            crate::error::RwaError::GlobalTransfersPaused
+        );
+        require!(
+            !self.transfers_paused,
+            crate::error::RwaError::TransfersPaused
+        );
+
+        Ok(())
     }
 
     /// Returns the remaining tokens that can be minted
+    /// Unclaimed sponsor allocations are already spoken for
     pub fn remaining_supply(&self) -> u64 {
-        self.total_supply.saturating_sub(self.circulating_supply)
+        self.total_supply
+            .saturating_sub(self.circulating_supply)
+            .saturating_sub(self.unclaimed_sponsor_supply())
+    }
+
+    /// Sponsor tokens reserved but not yet claimed
+    pub fn unclaimed_sponsor_supply(&self) -> u64 {
+        self.sponsor_allocated_supply
+            .saturating_sub(self.sponsor_claimed_supply)
+    }
+
+    /// Tokens available to investors (total minus the sponsor allocation)
+    pub fn investor_supply(&self) -> u64 {
+        self.total_supply.saturating_sub(self.sponsor_allocated_supply)
+    }
+
+    /// Tokens in circulation that did not come from sponsor vesting
+    pub fn sold_supply(&self) -> u64 {
+        self.circulating_supply
+            .saturating_sub(self.sponsor_claimed_supply)
+    }
+
+    /// Total revenue weight: every token that can claim revenue plus staking
+    /// boosts. Order and collateral escrow and the pool vault have no claim
+    /// path, so they are left out; stakes and bundle baskets claim through
+    /// their own positions.
+    pub fn revenue_weight_supply(&self) -> u64 {
+        let claimable_escrow = self.staked_supply.saturating_add(self.bundled_supply);
+        let unclaimable_escrow = self.escrowed_supply.saturating_sub(claimable_escrow);
+
+        self.circulating_supply
+            .saturating_sub(unclaimable_escrow)
+            .saturating_sub(self.pool_supply)
+            .saturating_add(self.staking_boost_weight)
+    }
+
+    /// Investor-sold share of the investor supply in basis points
+    /// Used for seller escrow milestones
+    pub fn sold_bps(&self) -> u16 {
+        let investor_supply = self.investor_supply();
+        if investor_supply == 0 {
+            return 0;
+        }
+
+        (self.sold_supply() as u128 * crate::constants::BPS_DIVISOR as u128
+            / investor_supply as u128)
+            .min(crate::constants::BPS_DIVISOR as u128) as u16
     }
 
     /// Returns the percentage of tokens currently circulating
@@ -76,3 +314,124 @@ impl PropertyState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_lifecycle_transitions() {
+        use PropertyStatus::*;
+
+        assert!(Draft.can_transition_to(Fundraising));
+        assert!(Fundraising.can_transition_to(Funded));
+        assert!(Funded.can_transition_to(Operating));
+        assert!(Operating.can_transition_to(Paused));
+        assert!(Paused.can_transition_to(Operating));
+
+        assert!(!Draft.can_transition_to(Operating));
+        assert!(!Operating.can_transition_to(Fundraising));
+        assert!(!Operating.can_transition_to(Liquidating));
+        assert!(!Liquidating.can_transition_to(Operating));
+        assert!(!Closed.can_transition_to(Draft));
+    }
+
+    #[test]
+    fn test_status_gates() {
+        use PropertyStatus::*;
+
+        assert!(Fundraising.allows_investment());
+        assert!(!Funded.allows_investment());
+        assert!(Operating.allows_revenue());
+        assert!(!Fundraising.allows_revenue());
+        assert!(Paused.allows_transfers());
+        assert!(!Draft.allows_transfers());
+        assert!(!Liquidating.allows_transfers());
+    }
+
+    #[test]
+    fn test_sponsor_allocation_excluded_from_sold_supply() {
+        let mut state = PropertyState {
+            authority: Pubkey::new_unique(),
+            seller_wallet: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            property_name: String::new(),
+            property_symbol: String::new(),
+            total_supply: 1_000,
+            circulating_supply: 0,
+            details: PropertyDetails {
+                property_address: String::new(),
+                property_type: String::new(),
+                total_value_usd: 0,
+                rental_yield_bps: 0,
+                metadata_uri: String::new(),
+            },
+            status: PropertyStatus::Fundraising,
+            paused_from: PropertyStatus::Draft,
+            transfers_paused: false,
+            escrowed_supply: 0,
+            transfer_fee_enabled: false,
+            transfer_fee_retired_share_bps: 0,
+            sponsor_allocated_supply: 200,
+            sponsor_claimed_supply: 0,
+            staked_supply: 0,
+            staking_boost_weight: 0,
+            pool_supply: 0,
+            bundled_supply: 0,
+            revenue_epoch_count: 0,
+            last_revenue_at: 0,
+            created_at: 0,
+            updated_at: 0,
+            bump: 255,
+            version: PropertyState::CURRENT_VERSION,
+        };
+
+        assert_eq!(state.remaining_supply(), 800);
+
+        // 400 sold to investors, 100 claimed by the sponsor
+        state.circulating_supply = 500;
+        state.sponsor_claimed_supply = 100;
+        assert_eq!(state.remaining_supply(), 400);
+        assert_eq!(state.sold_supply(), 400);
+        assert_eq!(state.sold_bps(), 5_000);
+    }
+
+    #[test]
+    fn test_first_release_layout_reads_after_realloc() {
+        let details = PropertyDetails {
+            property_address: String::from("Rua Augusta 100"),
+            property_type: String::from("residential"),
+            total_value_usd: 1_000_000,
+            rental_yield_bps: 600,
+            metadata_uri: String::new(),
+        };
+
+        // Account written by the first release: ..., details, is_active, created_at, updated_at, bump
+        let mut data = PropertyState::DISCRIMINATOR.to_vec();
+        for key in [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()] {
+            key.serialize(&mut data).unwrap();
+        }
+        String::from("Edificio Augusta").serialize(&mut data).unwrap();
+        String::from("AUG").serialize(&mut data).unwrap();
+        1_000u64.serialize(&mut data).unwrap();
+        400u64.serialize(&mut data).unwrap();
+        details.serialize(&mut data).unwrap();
+        true.serialize(&mut data).unwrap();
+        10i64.serialize(&mut data).unwrap();
+        20i64.serialize(&mut data).unwrap();
+        254u8.serialize(&mut data).unwrap();
+
+        // migrate_property_accounts grows the account with zeroes
+        data.resize(8 + PropertyState::INIT_SPACE, 0);
+
+        let state = PropertyState::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(state.status, PropertyStatus::Fundraising);
+        assert_eq!(state.circulating_supply, 400);
+        assert_eq!(state.details.total_value_usd, 1_000_000);
+        assert_eq!(state.bump, 254);
+        assert_eq!(state.version, 0);
+        assert_eq!(state.escrowed_supply, 0);
+        assert!(!state.transfer_fee_enabled);
+    }
+}
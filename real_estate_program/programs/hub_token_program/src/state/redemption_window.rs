@@ -0,0 +1,99 @@
+/// Redemption window state for reserve-funded token buybacks
+use anchor_lang::prelude::*;
+
+/// A time-boxed buyback funded by the property's reserve fund
+/// - Budget: Moved from the reserve fund into this PDA when the window opens
+/// - Cap: Maximum tokens bought back, served first-come first-served
+///
+/// Unspent budget returns to the reserve fund when the window is closed.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct RedemptionWindow {
+    /// The PropertyState this window belongs to
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Price paid per whole token (in lamports)
+    pub price_per_token: u64,
+
+    /// Budget drawn from the reserve fund (in lamports)
+    pub budget: u64,
+
+    /// Amount paid out so far (in lamports)
+    pub spent: u64,
+
+    /// Maximum number of tokens that can be redeemed in this window
+    pub max_tokens: u64,
+
+    /// Tokens redeemed (burned) so far
+    pub tokens_redeemed: u64,
+
+    /// Timestamp when the window opened
+    pub opens_at: i64,
+
+    /// Timestamp after which redemptions are rejected
+    pub closes_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RedemptionWindow {
+    /// Whether redemptions are accepted at the given time
+    pub fn is_open(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.opens_at && current_timestamp < self.closes_at
+    }
+
+    /// Remaining budget (in lamports)
+    pub fn remaining_budget(&self) -> u64 {
+        self.budget.saturating_sub(self.spent)
+    }
+
+    /// Remaining tokens under the window cap
+    pub fn remaining_tokens(&self) -> u64 {
+        self.max_tokens.saturating_sub(self.tokens_redeemed)
+    }
+
+    /// Calculate the payout for `amount` token base units
+    /// `payout = amount * price_per_token / 10^decimals`
+    pub fn calculate_payout(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let unit = 10u128
+            .checked_pow(decimals as u32)
+            .ok_or(error!(crate::error::RwaError::MathOverflow))?;
+
+        let payout = (amount as u128)
+            .checked_mul(self.price_per_token as u128)
+            .and_then(|v| v.checked_div(unit))
+            .ok_or(error!(crate::error::RwaError::MathOverflow))?;
+
+        u64::try_from(payout).map_err(|_| error!(crate::error::RwaError::MathOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_payout_uses_whole_token_price() {
+        let window = RedemptionWindow {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            price_per_token: 2_000_000,
+            budget: 10_000_000,
+            spent: 0,
+            max_tokens: 5_000_000,
+            tokens_redeemed: 0,
+            opens_at: 100,
+            closes_at: 200,
+            bump: 255,
+        };
+
+        // 1.5 tokens at 6 decimals
+        assert_eq!(window.calculate_payout(1_500_000, 6).unwrap(), 3_000_000);
+        assert!(window.is_open(100));
+        assert!(!window.is_open(200));
+    }
+}
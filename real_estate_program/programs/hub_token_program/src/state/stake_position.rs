@@ -0,0 +1,169 @@
+/// Staking position state for boosted revenue share
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// A holder's locked stake in one property
+/// - Tokens: Held in program escrow (`PropertyState::escrowed_supply`)
+/// - Multiplier: Grows linearly with the lock duration, up to
+///   `MAX_STAKE_BOOST_BPS` on top of 1x at `MAX_STAKE_LOCK_SECONDS`
+/// - Governance: Voting weight is the stake held at the proposal snapshot
+///   (see `voting_weight`); voting extends `votes_locked_until` to the end
+///   of the voting period
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct StakePosition {
+    /// The PropertyState this stake belongs to
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Staker wallet
+    pub owner: Pubkey,
+
+    /// Tokens staked (base units)
+    pub amount: u64,
+
+    /// Lock duration chosen by the staker (seconds)
+    pub lock_duration: i64,
+
+    /// Revenue weight multiplier in basis points (10000 = 1x)
+    pub multiplier_bps: u16,
+
+    /// Timestamp of the last stake (revenue epochs deposited at or before
+    /// it only count `prior_amount`, unboosted)
+    pub staked_at: i64,
+
+    /// Amount staked before the last stake
+    pub prior_amount: u64,
+
+    /// Timestamp of the stake before the last one (held since then)
+    pub prior_staked_at: i64,
+
+    /// Timestamp when the tokens can be unstaked
+    pub unlock_at: i64,
+
+    /// Timestamp until which the stake backs an open vote
+    pub votes_locked_until: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakePosition {
+    /// Multiplier for a lock duration
+    pub fn multiplier_for(lock_duration: i64) -> Result<u16> {
+        require!(
+            (MIN_STAKE_LOCK_SECONDS..=MAX_STAKE_LOCK_SECONDS).contains(&lock_duration),
+            RwaError::InvalidStakeLock
+        );
+
+        let boost = MAX_STAKE_BOOST_BPS as i128 * lock_duration as i128
+            / MAX_STAKE_LOCK_SECONDS as i128;
+        Ok((BPS_DIVISOR as i128 + boost) as u16)
+    }
+
+    /// Extra revenue weight above the staked amount
+    pub fn boost_weight(&self) -> u64 {
+        let extra_bps = (self.multiplier_bps as u64).saturating_sub(BPS_DIVISOR);
+        (self.amount as u128 * extra_bps as u128 / BPS_DIVISOR as u128) as u64
+    }
+
+    /// Revenue weight for an epoch deposited at `deposited_at`
+    ///
+    /// Only tokens staked before the deposit earn it, so a stake opened
+    /// right after a deposit cannot claim rent earned before it. If the
+    /// stake grew since the deposit, only the earlier amount counts.
+    pub fn revenue_weight(&self, deposited_at: i64) -> u64 {
+        if self.staked_at < deposited_at {
+            self.amount.saturating_add(self.boost_weight())
+        } else if self.prior_staked_at < deposited_at {
+            self.prior_amount
+        } else {
+            0
+        }
+    }
+
+    /// Voting weight for a proposal snapshotted at `snapshot_at`
+    ///
+    /// Only tokens staked before the snapshot count. If the stake grew
+    /// twice since then, the balance at the snapshot is unknown and the
+    /// position does not vote.
+    pub fn voting_weight(&self, snapshot_at: i64) -> u64 {
+        if self.staked_at < snapshot_at {
+            self.amount
+        } else if self.prior_staked_at < snapshot_at {
+            self.prior_amount
+        } else {
+            0
+        }
+    }
+
+    /// Whether the lock and any open votes have expired
+    pub fn can_unstake(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.unlock_at && current_timestamp >= self.votes_locked_until
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boosted_weights_and_lock() {
+        let multiplier = StakePosition::multiplier_for(MAX_STAKE_LOCK_SECONDS / 2).unwrap();
+        assert_eq!(multiplier as u64, BPS_DIVISOR + MAX_STAKE_BOOST_BPS as u64 / 2);
+        assert!(StakePosition::multiplier_for(MIN_STAKE_LOCK_SECONDS - 1).is_err());
+        assert!(StakePosition::multiplier_for(MAX_STAKE_LOCK_SECONDS + 1).is_err());
+
+        let position = StakePosition {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1_000,
+            lock_duration: MAX_STAKE_LOCK_SECONDS,
+            multiplier_bps: 15_000,
+            staked_at: 100,
+            prior_amount: 400,
+            prior_staked_at: 50,
+            unlock_at: 500,
+            votes_locked_until: 600,
+            bump: 255,
+        };
+
+        assert_eq!(position.boost_weight(), 500);
+        assert_eq!(position.revenue_weight(101), 1_500);
+        assert_eq!(position.voting_weight(101), 1_000);
+        assert_eq!(position.voting_weight(100), 400);
+        assert_eq!(position.voting_weight(50), 0);
+        assert!(!position.can_unstake(500));
+        assert!(position.can_unstake(600));
+    }
+
+    #[test]
+    fn test_stake_after_deposit_earns_nothing() {
+        let position = StakePosition {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 1_000,
+            lock_duration: MAX_STAKE_LOCK_SECONDS,
+            multiplier_bps: 15_000,
+            staked_at: 100,
+            prior_amount: 400,
+            prior_staked_at: 50,
+            unlock_at: 500,
+            votes_locked_until: 0,
+            bump: 255,
+        };
+
+        // Topped up at or after the deposit: only the earlier stake counts
+        assert_eq!(position.revenue_weight(100), 400);
+        assert_eq!(position.revenue_weight(51), 400);
+
+        // Opened at or after the deposit: no weight at all
+        assert_eq!(position.revenue_weight(50), 0);
+        assert_eq!(position.revenue_weight(10), 0);
+    }
+}
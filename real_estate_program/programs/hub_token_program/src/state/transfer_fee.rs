@@ -0,0 +1,44 @@
+/// Secondary-trade royalty configuration (Token-2022 TransferFeeConfig)
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::RwaError};
+
+/// Transfer fee set at property creation (updated with set_transfer_fee)
+/// Fees are withheld in the recipient's token account by Token-2022 and
+/// harvested periodically: part goes to the platform treasury, the rest is
+/// retired (burned and removed from the supply) for the remaining holders.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug)]
+pub struct TransferFeeParams {
+    /// Fee charged on every transfer in basis points
+    /// Example: 50 = 0.5%
+    pub fee_bps: u16,
+
+    /// Maximum fee per transfer (in token base units)
+    pub max_fee: u64,
+
+    /// Share of harvested fees retired (basis points)
+    /// The rest goes to the platform treasury
+    pub retired_share_bps: u16,
+}
+
+impl TransferFeeParams {
+    /// Validates the fee configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_bps > 0 && self.fee_bps <= MAX_TRANSFER_FEE_BPS,
+            RwaError::InvalidTransferFee
+        );
+        require!(
+            self.retired_share_bps as u64 <= BPS_DIVISOR,
+            RwaError::InvalidTransferFee
+        );
+
+        Ok(())
+    }
+
+    /// Splits a harvested amount into (platform share, retired share)
+    pub fn split(amount: u64, retired_share_bps: u16) -> (u64, u64) {
+        let retired_share = (amount as u128 * retired_share_bps as u128 / BPS_DIVISOR as u128) as u64;
+        (amount - retired_share, retired_share)
+    }
+}
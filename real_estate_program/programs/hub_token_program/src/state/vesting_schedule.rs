@@ -0,0 +1,107 @@
+/// Sponsor/team vesting schedule state
+use anchor_lang::prelude::*;
+
+use crate::error::RwaError;
+
+/// Tokens reserved for a sponsor with cliff and linear release
+/// - Reserved: Counted against `total_supply` at creation, not minted
+/// - Claimed: Minted to the beneficiary as they vest
+///
+/// Nothing vests before `cliff_at`; after it the vested amount grows
+/// linearly from `start_at` until the full amount at `end_at`.
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct VestingSchedule {
+    /// The PropertyState this schedule belongs to
+    pub property_state: Pubkey,
+
+    /// The property mint
+    pub mint: Pubkey,
+
+    /// Sponsor wallet receiving the tokens
+    pub beneficiary: Pubkey,
+
+    /// Total tokens reserved (base units)
+    pub total_amount: u64,
+
+    /// Tokens already claimed (base units)
+    pub claimed_amount: u64,
+
+    /// Timestamp when linear vesting starts
+    pub start_at: i64,
+
+    /// Timestamp before which nothing can be claimed
+    pub cliff_at: i64,
+
+    /// Timestamp when everything is vested
+    pub end_at: i64,
+
+    /// Timestamp when the schedule was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    /// Validates the schedule parameters
+    pub fn validate(total_amount: u64, start_at: i64, cliff_at: i64, end_at: i64) -> Result<()> {
+        require!(
+            total_amount > 0 && start_at <= cliff_at && cliff_at <= end_at && start_at < end_at,
+            RwaError::InvalidVestingSchedule
+        );
+
+        Ok(())
+    }
+
+    /// Tokens vested at the given time (claimed or not)
+    pub fn vested_amount(&self, current_timestamp: i64) -> u64 {
+        if current_timestamp < self.cliff_at {
+            0
+        } else if current_timestamp >= self.end_at {
+            self.total_amount
+        } else {
+            let elapsed = (current_timestamp - self.start_at) as u128;
+            let duration = (self.end_at - self.start_at) as u128;
+            (self.total_amount as u128 * elapsed / duration) as u64
+        }
+    }
+
+    /// Tokens vested but not yet claimed
+    pub fn claimable_amount(&self, current_timestamp: i64) -> u64 {
+        self.vested_amount(current_timestamp)
+            .saturating_sub(self.claimed_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cliff_and_linear_release() {
+        let schedule = VestingSchedule {
+            property_state: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            total_amount: 1_000,
+            claimed_amount: 250,
+            start_at: 0,
+            cliff_at: 100,
+            end_at: 400,
+            created_at: 0,
+            bump: 255,
+        };
+
+        assert_eq!(schedule.vested_amount(99), 0);
+        assert_eq!(schedule.vested_amount(100), 250);
+        assert_eq!(schedule.claimable_amount(100), 0);
+        assert_eq!(schedule.claimable_amount(200), 250);
+        assert_eq!(schedule.vested_amount(400), 1_000);
+        assert_eq!(schedule.claimable_amount(1_000), 750);
+
+        assert!(VestingSchedule::validate(1_000, 0, 100, 400).is_ok());
+        assert!(VestingSchedule::validate(1_000, 0, 500, 400).is_err());
+        assert!(VestingSchedule::validate(0, 0, 100, 400).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+/// Property token transfers that go through the transfer hook
+///
+/// This program cannot `transfer_checked` its own hooked mint (see
+/// `token_escrow`), so a trade that must pass the hook is split in two
+/// top-level instructions of the same transaction: the trader's own
+/// Token-2022 `TransferChecked` and the program instruction that settles the
+/// other leg. The program instruction reads the instructions sysvar to make
+/// sure the transfer is there and matches exactly.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+        },
+        instruction::TokenInstruction,
+        state::Mint as MintState,
+    },
+    token_interface::Mint,
+};
+
+use crate::error::RwaError;
+
+/// Checks that the instruction at `relative_index` (from the current one) is a
+/// Token-2022 `TransferChecked` of exactly `amount` tokens of `mint` from
+/// `source` to `destination`, signed by `authority`
+pub fn require_hooked_transfer(
+    instructions_sysvar: &AccountInfo,
+    relative_index: i64,
+    mint: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let instruction = get_instruction_relative(relative_index, instructions_sysvar)
+        .map_err(|_| RwaError::InvalidPoolTransfer)?;
+    require_keys_eq!(
+        instruction.program_id,
+        spl_token_2022::ID,
+        RwaError::InvalidPoolTransfer
+    );
+
+    let transferred = match TokenInstruction::unpack(&instruction.data) {
+        Ok(TokenInstruction::TransferChecked { amount, .. }) => amount,
+        _ => return Err(RwaError::InvalidPoolTransfer.into()),
+    };
+    require!(transferred == amount, RwaError::InvalidPoolTransfer);
+
+    // Accounts: source, mint, destination, authority, then the hook's extras
+    let keys: Vec<&Pubkey> = instruction
+        .accounts
+        .iter()
+        .take(4)
+        .map(|meta| &meta.pubkey)
+        .collect();
+    require!(
+        keys == [source, mint, destination, authority],
+        RwaError::InvalidPoolTransfer
+    );
+
+    Ok(())
+}
+
+/// Transfer fee Token-2022 withholds from a transfer of `amount` tokens
+/// (zero for mints without the TransferFeeConfig extension)
+pub fn transfer_fee_for(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or_else(|| RwaError::MathOverflow.into()),
+        Err(_) => Ok(0),
+    }
+}
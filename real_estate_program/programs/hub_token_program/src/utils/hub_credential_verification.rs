@@ -3,155 +3,42 @@
 /// This module provides KYC verification using the Hub Credential Protocol.
 /// Hub Credential is the primary KYC verification method for Hub Token transfers.
 ///
-/// Hub Credential Protocol stores credentials as on-chain PDAs with the following data:
-/// - user: Pubkey (32 bytes)
-/// - issuer: Pubkey (32 bytes)
-/// - credential_type: CredentialType enum (1 byte)
-/// - status: CredentialStatus enum (1 byte)
-/// - issued_at: i64 (8 bytes)
-/// - expires_at: i64 (8 bytes)
-/// - metadata: String (4 + variable length)
-/// - bump: u8 (1 byte)
-use anchor_lang::prelude::*;
+/// Account types, PDA derivation and validity rules come from the
+/// `credential_program` crate (built with `cpi`), so the hub reads credentials
+/// with exactly the layout and semantics the issuing program writes them with.
+use anchor_lang::{prelude::*, Discriminator};
+use credential_program::state::{CredentialStatus, CredentialType, UserCredential};
+pub use credential_program::state::{
+    is_country_code, CredentialAttributes, InvestorTier, RiskBand,
+};
 
 use crate::{constants::HUB_CREDENTIAL_PROGRAM_ID, error::RwaError, events::*};
 
-/// Credential Type enum matching the Hub Credential Protocol
-#[derive(Clone, Copy, Debug, PartialEq)]
-#[repr(u8)]
-pub enum HubCredentialType {
-    KycBasic = 0,
-    KycFull = 1,
-    AccreditedInvestor = 2,
-    QualifiedPurchaser = 3,
-    BrazilianCpf = 4,
-    BrazilianCnpj = 5,
-}
-
-impl HubCredentialType {
-    fn from_u8(value: u8) -> Option<Self> {
-        match value {
-            0 => Some(HubCredentialType::KycBasic),
-            1 => Some(HubCredentialType::KycFull),
-            2 => Some(HubCredentialType::AccreditedInvestor),
-            3 => Some(HubCredentialType::QualifiedPurchaser),
-            4 => Some(HubCredentialType::BrazilianCpf),
-            5 => Some(HubCredentialType::BrazilianCnpj),
-            _ => None,
-        }
-    }
-}
-
-/// Credential Status enum matching the Hub Credential Protocol
-#[derive(Clone, Copy, Debug, PartialEq)]
-#[repr(u8)]
-pub enum HubCredentialStatus {
-    Active = 0,
-    Expired = 1,
-    Revoked = 2,
-    Suspended = 3,
-}
-
-impl HubCredentialStatus {
-    fn from_u8(value: u8) -> Option<Self> {
-        match value {
-            0 => Some(HubCredentialStatus::Active),
-            1 => Some(HubCredentialStatus::Expired),
-            2 => Some(HubCredentialStatus::Revoked),
-            3 => Some(HubCredentialStatus::Suspended),
-            _ => None,
-        }
-    }
-}
-
-/// Hub Credential data structure (partial, only what we need)
-pub struct HubCredential {
-    pub user: Pubkey,
-    pub issuer: Pubkey,
-    pub credential_type: HubCredentialType,
-    pub status: HubCredentialStatus,
-    pub issued_at: i64,
-    pub expires_at: i64,
-}
-
-impl HubCredential {
-    /// Minimum size of credential account
-    /// 8 (discriminator) + 32 (user) + 32 (issuer) + 1 (type) + 1 (status) + 8 (issued_at) + 8 (expires_at) = 90 bytes
-    pub const MIN_SIZE: usize = 90;
-
-    /// Parse credential from account data
-    pub fn parse(data: &[u8]) -> Result<Self> {
-        if data.len() < Self::MIN_SIZE {
-            msg!("Hub Credential data too small: {} bytes", data.len());
-            return Err(RwaError::KycVerificationRequired.into());
-        }
+/// Credential Type enum of the Hub Credential Protocol
+pub type HubCredentialType = CredentialType;
 
-        // Skip 8-byte Anchor discriminator
-        let offset = 8;
-
-        // user: Pubkey at offset 8
-        let user = Pubkey::try_from(&data[offset..offset + 32])
-            .map_err(|_| RwaError::KycVerificationRequired)?;
-
-        // issuer: Pubkey at offset 40
-        let issuer = Pubkey::try_from(&data[offset + 32..offset + 64])
-            .map_err(|_| RwaError::KycVerificationRequired)?;
-
-        // credential_type: u8 at offset 72
-        let type_byte = data[offset + 64];
-        let credential_type = HubCredentialType::from_u8(type_byte)
-            .ok_or(RwaError::KycVerificationRequired)?;
-
-        // status: u8 at offset 73
-        let status_byte = data[offset + 65];
-        let status = HubCredentialStatus::from_u8(status_byte)
-            .ok_or(RwaError::KycVerificationRequired)?;
-
-        // issued_at: i64 at offset 74
-        let issued_at_bytes: [u8; 8] = data[offset + 66..offset + 74]
-            .try_into()
-            .map_err(|_| RwaError::KycVerificationRequired)?;
-        let issued_at = i64::from_le_bytes(issued_at_bytes);
-
-        // expires_at: i64 at offset 82
-        let expires_at_bytes: [u8; 8] = data[offset + 74..offset + 82]
-            .try_into()
-            .map_err(|_| RwaError::KycVerificationRequired)?;
-        let expires_at = i64::from_le_bytes(expires_at_bytes);
-
-        Ok(HubCredential {
-            user,
-            issuer,
-            credential_type,
-            status,
-            issued_at,
-            expires_at,
-        })
-    }
-}
+/// Credential Status enum of the Hub Credential Protocol
+pub type HubCredentialStatus = CredentialStatus;
 
-/// Verifies that a wallet has a valid Hub Credential
-///
-/// # Arguments
-/// * `credential_account` - The Hub Credential account
-/// * `wallet` - The wallet address to verify
-/// * `property_mint` - The property token mint (for event logging)
-///
-/// # Returns
-/// * `Result<()>` - Ok if wallet has valid credential, Error otherwise
+/// Deserialize a Hub Credential and check it is valid for `wallet` at `current_time`
 ///
 /// # Verification Steps
 /// 1. Verify the account is owned by the Hub Credential program
-/// 2. Parse and verify the credential belongs to the wallet
-/// 3. Verify the credential is not expired
-/// 4. Verify the credential status is Active
-pub fn verify_hub_credential(
+/// 2. Deserialize and verify the credential belongs to the wallet
+/// 3. Verify the effective status (expiry, lapsed suspensions) is Active
+///
+/// # Issuer Status
+/// The issuer and network accounts are not read: the transfer hook resolves a
+/// credential PDA for every type, including ones the wallet does not hold, and
+/// an issuer PDA seeded from a missing credential cannot be resolved. Credentials
+/// of a deactivated issuer therefore stay valid here until the credential
+/// program's `revoke_issuer_credentials` cascade revokes them; deactivating an
+/// issuer must always be followed by that cascade.
+pub fn check_hub_credential(
     credential_account: &AccountInfo,
     wallet: &Pubkey,
-    _property_mint: &Pubkey,
-) -> Result<()> {
-    msg!("Verifying Hub Credential for wallet: {}", wallet);
-
+    current_time: i64,
+) -> Result<UserCredential> {
     // 1. Verify account owner is the Hub Credential program
     if credential_account.owner != &HUB_CREDENTIAL_PROGRAM_ID {
         msg!(
@@ -162,41 +49,54 @@ pub fn verify_hub_credential(
         return Err(RwaError::KycVerificationRequired.into());
     }
 
-    // 2. Parse the credential
+    // 2. Deserialize the credential
     let data = credential_account.try_borrow_data()?;
-    let credential = HubCredential::parse(&data)?;
+    let credential = UserCredential::try_deserialize(&mut &data[..])
+        .map_err(|_| RwaError::KycVerificationRequired)?;
 
-    // 3. Verify the credential belongs to the wallet
-    if credential.user != *wallet {
+    if credential.holder != *wallet {
         msg!(
             "Credential owner mismatch: expected {}, got {}",
             wallet,
-            credential.user
+            credential.holder
         );
         return Err(RwaError::KycVerificationRequired.into());
     }
 
-    // 4. Verify credential status is Active
-    if credential.status != HubCredentialStatus::Active {
-        msg!("Credential not active: status = {:?}", credential.status);
-        return Err(match credential.status {
-            HubCredentialStatus::Revoked => RwaError::CredentialRevoked.into(),
-            HubCredentialStatus::Suspended => RwaError::CredentialSuspended.into(),
-            HubCredentialStatus::Expired => RwaError::CredentialExpired.into(),
-            _ => RwaError::KycVerificationRequired.into(),
-        });
+    // 3. Verify credential is currently active
+    match credential.effective_status(current_time) {
+        CredentialStatus::Active => Ok(credential),
+        status => {
+            msg!("Credential not active: status = {:?}", status);
+            Err(match status {
+                CredentialStatus::Revoked => RwaError::CredentialRevoked.into(),
+                CredentialStatus::Suspended => RwaError::CredentialSuspended.into(),
+                _ => RwaError::CredentialExpired.into(),
+            })
+        }
     }
+}
+
+/// Verifies that a wallet has a valid Hub Credential
+///
+/// # Arguments
+/// * `credential_account` - The Hub Credential account
+/// * `wallet` - The wallet address to verify
+/// * `property_mint` - The property token mint (for event logging)
+///
+/// # Returns
+/// * `Result<UserCredential>` - The credential (type and compliance attributes) if valid
+///
+/// See `check_hub_credential` for the verification steps
+pub fn verify_hub_credential(
+    credential_account: &AccountInfo,
+    wallet: &Pubkey,
+    _property_mint: &Pubkey,
+) -> Result<UserCredential> {
+    msg!("Verifying Hub Credential for wallet: {}", wallet);
 
-    // 5. Check expiration
     let current_time = Clock::get()?.unix_timestamp;
-    if current_time >= credential.expires_at {
-        msg!(
-            "Credential expired at {}, current time: {}",
-            credential.expires_at,
-            current_time
-        );
-        return Err(RwaError::CredentialExpired.into());
-    }
+    let credential = check_hub_credential(credential_account, wallet, current_time)?;
 
     // Emit success event for audit trail
     emit!(HubCredentialVerified {
@@ -212,41 +112,104 @@ pub fn verify_hub_credential(
         credential.credential_type
     );
 
-    Ok(())
+    Ok(credential)
 }
 
-/// Derive the Hub Credential PDA for a wallet
+/// Verifies that a wallet holds at least one valid Hub Credential accepted by a policy
+///
+/// Wallets hold one credential per type, so a policy (e.g. a funding round that
+/// requires accredited investors) may be satisfied by any of several accounts.
+///
+/// # Arguments
+/// * `credential_accounts` - Candidate Hub Credential accounts, checked in order
+/// * `wallet` - The wallet address to verify
+/// * `property_mint` - The property token mint (for event logging)
+/// * `accepted_types` - Mask of accepted `HubCredentialType`s (see `HubCredentialType::bit`)
+///
+/// # Returns
+/// * `Result<UserCredential>` - The first valid accepted credential
+pub fn verify_any_hub_credential(
+    credential_accounts: &[AccountInfo],
+    wallet: &Pubkey,
+    property_mint: &Pubkey,
+    accepted_types: u8,
+) -> Result<UserCredential> {
+    let mut last_error: Option<Error> = None;
+
+    for credential_account in credential_accounts {
+        // Skip credentials of types the policy does not accept
+        if hub_credential_exists(credential_account) {
+            let data = credential_account.try_borrow_data()?;
+            let accepted = UserCredential::try_deserialize(&mut &data[..])
+                .map(|credential| credential.credential_type.bit() & accepted_types != 0)
+                .unwrap_or(false);
+            if !accepted {
+                last_error.get_or_insert(RwaError::CredentialTypeNotAllowed.into());
+                continue;
+            }
+        }
+
+        match verify_hub_credential(credential_account, wallet, property_mint) {
+            Ok(credential) => return Ok(credential),
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| RwaError::KycVerificationRequired.into()))
+}
+
+/// Derive the Hub Credential PDA for a wallet and credential type
 ///
 /// Credential PDAs are derived from:
 /// - "credential" literal
 /// - The wallet address
+/// - The credential type byte
 ///
-/// Seeds: ["credential", wallet]
-pub fn derive_hub_credential_pda(wallet: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(
-        &[b"credential", wallet.as_ref()],
-        &HUB_CREDENTIAL_PROGRAM_ID,
-    )
+/// Seeds: ["credential", wallet, credential_type]
+pub fn derive_hub_credential_pda(
+    wallet: &Pubkey,
+    credential_type: HubCredentialType,
+) -> (Pubkey, u8) {
+    UserCredential::find_address(wallet, credential_type)
 }
 
 /// Check if a Hub Credential exists for a wallet (without full verification)
 pub fn hub_credential_exists(credential_account: &AccountInfo) -> bool {
-    credential_account.data_len() >= HubCredential::MIN_SIZE
-        && credential_account.owner == &HUB_CREDENTIAL_PROGRAM_ID
+    credential_account.owner == &HUB_CREDENTIAL_PROGRAM_ID
+        && credential_account
+            .try_borrow_data()
+            .map(|data| data.starts_with(&UserCredential::DISCRIMINATOR))
+            .unwrap_or(false)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::ANY_HUB_CREDENTIAL_TYPE;
 
     #[test]
     fn test_derive_hub_credential_pda() {
         let wallet = Pubkey::new_unique();
-        let (pda, _bump) = derive_hub_credential_pda(&wallet);
+        let (pda, _bump) = derive_hub_credential_pda(&wallet, HubCredentialType::KycBasic);
 
         // Verify PDA is deterministic
-        let (pda2, _) = derive_hub_credential_pda(&wallet);
+        let (pda2, _) = derive_hub_credential_pda(&wallet, HubCredentialType::KycBasic);
         assert_eq!(pda, pda2);
+
+        // Each credential type has its own PDA
+        let (accredited, _) =
+            derive_hub_credential_pda(&wallet, HubCredentialType::AccreditedInvestor);
+        assert_ne!(pda, accredited);
+    }
+
+    #[test]
+    fn test_credential_type_mask() {
+        assert_eq!(HubCredentialType::KycBasic.bit(), 0b0000_0001);
+        assert_eq!(HubCredentialType::BrazilianCnpj.bit(), 0b0010_0000);
+        assert_eq!(
+            HubCredentialType::AccreditedInvestor.bit() & ANY_HUB_CREDENTIAL_TYPE,
+            HubCredentialType::AccreditedInvestor.bit()
+        );
     }
 
     #[test]
@@ -266,16 +229,193 @@ mod tests {
         assert_eq!(HubCredentialType::from_u8(10), None);
     }
 
+    const NOW: i64 = 1_700_000_000;
+
+    fn credential(holder: Pubkey) -> UserCredential {
+        UserCredential {
+            holder,
+            issuer: Pubkey::new_unique(),
+            credential_type: HubCredentialType::AccreditedInvestor,
+            status: HubCredentialStatus::Active,
+            issued_at: NOW - 86_400,
+            expires_at: NOW + 86_400,
+            last_verified_at: NOW - 86_400,
+            metadata_uri: String::from("ipfs://metadata"),
+            revocation_reason: String::new(),
+            suspended_until: 0,
+            suspension_reason: String::new(),
+            attributes: CredentialAttributes {
+                country_of_residence: *b"BR",
+                tax_residency: *b"BR",
+                investor_tier: InvestorTier::Qualified,
+                risk_band: RiskBand::Low,
+                is_pep: false,
+                investment_limit: Some(1_000_000_000),
+            },
+            commitment: [0; 32],
+            version: UserCredential::CURRENT_VERSION,
+            bump: 255,
+        }
+    }
+
+    /// Serialize a credential the way the credential program stores it and
+    /// run it through the hub verification path
+    fn check(
+        credential: &UserCredential,
+        owner: Pubkey,
+        wallet: &Pubkey,
+    ) -> Result<UserCredential> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let mut data = Vec::new();
+        credential.try_serialize(&mut data).unwrap();
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        check_hub_credential(&account, wallet, NOW)
+    }
+
     #[test]
-    fn test_credential_status_from_u8() {
+    fn test_check_valid_credential() {
+        let wallet = Pubkey::new_unique();
+        let credential = credential(wallet);
+
+        let verified = check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).unwrap();
+        assert_eq!(verified.holder, wallet);
+        assert_eq!(verified.issuer, credential.issuer);
         assert_eq!(
-            HubCredentialStatus::from_u8(0),
-            Some(HubCredentialStatus::Active)
+            verified.credential_type,
+            HubCredentialType::AccreditedInvestor
         );
+        assert_eq!(verified.attributes, credential.attributes);
+    }
+
+    #[test]
+    fn test_check_credential_without_expiry() {
+        let wallet = Pubkey::new_unique();
+        let mut credential = credential(wallet);
+        credential.expires_at = 0;
+
+        assert!(check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).is_ok());
+    }
+
+    #[test]
+    fn test_check_expired_credential() {
+        let wallet = Pubkey::new_unique();
+        let mut credential = credential(wallet);
+        credential.expires_at = NOW - 1;
+
         assert_eq!(
-            HubCredentialStatus::from_u8(2),
-            Some(HubCredentialStatus::Revoked)
+            check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).err(),
+            Some(RwaError::CredentialExpired.into())
+        );
+    }
+
+    #[test]
+    fn test_check_suspended_credential() {
+        let wallet = Pubkey::new_unique();
+        let mut credential = credential(wallet);
+        credential.status = HubCredentialStatus::Suspended;
+
+        assert_eq!(
+            check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).err(),
+            Some(RwaError::CredentialSuspended.into())
+        );
+
+        // A lapsed suspension reads as reinstated
+        credential.suspended_until = NOW - 1;
+        assert!(check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).is_ok());
+    }
+
+    #[test]
+    fn test_check_revoked_credential() {
+        let wallet = Pubkey::new_unique();
+        let mut credential = credential(wallet);
+        credential.status = HubCredentialStatus::Revoked;
+
+        assert_eq!(
+            check(&credential, HUB_CREDENTIAL_PROGRAM_ID, &wallet).err(),
+            Some(RwaError::CredentialRevoked.into())
+        );
+    }
+
+    #[test]
+    fn test_check_rejects_wrong_owner_or_holder() {
+        let wallet = Pubkey::new_unique();
+        let credential = credential(wallet);
+
+        assert_eq!(
+            check(&credential, Pubkey::new_unique(), &wallet).err(),
+            Some(RwaError::KycVerificationRequired.into())
+        );
+        assert_eq!(
+            check(
+                &credential,
+                HUB_CREDENTIAL_PROGRAM_ID,
+                &Pubkey::new_unique()
+            )
+            .err(),
+            Some(RwaError::KycVerificationRequired.into())
+        );
+    }
+
+    #[test]
+    fn test_first_release_credential_reads_after_realloc() {
+        let wallet = Pubkey::new_unique();
+
+        // Account written by the first release: ..., revocation_reason, version, bump
+        let mut data = UserCredential::DISCRIMINATOR.to_vec();
+        wallet.serialize(&mut data).unwrap();
+        Pubkey::new_unique().serialize(&mut data).unwrap();
+        HubCredentialType::KycBasic.serialize(&mut data).unwrap();
+        HubCredentialStatus::Active.serialize(&mut data).unwrap();
+        (NOW - 86_400).serialize(&mut data).unwrap();
+        0i64.serialize(&mut data).unwrap();
+        (NOW - 86_400).serialize(&mut data).unwrap();
+        String::from("ipfs://metadata")
+            .serialize(&mut data)
+            .unwrap();
+        String::new().serialize(&mut data).unwrap();
+        1u8.serialize(&mut data).unwrap();
+        254u8.serialize(&mut data).unwrap();
+
+        // Migration grows the account with zeroes
+        data.resize(8 + UserCredential::INIT_SPACE, 0);
+
+        let credential = UserCredential::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(credential.holder, wallet);
+        assert_eq!(credential.version, 1);
+        assert_eq!(credential.bump, 254);
+        assert_eq!(credential.suspended_until, 0);
+        assert!(credential.suspension_reason.is_empty());
+        assert!(!credential.attributes.is_valid());
+        assert_eq!(credential.attributes.investment_limit, None);
+        assert_eq!(credential.commitment, [0; 32]);
+        assert_eq!(
+            credential.effective_status(NOW),
+            HubCredentialStatus::Active
+        );
+    }
+
+    #[test]
+    fn test_derive_matches_credential_program() {
+        let wallet = Pubkey::new_unique();
+        let (pda, _) = derive_hub_credential_pda(&wallet, HubCredentialType::KycFull);
+        let (expected, _) = Pubkey::find_program_address(
+            &[
+                b"credential",
+                wallet.as_ref(),
+                &[HubCredentialType::KycFull as u8],
+            ],
+            &HUB_CREDENTIAL_PROGRAM_ID,
         );
-        assert_eq!(HubCredentialStatus::from_u8(10), None);
+        assert_eq!(pda, expected);
     }
 }
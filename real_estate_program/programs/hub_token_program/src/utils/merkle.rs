@@ -0,0 +1,49 @@
+/// Merkle allow-list verification
+///
+/// Leaves are `sha256(wallet)`; parent nodes hash the two children in
+/// sorted order, so proofs do not need left/right flags.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Computes the allow-list leaf for a wallet
+pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[wallet.as_ref()]).to_bytes()
+}
+
+/// Verifies a Merkle proof against a root
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(allowlist_leaf).collect();
+
+        let pair = |a: [u8; 32], b: [u8; 32]| {
+            if a <= b {
+                hashv(&[&a, &b]).to_bytes()
+            } else {
+                hashv(&[&b, &a]).to_bytes()
+            }
+        };
+        let left = pair(leaves[0], leaves[1]);
+        let root = pair(left, leaves[2]);
+
+        assert!(verify_merkle_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[left], &root, leaves[2]));
+        assert!(!verify_merkle_proof(&[left], &root, allowlist_leaf(&Pubkey::new_unique())));
+    }
+}
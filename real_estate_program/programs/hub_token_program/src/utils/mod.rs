@@ -1,3 +1,9 @@
+pub mod hooked_transfer;
 pub mod hub_credential_verification;
+pub mod merkle;
+pub mod token_escrow;
 
+pub use hooked_transfer::*;
 pub use hub_credential_verification::*;
+pub use merkle::*;
+pub use token_escrow::*;
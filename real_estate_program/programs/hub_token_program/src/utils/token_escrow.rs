@@ -0,0 +1,143 @@
+/// Program escrow for property tokens
+///
+/// Token-2022 invokes this program's transfer hook on every transfer, and the
+/// runtime does not allow re-entering this program through Token-2022. The
+/// program therefore cannot `transfer_checked` its own tokens into or out of
+/// a PDA. Instead, escrowed tokens are burned from the holder (the holder
+/// signs) and re-minted on release (PropertyState PDA is the mint authority).
+///
+/// Neither path goes through the transfer hook, so callers that release
+/// tokens to a wallet must verify its Hub Credential themselves.
+///
+/// Escrowed tokens stay in `circulating_supply` and are tracked in
+/// `PropertyState::escrowed_supply`, so `mint.supply + escrowed_supply`
+/// always equals `circulating_supply`.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{self, Burn, MintTo, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{constants::PROPERTY_STATE_SEED, error::RwaError, state::PropertyState};
+
+/// Move `amount` tokens from a holder into program escrow
+pub fn escrow_property_tokens<'info>(
+    token_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, Mint>,
+    holder_token_account: &InterfaceAccount<'info, TokenAccount>,
+    holder: &Signer<'info>,
+    property_state: &mut Account<'info, PropertyState>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(
+        holder_token_account.amount >= amount,
+        RwaError::InsufficientBalance
+    );
+
+    let cpi_context = CpiContext::new(
+        token_program.to_account_info(),
+        Burn {
+            mint: mint.to_account_info(),
+            from: holder_token_account.to_account_info(),
+            authority: holder.to_account_info(),
+        },
+    );
+    token_2022::burn(cpi_context, amount)?;
+
+    property_state.escrowed_supply = property_state
+        .escrowed_supply
+        .checked_add(amount)
+        .ok_or(RwaError::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Release `amount` escrowed tokens to a token account
+pub fn release_property_tokens<'info>(
+    token_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, Mint>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    property_state: &mut Account<'info, PropertyState>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+
+    property_state.escrowed_supply = property_state
+        .escrowed_supply
+        .checked_sub(amount)
+        .ok_or(RwaError::InsufficientEscrow)?;
+
+    let mint_key = property_state.mint;
+    let seeds = &[
+        PROPERTY_STATE_SEED,
+        mint_key.as_ref(),
+        &[property_state.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo {
+            mint: mint.to_account_info(),
+            to: recipient_token_account.to_account_info(),
+            authority: property_state.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_2022::mint_to(cpi_context, amount)
+}
+
+/// Move `amount` tokens between two token accounts without the transfer hook
+///
+/// Burns from `from` (signed by `from_authority`, with `from_signer_seeds`
+/// when it is a PDA) and mints the same amount to `to`, so neither
+/// `circulating_supply` nor `escrowed_supply` changes.
+#[allow(clippy::too_many_arguments)]
+pub fn reissue_property_tokens<'info>(
+    token_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    from_authority: &AccountInfo<'info>,
+    from_signer_seeds: &[&[&[u8]]],
+    to: &InterfaceAccount<'info, TokenAccount>,
+    property_state: &Account<'info, PropertyState>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RwaError::InvalidAmount);
+    require!(from.amount >= amount, RwaError::InsufficientBalance);
+
+    token_2022::burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: from.to_account_info(),
+                authority: from_authority.clone(),
+            },
+            from_signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let mint_key = property_state.mint;
+    let seeds = &[
+        PROPERTY_STATE_SEED,
+        mint_key.as_ref(),
+        &[property_state.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: property_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
@@ -153,16 +153,31 @@ if (!propertyStateAccount) {
 
   const expectedFields = [
     'authority',
+    'sellerWallet',
     'mint',
     'propertyName',
     'propertySymbol',
     'totalSupply',
     'circulatingSupply',
     'details',
-    'isActive',
+    'status',
     'createdAt',
     'updatedAt',
     'bump',
+    'version',
+    'pausedFrom',
+    'transfersPaused',
+    'escrowedSupply',
+    'transferFeeEnabled',
+    'transferFeeRetiredShareBps',
+    'sponsorAllocatedSupply',
+    'sponsorClaimedSupply',
+    'stakedSupply',
+    'stakingBoostWeight',
+    'poolSupply',
+    'bundledSupply',
+    'revenueEpochCount',
+    'lastRevenueAt',
   ];
 
   const fields = propertyStateAccount.type.fields.map((f: any) => f.name);
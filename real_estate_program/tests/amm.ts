@@ -0,0 +1,161 @@
+/**
+ * HUB Token Program - AMM Swap through the Transfer Hook
+ *
+ * Token-to-SOL swaps settled against a hooked transfer into the pool vault.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  complianceRules,
+  createTokenAccount,
+  credentialAddress,
+  expectError,
+  hookedTransfer,
+  invest,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  PLATFORM_TREASURY,
+  platformConfig,
+  program,
+  Property,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+} from "./utils/harness";
+
+describe("HUB Token Program - AMM Swap through the Transfer Hook", () => {
+  before(startHarness);
+
+  let property: Property;
+  let trader: Keypair;
+  let traderTokenAccount: PublicKey;
+  let liquidityPool: PublicKey;
+  let tokenVault: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    const provider = await newWallet();
+    trader = await newWallet();
+    const providerTokenAccount = await invest(property, provider, 10, 1_000);
+    traderTokenAccount = await invest(property, trader, 10, 1_000);
+
+    liquidityPool = pda([Buffer.from("liquidity_pool"), property.mint.toBuffer()]);
+    const lpMint = pda([Buffer.from("lp_mint"), property.mint.toBuffer()]);
+    tokenVault = pda([Buffer.from("pool_vault"), property.mint.toBuffer()]);
+
+    await send(
+      [
+        await program.methods
+          .initializePool(30)
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            liquidityPool,
+            lpMint,
+            tokenVault,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    const providerLpAccount = await createTokenAccount(provider.publicKey, lpMint);
+    await send(
+      [
+        await program.methods
+          .addLiquidity(new BN(500).muln(TOKEN), new BN(5 * LAMPORTS_PER_SOL), new BN(0))
+          .accountsPartial({
+            provider: provider.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            liquidityPool,
+            lpMint,
+            tokenVault,
+            providerTokenAccount,
+            providerLpAccount,
+            providerCredential: credentialAddress(provider.publicKey)[0],
+            complianceRules: complianceRules(property),
+            platformConfig,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [provider]
+    );
+  });
+
+  const swap = (amountIn: number) =>
+    program.methods
+      .swap({ tokenToSol: {} }, new BN(amountIn), new BN(1))
+      .accountsPartial({
+        user: trader.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        liquidityPool,
+        tokenVault,
+        userTokenAccount: traderTokenAccount,
+        userCredential: credentialAddress(trader.publicKey)[0],
+        complianceRules: complianceRules(property),
+        platformConfig,
+        platformTreasury: PLATFORM_TREASURY,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  it("Should sell tokens sent to the vault through the hook", async () => {
+    const amount = 100 * TOKEN;
+    const poolBefore = await program.account.liquidityPool.fetch(liquidityPool);
+    const traderBefore = await lamports(trader.publicKey);
+    const treasuryBefore = await lamports(PLATFORM_TREASURY);
+
+    await send(
+      [
+        hookedTransfer(
+          property,
+          traderTokenAccount,
+          tokenVault,
+          liquidityPool,
+          trader,
+          BigInt(amount)
+        ),
+        await swap(amount),
+      ],
+      [trader]
+    );
+
+    const poolAfter = await program.account.liquidityPool.fetch(liquidityPool);
+    const reserveOut = BigInt(poolBefore.solReserve.sub(poolAfter.solReserve).toString());
+    const platformFee = (await lamports(PLATFORM_TREASURY)) - treasuryBefore;
+
+    assert.equal(
+      poolAfter.tokenReserve.sub(poolBefore.tokenReserve).toString(),
+      amount.toString()
+    );
+    assert.isTrue(platformFee > 0n);
+    assert.equal((await lamports(trader.publicKey)) - traderBefore, reserveOut - platformFee);
+    assert.equal(await tokenBalance(traderTokenAccount), BigInt(900 * TOKEN));
+    console.log("✅ Swapped 100 tokens for", (reserveOut - platformFee).toString(), "lamports");
+  });
+
+  it("Should refuse a sale without the hooked transfer", async () => {
+    await expectError([await swap(10 * TOKEN)], [trader], "InvalidPoolTransfer");
+  });
+});
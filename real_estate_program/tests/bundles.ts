@@ -0,0 +1,185 @@
+/**
+ * HUB Token Program - Bundle Revenue Collection
+ *
+ * Revenue collected by a bundle for its basket and claimed by holders.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  complianceRules,
+  context,
+  createTokenAccount,
+  credentialAddress,
+  invest,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  platformAdmin,
+  platformConfig,
+  program,
+  Property,
+  send,
+  setStatus,
+  startHarness,
+  TOKEN,
+  u64,
+} from "./utils/harness";
+
+describe("HUB Token Program - Bundle Revenue Collection", () => {
+  before(startHarness);
+
+  let property: Property;
+  let holder: Keypair;
+  let bundle: PublicKey;
+  let bundleHolder: PublicKey;
+  let revenueEpoch: PublicKey;
+  let revenueVault: PublicKey;
+  let collected: bigint;
+
+  before(async () => {
+    property = await newListedProperty();
+    holder = await newWallet();
+    const holderTokenAccount = await invest(property, holder, 10, 1_000);
+
+    const bundleMint = Keypair.generate();
+    bundle = pda([Buffer.from("bundle"), bundleMint.publicKey.toBuffer()]);
+    bundleHolder = pda([
+      Buffer.from("bundle_holder"),
+      bundle.toBuffer(),
+      holder.publicKey.toBuffer(),
+    ]);
+
+    await send(
+      [
+        await program.methods
+          .createBundle([
+            { propertyMint: property.mint, amountPerUnit: new BN(100).muln(TOKEN) },
+          ])
+          .accountsPartial({
+            admin: platformAdmin.publicKey,
+            platformConfig,
+            bundleMint: bundleMint.publicKey,
+            bundle,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [platformAdmin, bundleMint]
+    );
+
+    const holderBundleAccount = await createTokenAccount(
+      holder.publicKey,
+      bundleMint.publicKey
+    );
+    await send(
+      [
+        await program.methods
+          .mintBundle(new BN(2))
+          .accountsPartial({
+            holder: holder.publicKey,
+            bundle,
+            bundleMint: bundleMint.publicKey,
+            holderBundleAccount,
+            bundleHolder,
+            holderCredential: credentialAddress(holder.publicKey)[0],
+            platformConfig,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([
+            { pubkey: property.propertyState, isSigner: false, isWritable: true },
+            { pubkey: property.mint, isSigner: false, isWritable: true },
+            { pubkey: holderTokenAccount, isSigner: false, isWritable: true },
+            { pubkey: complianceRules(property), isSigner: false, isWritable: false },
+          ])
+          .instruction(),
+      ],
+      [holder]
+    );
+
+    // Revenue is deposited once the property operates
+    await setStatus(property, { operating: {} });
+    revenueEpoch = pda([
+      Buffer.from("revenue_epoch"),
+      property.propertyState.toBuffer(),
+      u64(0),
+    ]);
+    revenueVault = pda([Buffer.from("revenue_vault"), revenueEpoch.toBuffer()]);
+    await send(
+      [
+        await program.methods
+          .depositRevenue(new BN(0), new BN(LAMPORTS_PER_SOL))
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            revenueEpoch,
+            revenueVault,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+  });
+
+  it("Should collect the basket's share of the epoch", async () => {
+    const epoch = await program.account.revenueEpoch.fetch(revenueEpoch);
+    // 2 units of 100 tokens each
+    const basket = BigInt(200 * TOKEN);
+    const expected =
+      (basket * BigInt(epoch.totalRevenue.toString())) /
+      BigInt(epoch.eligibleSupply.toString());
+    const bundleBefore = await lamports(bundle);
+
+    await send([
+      await program.methods
+        .collectBundleRevenue()
+        .accountsPartial({
+          payer: context.payer.publicKey,
+          bundle,
+          propertyState: property.propertyState,
+          revenueEpoch,
+          claimRecord: pda([
+            Buffer.from("claim_record"),
+            revenueEpoch.toBuffer(),
+            bundle.toBuffer(),
+          ]),
+          revenueVault,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+    ]);
+
+    collected = (await lamports(bundle)) - bundleBefore;
+    assert.equal(collected, expected);
+    assert.isTrue(collected > 0n);
+  });
+
+  it("Should pay the collected revenue to the bundle holder", async () => {
+    const before = await lamports(holder.publicKey);
+
+    await send(
+      [
+        await program.methods
+          .claimBundleRevenue()
+          .accountsPartial({ holder: holder.publicKey, bundle, bundleHolder })
+          .instruction(),
+      ],
+      [holder]
+    );
+
+    assert.equal((await lamports(holder.publicKey)) - before, collected);
+    console.log("✅ Bundle revenue claimed:", collected.toString(), "lamports");
+  });
+});
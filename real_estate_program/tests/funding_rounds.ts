@@ -0,0 +1,137 @@
+/**
+ * HUB Token Program - Funding Rounds
+ *
+ * Round-priced investments and the platform / reserve / seller split.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  createTokenAccount,
+  DAY,
+  expectError,
+  investInstruction,
+  investmentVault,
+  lamports,
+  newListedProperty,
+  newWallet,
+  now,
+  pda,
+  PLATFORM_TREASURY,
+  program,
+  Property,
+  reserveFund,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+} from "./utils/harness";
+
+describe("HUB Token Program - Funding Rounds", () => {
+  before(startHarness);
+
+  let property: Property;
+  let investor: Keypair;
+  let fundingRound: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    investor = await newWallet();
+    await createTokenAccount(investor.publicKey, property.mint);
+    fundingRound = pda([
+      Buffer.from("funding_round"),
+      property.mint.toBuffer(),
+      Buffer.from([0]),
+    ]);
+
+    const startsAt = await now();
+    await send(
+      [
+        await program.methods
+          .createFundingRound(
+            new BN(0.01 * LAMPORTS_PER_SOL), // per whole token
+            new BN(500_000).muln(TOKEN),
+            new BN(startsAt),
+            new BN(startsAt + 7 * DAY),
+            null,
+            null
+          )
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            investmentVault: investmentVault(property),
+            fundingRound,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+  });
+
+  it("Should require the active round once rounds exist", async () => {
+    await expectError(
+      [
+        await investInstruction(
+          property,
+          investor,
+          new BN(LAMPORTS_PER_SOL),
+          new BN(100).muln(TOKEN),
+          null
+        ),
+      ],
+      [investor],
+      "FundingRoundRequired"
+    );
+  });
+
+  it("Should price the investment by the round and split the payment", async () => {
+    const sellerBefore = await lamports(property.seller.publicKey);
+    const reserveBefore = await lamports(reserveFund(property));
+    const treasuryBefore = await lamports(PLATFORM_TREASURY);
+
+    // 1 SOL at 0.01 SOL per token = 100 tokens
+    await send(
+      [
+        await investInstruction(
+          property,
+          investor,
+          new BN(LAMPORTS_PER_SOL),
+          new BN(100).muln(TOKEN),
+          fundingRound
+        ),
+      ],
+      [investor]
+    );
+
+    const tokenAccount = getAssociatedTokenAddressSync(
+      property.mint,
+      investor.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(await tokenBalance(tokenAccount), BigInt(100 * TOKEN));
+    assert.equal(
+      (await lamports(property.seller.publicKey)) - sellerBefore,
+      900_000_000n
+    );
+    assert.equal((await lamports(reserveFund(property))) - reserveBefore, 75_000_000n);
+    assert.equal((await lamports(PLATFORM_TREASURY)) - treasuryBefore, 25_000_000n);
+
+    const vault = await program.account.investmentVault.fetch(investmentVault(property));
+    assert.equal(vault.roundTokensSold[0].toString(), (100 * TOKEN).toString());
+    assert.equal(vault.roundSolRaised[0].toString(), LAMPORTS_PER_SOL.toString());
+    console.log("✅ 100 tokens bought in round 0, 90/7.5/2.5 split");
+  });
+});
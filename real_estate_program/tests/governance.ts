@@ -0,0 +1,249 @@
+/**
+ * HUB Token Program - Governance Execution and Unstaking
+ *
+ * Staked voting with a staked-supply quorum, timelocked reserve withdrawals
+ * and the vote lock on stakes.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  context,
+  credentialAddress,
+  DAY,
+  expectError,
+  invest,
+  investmentVault,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  program,
+  Property,
+  reserveFund,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+  u64,
+  warp,
+} from "./utils/harness";
+
+describe("HUB Token Program - Governance Execution and Unstaking", () => {
+  before(startHarness);
+
+  let property: Property;
+  let voter: Keypair;
+  let holder: Keypair;
+  let recipient: Keypair;
+  let tokenAccount: PublicKey;
+  let stakePosition: PublicKey;
+  let governanceConfig: PublicKey;
+  let proposal: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    voter = await newWallet();
+    holder = await newWallet();
+    recipient = Keypair.generate();
+    tokenAccount = await invest(property, voter, 10, 1_000); // 0.75 SOL reserve
+    await invest(property, holder, 10, 3_000); // holds without staking
+    stakePosition = pda([
+      Buffer.from("stake_position"),
+      property.mint.toBuffer(),
+      voter.publicKey.toBuffer(),
+    ]);
+    governanceConfig = pda([Buffer.from("governance"), property.mint.toBuffer()]);
+    proposal = pda([Buffer.from("proposal"), property.mint.toBuffer(), u64(0)]);
+
+    await send(
+      [
+        await program.methods
+          .initializeGovernance({
+            quorumBps: 3_000,
+            approvalThresholdBps: 6_000,
+            votingPeriodSeconds: new BN(DAY),
+            executionDelaySeconds: new BN(2 * DAY),
+            proposalThreshold: new BN(100).muln(TOKEN),
+          })
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            governanceConfig,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    // Voting weight is the stake held before the proposal snapshot
+    await send(
+      [
+        await program.methods
+          .stakeTokens(new BN(1_000).muln(TOKEN), new BN(30 * DAY))
+          .accountsPartial({
+            owner: voter.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            stakePosition,
+            ownerTokenAccount: tokenAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [voter]
+    );
+    await warp(60);
+
+    await send(
+      [
+        await program.methods
+          .createProposal(
+            {
+              withdrawReserve: {
+                amount: new BN(0.5 * LAMPORTS_PER_SOL),
+                recipient: recipient.publicKey,
+              },
+            },
+            "ipfs://proposal-roof-repair"
+          )
+          .accountsPartial({
+            proposer: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            governanceConfig,
+            proposal,
+            proposerStakePosition: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    await send(
+      [
+        await program.methods
+          .castVote(true)
+          .accountsPartial({
+            voter: voter.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            proposal,
+            voteRecord: pda([
+              Buffer.from("vote_record"),
+              proposal.toBuffer(),
+              voter.publicKey.toBuffer(),
+            ]),
+            stakePosition,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [voter]
+    );
+  });
+
+  const execute = () =>
+    program.methods
+      .executeProposal()
+      .accountsPartial({
+        executor: voter.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        proposal,
+        governanceConfig: null,
+        investmentVault: investmentVault(property),
+        reserveFund: reserveFund(property),
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  const unstake = () =>
+    program.methods
+      .unstakeTokens()
+      .accountsPartial({
+        owner: voter.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        stakePosition,
+        ownerTokenAccount: tokenAccount,
+        ownerCredential: credentialAddress(voter.publicKey)[0],
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+  it("Should measure quorum against the staked supply", async () => {
+    // 1,000 of 4,000 tokens are staked; the 30% quorum is met by staked votes
+    const created = await program.account.proposal.fetch(proposal);
+    assert.equal(created.snapshotSupply.toString(), (1_000 * TOKEN).toString());
+    assert.equal(created.votesFor.toString(), (1_000 * TOKEN).toString());
+  });
+
+  it("Should require the proposal threshold in stake", async () => {
+    await expectError(
+      [
+        await program.methods
+          .createProposal({ signal: {} }, "ipfs://proposal-unstaked")
+          .accountsPartial({
+            proposer: holder.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            governanceConfig,
+            proposal: pda([Buffer.from("proposal"), property.mint.toBuffer(), u64(1)]),
+            proposerStakePosition: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [holder],
+      "ProposalThresholdNotMet"
+    );
+  });
+
+  it("Should not execute while voting is open", async () => {
+    await expectError([await execute()], [voter], "VotingNotEnded");
+  });
+
+  it("Should not execute before the timelock", async () => {
+    await warp(DAY);
+    await expectError([await execute()], [voter], "ProposalTimelocked");
+  });
+
+  it("Should withdraw the reserve once the timelock passes", async () => {
+    await warp(2 * DAY);
+    await send([await execute()], [voter]);
+
+    assert.equal(await lamports(recipient.publicKey), 500_000_000n);
+    const vault = await program.account.investmentVault.fetch(investmentVault(property));
+    assert.equal(vault.reserveBalance.toString(), LAMPORTS_PER_SOL.toString());
+    const executed = await program.account.proposal.fetch(proposal);
+    assert.isTrue(executed.executed);
+    console.log("✅ 0.5 SOL withdrawn from the reserve by proposal 0");
+  });
+
+  it("Should keep the stake locked until the lock expires", async () => {
+    await expectError([await unstake()], [voter], "StakeLocked");
+  });
+
+  it("Should return the staked tokens after the lock", async () => {
+    await warp(30 * DAY);
+    await send([await unstake()], [voter]);
+
+    assert.equal(await tokenBalance(tokenAccount), BigInt(1_000 * TOKEN));
+    assert.isNull(await context.banksClient.getAccount(stakePosition));
+    const state = await program.account.propertyState.fetch(property.propertyState);
+    assert.equal(state.stakedSupply.toString(), "0");
+  });
+});
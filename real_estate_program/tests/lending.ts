@@ -0,0 +1,274 @@
+/**
+ * HUB Token Program - Lending Borrow and Liquidation
+ *
+ * LTV-limited borrowing and bonus liquidation after a valuation drop.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  MINT_SIZE,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMint2Instruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  complianceRules,
+  context,
+  createTokenAccount,
+  credentialAddress,
+  DAY,
+  DECIMALS,
+  expectError,
+  invest,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  platformAdmin,
+  platformConfig,
+  program,
+  Property,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+  warp,
+} from "./utils/harness";
+
+describe("HUB Token Program - Lending Borrow and Liquidation", () => {
+  before(startHarness);
+
+  let property: Property;
+  let borrower: Keypair;
+  let liquidator: Keypair;
+  let loanMint: Keypair;
+  let lendingMarket: PublicKey;
+  let loanVault: PublicKey;
+  let loanPosition: PublicKey;
+  let borrowerLoanAccount: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    borrower = await newWallet();
+    liquidator = await newWallet();
+    const borrowerTokenAccount = await invest(property, borrower, 10, 1_000);
+
+    // Classic SPL stablecoin lent against the property tokens
+    loanMint = Keypair.generate();
+    const rent = await context.banksClient.getRent();
+    await send(
+      [
+        SystemProgram.createAccount({
+          fromPubkey: context.payer.publicKey,
+          newAccountPubkey: loanMint.publicKey,
+          lamports: Number(rent.minimumBalance(BigInt(MINT_SIZE))),
+          space: MINT_SIZE,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        createInitializeMint2Instruction(
+          loanMint.publicKey,
+          DECIMALS,
+          context.payer.publicKey,
+          null,
+          TOKEN_PROGRAM_ID
+        ),
+      ],
+      [loanMint]
+    );
+
+    lendingMarket = pda([Buffer.from("lending_market"), property.mint.toBuffer()]);
+    loanVault = pda([Buffer.from("lending_vault"), property.mint.toBuffer()]);
+    loanPosition = pda([
+      Buffer.from("loan_position"),
+      property.mint.toBuffer(),
+      borrower.publicKey.toBuffer(),
+    ]);
+
+    await send(
+      [
+        await program.methods
+          .initializeLendingMarket(7_000, 7_500, 500, 0)
+          .accountsPartial({
+            admin: platformAdmin.publicKey,
+            platformConfig,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            loanMint: loanMint.publicKey,
+            lendingMarket,
+            loanVault,
+            loanTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [platformAdmin]
+    );
+    await send([
+      createMintToInstruction(
+        loanMint.publicKey,
+        loanVault,
+        context.payer.publicKey,
+        1_000_000 * TOKEN,
+        [],
+        TOKEN_PROGRAM_ID
+      ),
+    ]);
+
+    await send(
+      [
+        await program.methods
+          .depositCollateral(new BN(1_000).muln(TOKEN))
+          .accountsPartial({
+            borrower: borrower.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            lendingMarket,
+            loanPosition,
+            borrowerTokenAccount,
+            platformConfig,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [borrower]
+    );
+    borrowerLoanAccount = await createTokenAccount(
+      borrower.publicKey,
+      loanMint.publicKey,
+      TOKEN_PROGRAM_ID
+    );
+  });
+
+  const borrow = (amount: number) =>
+    program.methods
+      .borrow(new BN(amount))
+      .accountsPartial({
+        borrower: borrower.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        lendingMarket,
+        loanPosition,
+        loanMint: loanMint.publicKey,
+        loanVault,
+        borrowerLoanAccount,
+        platformConfig,
+        loanTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+
+  async function liquidate(repayAmount: number) {
+    const liquidatorLoanAccount = await createTokenAccount(
+      liquidator.publicKey,
+      loanMint.publicKey,
+      TOKEN_PROGRAM_ID
+    );
+    return program.methods
+      .liquidateLoan(new BN(repayAmount))
+      .accountsPartial({
+        liquidator: liquidator.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        lendingMarket,
+        loanPosition,
+        loanMint: loanMint.publicKey,
+        loanVault,
+        liquidatorLoanAccount,
+        liquidatorTokenAccount: await createTokenAccount(
+          liquidator.publicKey,
+          property.mint
+        ),
+        liquidatorCredential: credentialAddress(liquidator.publicKey)[0],
+        complianceRules: complianceRules(property),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        loanTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  // 1,000 of 1M tokens of a $1M property = $1,000 of collateral
+  it("Should refuse a loan above the maximum LTV", async () => {
+    await expectError([await borrow(700 * TOKEN + 1)], [borrower], "BorrowLimitExceeded");
+  });
+
+  it("Should lend up to the maximum LTV", async () => {
+    await send([await borrow(700 * TOKEN)], [borrower]);
+
+    assert.equal(
+      await tokenBalance(borrowerLoanAccount, TOKEN_PROGRAM_ID),
+      BigInt(700 * TOKEN)
+    );
+    const position = await program.account.loanPosition.fetch(loanPosition);
+    assert.equal(position.debt.toString(), (700 * TOKEN).toString());
+  });
+
+  it("Should not liquidate a healthy position", async () => {
+    await expectError([await liquidate(350 * TOKEN)], [liquidator], "PositionHealthy");
+  });
+
+  it("Should seize collateral with the bonus after a valuation drop", async () => {
+    // Valuations move at most 10% per day
+    await warp(DAY);
+    await send(
+      [
+        await program.methods
+          .updateLendingValuation(new BN(90_000_000))
+          .accountsPartial({
+            admin: platformAdmin.publicKey,
+            platformConfig,
+            lendingMarket,
+          })
+          .instruction(),
+      ],
+      [platformAdmin]
+    );
+
+    const liquidatorLoanAccount = await createTokenAccount(
+      liquidator.publicKey,
+      loanMint.publicKey,
+      TOKEN_PROGRAM_ID
+    );
+    await send([
+      createMintToInstruction(
+        loanMint.publicKey,
+        liquidatorLoanAccount,
+        context.payer.publicKey,
+        350 * TOKEN,
+        [],
+        TOKEN_PROGRAM_ID
+      ),
+    ]);
+
+    // $700 debt against $900 of collateral is past the 75% threshold
+    await send([await liquidate(350 * TOKEN)], [liquidator]);
+
+    // 350 repaid + 5% bonus, in collateral valued at $0.90 per token
+    const seized = (350n * 10_500n * BigInt(TOKEN) * 1_000n) / (10_000n * 900n);
+    const liquidatorTokenAccount = getAssociatedTokenAddressSync(
+      property.mint,
+      liquidator.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(await tokenBalance(liquidatorTokenAccount), seized);
+    assert.equal(await tokenBalance(liquidatorLoanAccount, TOKEN_PROGRAM_ID), 0n);
+
+    const position = await program.account.loanPosition.fetch(loanPosition);
+    assert.equal(position.debt.toString(), (350 * TOKEN).toString());
+    assert.equal(
+      position.collateralAmount.toString(),
+      (BigInt(1_000 * TOKEN) - seized).toString()
+    );
+    console.log("✅ Liquidated 350 of debt for", seized.toString(), "collateral base units");
+  });
+
+  it("Should not liquidate against a stale valuation", async () => {
+    // Valuations older than 90 days cannot back a liquidation
+    await warp(91 * DAY);
+    await expectError([await liquidate(TOKEN)], [liquidator], "StaleValuation");
+  });
+});
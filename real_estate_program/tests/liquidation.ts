@@ -0,0 +1,151 @@
+/**
+ * HUB Token Program - Liquidation Redemption
+ *
+ * Sale proceeds plus the swept reserve paid out pro rata on redemption, and
+ * the close once every token is redeemed or the claim period has ended.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  context,
+  DAY,
+  expectError,
+  invest,
+  investmentVault,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  program,
+  Property,
+  reserveFund,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+  warp,
+} from "./utils/harness";
+
+describe("HUB Token Program - Liquidation Redemption", () => {
+  before(startHarness);
+
+  let property: Property;
+  let investor1: Keypair;
+  let investor2: Keypair;
+  let tokenAccount1: PublicKey;
+  let tokenAccount2: PublicKey;
+  let propertyLiquidation: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    investor1 = await newWallet();
+    investor2 = await newWallet();
+    tokenAccount1 = await invest(property, investor1, 10, 1_000);
+    tokenAccount2 = await invest(property, investor2, 10, 3_000);
+    propertyLiquidation = pda([Buffer.from("liquidation"), property.mint.toBuffer()]);
+  });
+
+  const liquidate = (target: Property, saleProceeds: number) =>
+    program.methods
+      .liquidateProperty(new BN(saleProceeds * LAMPORTS_PER_SOL))
+      .accountsPartial({
+        authority: target.authority.publicKey,
+        propertyState: target.propertyState,
+        mint: target.mint,
+        propertyLiquidation: pda([Buffer.from("liquidation"), target.mint.toBuffer()]),
+        reserveFund: reserveFund(target),
+        investmentVault: investmentVault(target),
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  const close = (target: Property) =>
+    program.methods
+      .closeLiquidatedProperty()
+      .accountsPartial({
+        authority: target.authority.publicKey,
+        propertyState: target.propertyState,
+        mint: target.mint,
+        propertyLiquidation: pda([Buffer.from("liquidation"), target.mint.toBuffer()]),
+        investmentVault: investmentVault(target),
+      })
+      .instruction();
+
+  const redeem = (investor: Keypair, tokenAccount: PublicKey, amount: number) =>
+    program.methods
+      .redeemLiquidation(new BN(amount).muln(TOKEN))
+      .accountsPartial({
+        investor: investor.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        investorTokenAccount: tokenAccount,
+        propertyLiquidation,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+  it("Should deposit the sale proceeds and sweep the reserve", async () => {
+    await send([await liquidate(property, 100)], [property.authority]);
+
+    const liquidation = await program.account.propertyLiquidation.fetch(
+      propertyLiquidation
+    );
+    assert.equal(liquidation.reserveSwept.toString(), (1.5 * LAMPORTS_PER_SOL).toString());
+    assert.equal(
+      liquidation.remainingProceeds.toString(),
+      (101.5 * LAMPORTS_PER_SOL).toString()
+    );
+    assert.equal(await lamports(reserveFund(property)), 0n);
+  });
+
+  it("Should pay each holder their pro-rata share", async () => {
+    const before1 = await lamports(investor1.publicKey);
+    await send([await redeem(investor1, tokenAccount1, 1_000)], [investor1]);
+    // 1,000 of 4,000 tokens
+    assert.equal((await lamports(investor1.publicKey)) - before1, 25_375_000_000n);
+    assert.equal(await tokenBalance(tokenAccount1), 0n);
+
+    const before2 = await lamports(investor2.publicKey);
+    await send([await redeem(investor2, tokenAccount2, 3_000)], [investor2]);
+    assert.equal((await lamports(investor2.publicKey)) - before2, 76_125_000_000n);
+
+    const liquidation = await program.account.propertyLiquidation.fetch(
+      propertyLiquidation
+    );
+    assert.equal(liquidation.remainingProceeds.toString(), "0");
+    const state = await program.account.propertyState.fetch(property.propertyState);
+    assert.equal(state.circulatingSupply.toString(), "0");
+    console.log("✅ 101.5 SOL distributed to 4,000 tokens");
+  });
+
+  it("Should close once every token is redeemed", async () => {
+    await send([await close(property)], [property.authority]);
+    assert.isNull(await context.banksClient.getAccount(property.propertyState));
+  });
+
+  it("Should close with tokens outstanding only after the claim period", async () => {
+    const abandoned = await newListedProperty();
+    const holder = await newWallet();
+    await invest(abandoned, holder, 10, 1_000);
+    await send([await liquidate(abandoned, 10)], [abandoned.authority]);
+
+    await expectError([await close(abandoned)], [abandoned.authority], "TokensOutstanding");
+
+    await warp(365 * DAY);
+    const before = await lamports(abandoned.authority.publicKey);
+    await send([await close(abandoned)], [abandoned.authority]);
+
+    // Unclaimed proceeds return to the authority along with the rent
+    const recovered = (await lamports(abandoned.authority.publicKey)) - before;
+    assert.isTrue(recovered > 10n * BigInt(LAMPORTS_PER_SOL));
+    console.log("✅ Unclaimed proceeds recovered after the claim period");
+  });
+});
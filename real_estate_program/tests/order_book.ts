@@ -0,0 +1,157 @@
+/**
+ * HUB Token Program - Order Book Fills
+ *
+ * Partial sell fills and buy fills paid from the order deposit.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  complianceRules,
+  context,
+  createTokenAccount,
+  credentialAddress,
+  invest,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  platformConfig,
+  program,
+  Property,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+  u64,
+} from "./utils/harness";
+
+describe("HUB Token Program - Order Book Fills", () => {
+  before(startHarness);
+
+  let property: Property;
+  let maker: Keypair;
+  let taker: Keypair;
+  let makerTokenAccount: PublicKey;
+  let takerTokenAccount: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    maker = await newWallet();
+    taker = await newWallet();
+    makerTokenAccount = await invest(property, maker, 10, 1_000);
+    takerTokenAccount = await createTokenAccount(taker.publicKey, property.mint);
+  });
+
+  const order = (owner: Keypair, orderId: number) =>
+    pda([
+      Buffer.from("market_order"),
+      property.mint.toBuffer(),
+      owner.publicKey.toBuffer(),
+      u64(orderId),
+    ]);
+
+  const fill = (
+    filler: Keypair,
+    filled: PublicKey,
+    owner: Keypair,
+    fillerTokenAccount: PublicKey,
+    ownerTokenAccount: PublicKey | null,
+    amount: number
+  ) =>
+    program.methods
+      .fillOrder(new BN(amount).muln(TOKEN))
+      .accountsPartial({
+        taker: filler.publicKey,
+        maker: owner.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        order: filled,
+        takerTokenAccount: fillerTokenAccount,
+        makerTokenAccount: ownerTokenAccount,
+        feeCollectionAccount: null,
+        takerCredential: credentialAddress(filler.publicKey)[0],
+        makerCredential: credentialAddress(owner.publicKey)[0],
+        complianceRules: complianceRules(property),
+        platformConfig,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  it("Should fill a sell order partially at the maker's price", async () => {
+    const sellOrder = order(maker, 1);
+    await send(
+      [
+        await program.methods
+          .createSellOrder(new BN(1), new BN(0.01 * LAMPORTS_PER_SOL), new BN(500).muln(TOKEN))
+          .accountsPartial({
+            owner: maker.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            ownerTokenAccount: makerTokenAccount,
+            ownerCredential: credentialAddress(maker.publicKey)[0],
+            platformConfig,
+            order: sellOrder,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [maker]
+    );
+    assert.equal(await tokenBalance(makerTokenAccount), BigInt(500 * TOKEN));
+
+    const makerBefore = await lamports(maker.publicKey);
+    await send(
+      [await fill(taker, sellOrder, maker, takerTokenAccount, null, 200)],
+      [taker]
+    );
+
+    // 200 tokens at 0.01 SOL
+    assert.equal((await lamports(maker.publicKey)) - makerBefore, 2_000_000_000n);
+    assert.equal(await tokenBalance(takerTokenAccount), BigInt(200 * TOKEN));
+    const resting = await program.account.marketOrder.fetch(sellOrder);
+    assert.equal(resting.remaining.toString(), (300 * TOKEN).toString());
+  });
+
+  it("Should fill a buy order from its deposit and close it", async () => {
+    const buyOrder = order(taker, 2);
+    await send(
+      [
+        await program.methods
+          .createBuyOrder(new BN(2), new BN(0.01 * LAMPORTS_PER_SOL), new BN(100).muln(TOKEN))
+          .accountsPartial({
+            owner: taker.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            ownerCredential: credentialAddress(taker.publicKey)[0],
+            platformConfig,
+            order: buyOrder,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [taker]
+    );
+
+    const sellerBefore = await lamports(maker.publicKey);
+    await send(
+      [await fill(maker, buyOrder, taker, makerTokenAccount, takerTokenAccount, 100)],
+      [maker]
+    );
+
+    assert.equal((await lamports(maker.publicKey)) - sellerBefore, 1_000_000_000n);
+    assert.equal(await tokenBalance(makerTokenAccount), BigInt(400 * TOKEN));
+    assert.equal(await tokenBalance(takerTokenAccount), BigInt(300 * TOKEN));
+    assert.isNull(await context.banksClient.getAccount(buyOrder));
+    console.log("✅ Buy order filled and closed");
+  });
+});
@@ -0,0 +1,129 @@
+/**
+ * HUB Token Program - Redemption Window
+ *
+ * Reserve-funded buybacks, the token cap and the unspent budget refund.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  DAY,
+  expectError,
+  invest,
+  investmentVault,
+  lamports,
+  newListedProperty,
+  newWallet,
+  now,
+  pda,
+  program,
+  Property,
+  reserveFund,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+} from "./utils/harness";
+
+describe("HUB Token Program - Redemption Window", () => {
+  before(startHarness);
+
+  let property: Property;
+  let investor: Keypair;
+  let tokenAccount: PublicKey;
+  let redemptionWindow: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty();
+    investor = await newWallet();
+    tokenAccount = await invest(property, investor, 10, 1_000); // 0.75 SOL reserve
+    redemptionWindow = pda([
+      Buffer.from("redemption_window"),
+      property.mint.toBuffer(),
+    ]);
+
+    await send(
+      [
+        await program.methods
+          .openRedemptionWindow(
+            new BN(0.005 * LAMPORTS_PER_SOL), // per whole token
+            new BN(0.5 * LAMPORTS_PER_SOL),
+            new BN(100).muln(TOKEN),
+            new BN((await now()) + 7 * DAY)
+          )
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            investmentVault: investmentVault(property),
+            reserveFund: reserveFund(property),
+            redemptionWindow,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+  });
+
+  const redeem = (amount: number) =>
+    program.methods
+      .redeemTokens(new BN(amount).muln(TOKEN))
+      .accountsPartial({
+        investor: investor.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        investorTokenAccount: tokenAccount,
+        redemptionWindow,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+  it("Should burn tokens and pay from the window budget", async () => {
+    const before = await lamports(investor.publicKey);
+
+    await send([await redeem(40)], [investor]);
+
+    // 40 tokens at 0.005 SOL
+    assert.equal((await lamports(investor.publicKey)) - before, 200_000_000n);
+    assert.equal(await tokenBalance(tokenAccount), BigInt(960 * TOKEN));
+
+    const state = await program.account.propertyState.fetch(property.propertyState);
+    assert.equal(state.circulatingSupply.toString(), (960 * TOKEN).toString());
+  });
+
+  it("Should enforce the token cap", async () => {
+    await expectError([await redeem(61)], [investor], "RedemptionCapExceeded");
+  });
+
+  it("Should return the unspent budget to the reserve", async () => {
+    await send(
+      [
+        await program.methods
+          .closeRedemptionWindow()
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            investmentVault: investmentVault(property),
+            reserveFund: reserveFund(property),
+            redemptionWindow,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    const vault = await program.account.investmentVault.fetch(investmentVault(property));
+    assert.equal(vault.reserveBalance.toString(), (0.55 * LAMPORTS_PER_SOL).toString());
+    assert.equal(await lamports(reserveFund(property)), 550_000_000n);
+    console.log("✅ 0.3 SOL of budget returned to the reserve fund");
+  });
+});
@@ -0,0 +1,144 @@
+/**
+ * HUB Token Program - Staking Revenue
+ *
+ * Boosted revenue for stakes held at the deposit, none for stakes opened after.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  DAY,
+  expectError,
+  invest,
+  lamports,
+  newListedProperty,
+  newWallet,
+  pda,
+  program,
+  Property,
+  send,
+  setStatus,
+  startHarness,
+  TOKEN,
+  u64,
+  warp,
+} from "./utils/harness";
+
+describe("HUB Token Program - Staking Revenue", () => {
+  before(startHarness);
+
+  let property: Property;
+  let earlyStaker: Keypair;
+  let lateStaker: Keypair;
+  let earlyTokenAccount: PublicKey;
+  let lateTokenAccount: PublicKey;
+  let revenueEpoch: PublicKey;
+  let revenueVault: PublicKey;
+
+  const stakePosition = (owner: Keypair) =>
+    pda([
+      Buffer.from("stake_position"),
+      property.mint.toBuffer(),
+      owner.publicKey.toBuffer(),
+    ]);
+
+  const stake = (owner: Keypair, tokenAccount: PublicKey) =>
+    program.methods
+      .stakeTokens(new BN(1_000).muln(TOKEN), new BN(30 * DAY))
+      .accountsPartial({
+        owner: owner.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        stakePosition: stakePosition(owner),
+        ownerTokenAccount: tokenAccount,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  const claim = (investor: Keypair, tokenAccount: PublicKey) =>
+    program.methods
+      .claimRevenue()
+      .accountsPartial({
+        investor: investor.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        investorTokenAccount: tokenAccount,
+        revenueEpoch,
+        claimRecord: pda([
+          Buffer.from("claim_record"),
+          revenueEpoch.toBuffer(),
+          investor.publicKey.toBuffer(),
+        ]),
+        revenueVault,
+        stakePosition: stakePosition(investor),
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+  before(async () => {
+    property = await newListedProperty();
+    earlyStaker = await newWallet();
+    lateStaker = await newWallet();
+    earlyTokenAccount = await invest(property, earlyStaker, 10, 1_000);
+    lateTokenAccount = await invest(property, lateStaker, 10, 1_000);
+
+    await send([await stake(earlyStaker, earlyTokenAccount)], [earlyStaker]);
+    await warp(60);
+
+    await setStatus(property, { operating: {} });
+    revenueEpoch = pda([
+      Buffer.from("revenue_epoch"),
+      property.propertyState.toBuffer(),
+      u64(0),
+    ]);
+    revenueVault = pda([Buffer.from("revenue_vault"), revenueEpoch.toBuffer()]);
+    await send(
+      [
+        await program.methods
+          .depositRevenue(new BN(0), new BN(1_000_000_000))
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            revenueEpoch,
+            revenueVault,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    // Staked right after the deposit, in the same slot
+    await send([await stake(lateStaker, lateTokenAccount)], [lateStaker]);
+  });
+
+  it("Should not pay a stake opened after the deposit", async () => {
+    await expectError(
+      [await claim(lateStaker, lateTokenAccount)],
+      [lateStaker],
+      "InsufficientBalance"
+    );
+  });
+
+  it("Should pay the boosted weight of a stake held at the deposit", async () => {
+    const epoch = await program.account.revenueEpoch.fetch(revenueEpoch);
+    const position = await program.account.stakePosition.fetch(
+      stakePosition(earlyStaker)
+    );
+    const amount = BigInt(position.amount.toString());
+    const weight = amount + (amount * BigInt(position.multiplierBps - 10_000)) / 10_000n;
+    const expected =
+      (weight * BigInt(epoch.totalRevenue.toString())) /
+      BigInt(epoch.eligibleSupply.toString());
+    const before = await lamports(earlyStaker.publicKey);
+
+    await send([await claim(earlyStaker, earlyTokenAccount)], [earlyStaker]);
+
+    assert.equal((await lamports(earlyStaker.publicKey)) - before, expected);
+    console.log("✅ Early staker claimed", expected.toString(), "lamports");
+  });
+});
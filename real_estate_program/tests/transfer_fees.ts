@@ -0,0 +1,152 @@
+/**
+ * HUB Token Program - Transfer Hook and Fee Harvesting
+ *
+ * Credential checks in the hook (any credential type), withheld fees and the
+ * harvest split.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  createTokenAccount,
+  expectError,
+  hookedTransfer,
+  invest,
+  newListedProperty,
+  newWallet,
+  PLATFORM_TREASURY,
+  platformConfig,
+  program,
+  Property,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+} from "./utils/harness";
+
+describe("HUB Token Program - Transfer Hook and Fee Harvesting", () => {
+  before(startHarness);
+
+  let property: Property;
+  let sender: Keypair;
+  let receiver: Keypair;
+  let senderTokenAccount: PublicKey;
+  let receiverTokenAccount: PublicKey;
+
+  before(async () => {
+    property = await newListedProperty({
+      feeBps: 100, // 1%
+      maxFee: new BN(1_000).muln(TOKEN),
+      retiredShareBps: 5_000,
+    });
+    sender = await newWallet();
+    receiver = await newWallet();
+    senderTokenAccount = await invest(property, sender, 10, 1_000);
+    receiverTokenAccount = await createTokenAccount(receiver.publicKey, property.mint);
+  });
+
+  it("Should block transfers to wallets without a credential", async () => {
+    const outsider = Keypair.generate();
+    const outsiderTokenAccount = await createTokenAccount(outsider.publicKey, property.mint);
+
+    await expectError(
+      [
+        hookedTransfer(
+          property,
+          senderTokenAccount,
+          outsiderTokenAccount,
+          outsider.publicKey,
+          sender,
+          BigInt(TOKEN)
+        ),
+      ],
+      [sender],
+      "KycVerificationRequired"
+    );
+  });
+
+  it("Should withhold the fee on credentialed transfers", async () => {
+    await send(
+      [
+        hookedTransfer(
+          property,
+          senderTokenAccount,
+          receiverTokenAccount,
+          receiver.publicKey,
+          sender,
+          BigInt(100 * TOKEN)
+        ),
+      ],
+      [sender]
+    );
+
+    // 1% withheld on the receiving account
+    assert.equal(await tokenBalance(receiverTokenAccount), BigInt(99 * TOKEN));
+  });
+
+  it("Should accept a destination holding only a non-KycBasic credential", async () => {
+    const accredited = await newWallet(2); // AccreditedInvestor
+    const accreditedTokenAccount = await createTokenAccount(
+      accredited.publicKey,
+      property.mint
+    );
+
+    await send(
+      [
+        hookedTransfer(
+          property,
+          senderTokenAccount,
+          accreditedTokenAccount,
+          accredited.publicKey,
+          sender,
+          BigInt(100 * TOKEN)
+        ),
+      ],
+      [sender]
+    );
+
+    assert.equal(await tokenBalance(accreditedTokenAccount), BigInt(99 * TOKEN));
+  });
+
+  it("Should harvest withheld fees to the treasury and retire the rest", async () => {
+    const feeCollectionAccount = await createTokenAccount(
+      property.propertyState,
+      property.mint
+    );
+    const treasuryTokenAccount = await createTokenAccount(PLATFORM_TREASURY, property.mint);
+    const stateBefore = await program.account.propertyState.fetch(property.propertyState);
+
+    await send(
+      [
+        await program.methods
+          .harvestTransferFees()
+          .accountsPartial({
+            caller: property.authority.publicKey,
+            platformConfig,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            feeCollectionAccount,
+            treasuryTokenAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: receiverTokenAccount, isSigner: false, isWritable: true },
+          ])
+          .instruction(),
+      ],
+      [property.authority]
+    );
+
+    // 1 token withheld: half to the treasury, half retired
+    assert.equal(await tokenBalance(treasuryTokenAccount), BigInt(TOKEN / 2));
+    assert.equal(await tokenBalance(feeCollectionAccount), 0n);
+    const stateAfter = await program.account.propertyState.fetch(property.propertyState);
+    assert.equal(
+      stateBefore.totalSupply.sub(stateAfter.totalSupply).toString(),
+      (TOKEN / 2).toString()
+    );
+    console.log("✅ Harvested fees: 0.5 token to treasury, 0.5 token retired");
+  });
+});
@@ -0,0 +1,484 @@
+/**
+ * Bankrun harness for the HUB Token Program instruction tests
+ *
+ * Starts the program in bankrun so time-locked flows (governance, staking,
+ * vesting, lending valuations) can warp the clock, and provides helpers to
+ * create properties, invest and send hooked transfers.
+ *
+ * PlatformConfig can only be initialized by the fixed platform admin key and
+ * credentials are issued by the Hub Credential program, so both are injected
+ * as accounts with their on-chain layout.
+ */
+
+import { BN, Program } from "@coral-xyz/anchor";
+import { BankrunProvider } from "anchor-bankrun";
+import { Clock, ProgramTestContext, startAnchor } from "solana-bankrun";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createTransferCheckedInstruction,
+  getAssociatedTokenAddressSync,
+  unpackAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { HubTokenProgram } from "../../target/types/hub_token_program";
+import IDL from "../../target/idl/hub_token_program.json";
+
+export const HUB_CREDENTIAL_PROGRAM_ID = new PublicKey(
+  "FaJ4XGCLeu7eZiMjBEkANko3TRhpjns3cv6R1vZK94Wt"
+);
+export const PLATFORM_TREASURY = new PublicKey(
+  "AMuiRHoJLS2zhpRtUqVJUpYi4xEGbZcmMsJpqVT9uCJw"
+);
+
+export const DAY = 24 * 60 * 60;
+export const DECIMALS = 6;
+export const TOKEN = 10 ** DECIMALS;
+export const TOTAL_SUPPLY = new BN(1_000_000).muln(TOKEN); // 1M tokens
+
+export const propertyDetails = {
+  propertyAddress: "Av. Paulista, 1000, São Paulo - SP",
+  propertyType: "Commercial Office Building",
+  totalValueUsd: new BN(100_000_000), // $1M in cents
+  rentalYieldBps: 800,
+  metadataUri: "ipfs://QmExample123456789",
+};
+
+export interface TransferFeeParams {
+  feeBps: number;
+  maxFee: BN;
+  retiredShareBps: number;
+}
+
+export interface Property {
+  authority: Keypair;
+  seller: Keypair;
+  mint: PublicKey;
+  propertyState: PublicKey;
+}
+
+export let context: ProgramTestContext;
+export let program: Program<HubTokenProgram>;
+export let platformAdmin: Keypair;
+export let platformConfig: PublicKey;
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+export const pda = (seeds: (Buffer | Uint8Array)[]) =>
+  PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+export const u64 = (value: number | BN) =>
+  new BN(value).toArrayLike(Buffer, "le", 8);
+
+export const i64 = (value: number | bigint) => {
+  const buffer = Buffer.alloc(8);
+  buffer.writeBigInt64LE(BigInt(value));
+  return buffer;
+};
+
+export const borshString = (value: string) => {
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(Buffer.byteLength(value));
+  return Buffer.concat([length, Buffer.from(value)]);
+};
+
+export const accountDiscriminator = (name: string) =>
+  createHash("sha256").update(`account:${name}`).digest().subarray(0, 8);
+
+// Credential types: 0 = KycBasic, 1 = KycFull, 2 = AccreditedInvestor, ...
+export const CREDENTIAL_TYPE_COUNT = 6;
+
+export const credentialAddress = (wallet: PublicKey, credentialType = 0) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("credential"), wallet.toBuffer(), Buffer.from([credentialType])],
+    HUB_CREDENTIAL_PROGRAM_ID
+  );
+
+export async function now(): Promise<number> {
+  return Number((await context.banksClient.getClock()).unixTimestamp);
+}
+
+export async function warp(seconds: number) {
+  const clock = await context.banksClient.getClock();
+  const slot = clock.slot + 1n;
+  context.warpToSlot(slot);
+  context.setClock(
+    new Clock(
+      slot,
+      clock.epochStartTimestamp,
+      clock.epoch,
+      clock.leaderScheduleEpoch,
+      clock.unixTimestamp + BigInt(seconds)
+    )
+  );
+}
+
+export async function buildTransaction(
+  instructions: TransactionInstruction[],
+  signers: Keypair[]
+): Promise<Transaction> {
+  const [blockhash] = await context.banksClient.getLatestBlockhash();
+  const tx = new Transaction().add(
+    ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+    ...instructions
+  );
+  tx.recentBlockhash = blockhash;
+  tx.feePayer = context.payer.publicKey;
+  tx.sign(context.payer, ...signers);
+  return tx;
+}
+
+export async function send(instructions: TransactionInstruction[], signers: Keypair[] = []) {
+  await context.banksClient.processTransaction(
+    await buildTransaction(instructions, signers)
+  );
+}
+
+export async function expectError(
+  instructions: TransactionInstruction[],
+  signers: Keypair[],
+  errorName: string
+) {
+  const result = await context.banksClient.tryProcessTransaction(
+    await buildTransaction(instructions, signers)
+  );
+  assert.isNotNull(result.result, `Should have thrown ${errorName}`);
+  assert.isTrue(
+    result.meta.logMessages.some((log) => log.includes(errorName)),
+    `Expected ${errorName}, got:\n${result.meta.logMessages.join("\n")}`
+  );
+  console.log(`✅ Correctly rejected: ${errorName}`);
+}
+
+export async function lamports(address: PublicKey): Promise<bigint> {
+  return context.banksClient.getBalance(address);
+}
+
+export async function tokenBalance(
+  address: PublicKey,
+  tokenProgram = TOKEN_2022_PROGRAM_ID
+): Promise<bigint> {
+  const info = await context.banksClient.getAccount(address);
+  return unpackAccount(
+    address,
+    { ...info, data: Buffer.from(info.data) },
+    tokenProgram
+  ).amount;
+}
+
+// Funded wallet holding an active KycBasic Hub Credential
+export async function newWallet(credentialType = 0): Promise<Keypair> {
+  const wallet = Keypair.generate();
+  context.setAccount(wallet.publicKey, {
+    lamports: 1_000 * LAMPORTS_PER_SOL,
+    data: Buffer.alloc(0),
+    owner: SystemProgram.programId,
+    executable: false,
+  });
+
+  const [credential, bump] = credentialAddress(wallet.publicKey, credentialType);
+  const issuedAt = await now();
+  const data = Buffer.concat([
+    accountDiscriminator("UserCredential"),
+    wallet.publicKey.toBuffer(),
+    platformAdmin.publicKey.toBuffer(), // issuer
+    Buffer.from([credentialType, 0]), // type, Active
+    i64(issuedAt),
+    i64(0), // never expires
+    i64(issuedAt),
+    borshString("ipfs://credential"),
+    borshString(""),
+    Buffer.from([2, bump]),
+    i64(0), // not suspended
+    borshString(""),
+    Buffer.from("BRBR"), // residence and tax residency
+    Buffer.from([0, 0, 0, 0]), // Retail, Low risk, not PEP, no limit
+    Buffer.alloc(32), // no commitment
+  ]);
+  context.setAccount(credential, {
+    lamports: LAMPORTS_PER_SOL,
+    data,
+    owner: HUB_CREDENTIAL_PROGRAM_ID,
+    executable: false,
+  });
+
+  return wallet;
+}
+
+export async function createTokenAccount(
+  owner: PublicKey,
+  mint: PublicKey,
+  tokenProgram = TOKEN_2022_PROGRAM_ID
+): Promise<PublicKey> {
+  const address = getAssociatedTokenAddressSync(mint, owner, true, tokenProgram);
+  await send([
+    createAssociatedTokenAccountIdempotentInstruction(
+      context.payer.publicKey,
+      address,
+      owner,
+      mint,
+      tokenProgram
+    ),
+  ]);
+  return address;
+}
+
+export async function createProperty(
+  transferFee: TransferFeeParams | null = null
+): Promise<Property> {
+  const authority = await newWallet();
+  const seller = Keypair.generate();
+  const mint = Keypair.generate();
+  const propertyState = pda([Buffer.from("property"), mint.publicKey.toBuffer()]);
+
+  await send(
+    [
+      await program.methods
+        .createPropertyMint(
+          "Edifício Santos Dumont",
+          "EDSANTO",
+          DECIMALS,
+          TOTAL_SUPPLY,
+          seller.publicKey,
+          propertyDetails,
+          transferFee
+        )
+        .accountsPartial({
+          authority: authority.publicKey,
+          mint: mint.publicKey,
+          propertyState,
+          extraAccountMetaList: pda([
+            Buffer.from("extra-account-metas"),
+            mint.publicKey.toBuffer(),
+          ]),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+    ],
+    [authority, mint]
+  );
+
+  return { authority, seller, mint: mint.publicKey, propertyState };
+}
+
+export async function setStatus(property: Property, status: object) {
+  await send(
+    [
+      await program.methods
+        .setPropertyStatus(status as any)
+        .accountsPartial({
+          authority: property.authority.publicKey,
+          propertyState: property.propertyState,
+          mint: property.mint,
+        })
+        .instruction(),
+    ],
+    [property.authority]
+  );
+}
+
+// Open fundraising and the investment vault
+export async function listProperty(property: Property) {
+  await setStatus(property, { fundraising: {} });
+  await send(
+    [
+      await program.methods
+        .initializeInvestmentVault()
+        .accountsPartial({
+          authority: property.authority.publicKey,
+          propertyState: property.propertyState,
+          investmentVault: investmentVault(property),
+          reserveFund: reserveFund(property),
+          mint: property.mint,
+          seller: property.seller.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction(),
+    ],
+    [property.authority]
+  );
+}
+
+export async function newListedProperty(
+  transferFee: TransferFeeParams | null = null
+): Promise<Property> {
+  const property = await createProperty(transferFee);
+  await listProperty(property);
+  return property;
+}
+
+export const investmentVault = (property: Property) =>
+  pda([Buffer.from("investment_vault"), property.mint.toBuffer()]);
+
+export const reserveFund = (property: Property) =>
+  pda([Buffer.from("reserve_fund"), property.mint.toBuffer()]);
+
+export const complianceRules = (property: Property) =>
+  pda([Buffer.from("compliance_rules"), property.mint.toBuffer()]);
+
+export function investInstruction(
+  property: Property,
+  investor: Keypair,
+  solAmount: BN,
+  tokens: BN,
+  fundingRound: PublicKey | null
+): Promise<TransactionInstruction> {
+  return program.methods
+    .investInProperty(solAmount, tokens, [])
+    .accountsPartial({
+      investor: investor.publicKey,
+      propertyState: property.propertyState,
+      investmentVault: investmentVault(property),
+      reserveFund: reserveFund(property),
+      mint: property.mint,
+      investorTokenAccount: getAssociatedTokenAddressSync(
+        property.mint,
+        investor.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      investorCredential: credentialAddress(investor.publicKey)[0],
+      platformTreasury: PLATFORM_TREASURY,
+      seller: property.seller.publicKey,
+      fundingRound,
+      investmentLimits: pda([
+        Buffer.from("investment_limits"),
+        property.mint.toBuffer(),
+      ]),
+      complianceRules: complianceRules(property),
+      investorRecord: pda([
+        Buffer.from("investor_record"),
+        property.mint.toBuffer(),
+        investor.publicKey.toBuffer(),
+      ]),
+      distributor: null,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+// Invest `sol` SOL for `tokens` whole tokens; returns the investor's token account
+export async function invest(
+  property: Property,
+  investor: Keypair,
+  sol: number,
+  tokens: number
+): Promise<PublicKey> {
+  const tokenAccount = await createTokenAccount(investor.publicKey, property.mint);
+  await send(
+    [
+      await investInstruction(
+        property,
+        investor,
+        new BN(sol * LAMPORTS_PER_SOL),
+        new BN(tokens).muln(TOKEN),
+        null
+      ),
+    ],
+    [investor]
+  );
+  return tokenAccount;
+}
+
+// Token-2022 TransferChecked with the accounts the transfer hook resolves
+export function hookedTransfer(
+  property: Property,
+  source: PublicKey,
+  destination: PublicKey,
+  destinationOwner: PublicKey,
+  owner: Keypair,
+  amount: bigint
+): TransactionInstruction {
+  const instruction = createTransferCheckedInstruction(
+    source,
+    property.mint,
+    destination,
+    owner.publicKey,
+    amount,
+    DECIMALS,
+    [],
+    TOKEN_2022_PROGRAM_ID
+  );
+  const readonly = (pubkey: PublicKey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  });
+  instruction.keys.push(
+    readonly(HUB_CREDENTIAL_PROGRAM_ID),
+    readonly(credentialAddress(destinationOwner)[0]),
+    readonly(property.propertyState),
+    readonly(platformConfig),
+    readonly(complianceRules(property)),
+    ...Array.from({ length: CREDENTIAL_TYPE_COUNT - 1 }, (_, index) =>
+      readonly(credentialAddress(destinationOwner, index + 1)[0])
+    ),
+    readonly(program.programId),
+    readonly(pda([Buffer.from("extra-account-metas"), property.mint.toBuffer()]))
+  );
+  return instruction;
+}
+
+// Fresh bankrun context with the program, a test PlatformConfig and a funded
+// platform treasury
+export async function startHarness() {
+  context = await startAnchor("", [], []);
+  const provider = new BankrunProvider(context);
+  program = new Program<HubTokenProgram>(IDL as HubTokenProgram, provider);
+
+  // PlatformConfig with a test admin (initialize_platform_config is
+  // restricted to the fixed platform admin key)
+  platformAdmin = Keypair.generate();
+  context.setAccount(platformAdmin.publicKey, {
+    lamports: 1_000 * LAMPORTS_PER_SOL,
+    data: Buffer.alloc(0),
+    owner: SystemProgram.programId,
+    executable: false,
+  });
+
+  const [configAddress, configBump] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    program.programId
+  );
+  platformConfig = configAddress;
+  context.setAccount(platformConfig, {
+    lamports: LAMPORTS_PER_SOL,
+    data: Buffer.concat([
+      accountDiscriminator("PlatformConfig"),
+      platformAdmin.publicKey.toBuffer(),
+      platformAdmin.publicKey.toBuffer(), // guardian
+      Buffer.from([0]), // transfers not paused
+      i64(0),
+      Buffer.from([configBump]),
+    ]),
+    owner: program.programId,
+    executable: false,
+  });
+
+  // Treasury receives lamport-credited platform fees
+  context.setAccount(PLATFORM_TREASURY, {
+    lamports: LAMPORTS_PER_SOL,
+    data: Buffer.alloc(0),
+    owner: SystemProgram.programId,
+    executable: false,
+  });
+
+  console.log("\n🔑 Platform admin:", platformAdmin.publicKey.toString());
+  console.log("  PlatformConfig PDA:", platformConfig.toString());
+}
@@ -0,0 +1,112 @@
+/**
+ * HUB Token Program - Vesting Claims
+ *
+ * Sponsor allocations released after the cliff and linearly to the end.
+ */
+
+import { BN } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  createProperty,
+  createTokenAccount,
+  credentialAddress,
+  DAY,
+  expectError,
+  listProperty,
+  newWallet,
+  now,
+  pda,
+  program,
+  Property,
+  send,
+  startHarness,
+  TOKEN,
+  tokenBalance,
+  warp,
+} from "./utils/harness";
+
+describe("HUB Token Program - Vesting Claims", () => {
+  before(startHarness);
+
+  let property: Property;
+  let sponsor: Keypair;
+  let sponsorTokenAccount: PublicKey;
+  let vestingSchedule: PublicKey;
+  let startAt: number;
+  const total = 10_000 * TOKEN;
+
+  before(async () => {
+    property = await createProperty();
+    sponsor = await newWallet();
+    vestingSchedule = pda([
+      Buffer.from("vesting_schedule"),
+      property.mint.toBuffer(),
+      sponsor.publicKey.toBuffer(),
+    ]);
+
+    // Sponsor allocations are reserved while the property is a Draft
+    startAt = await now();
+    await send(
+      [
+        await program.methods
+          .createVestingSchedule(
+            sponsor.publicKey,
+            new BN(total),
+            new BN(startAt),
+            new BN(startAt + 30 * DAY),
+            new BN(startAt + 120 * DAY)
+          )
+          .accountsPartial({
+            authority: property.authority.publicKey,
+            propertyState: property.propertyState,
+            mint: property.mint,
+            vestingSchedule,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction(),
+      ],
+      [property.authority]
+    );
+    await listProperty(property);
+    sponsorTokenAccount = await createTokenAccount(sponsor.publicKey, property.mint);
+  });
+
+  const claim = () =>
+    program.methods
+      .claimVestedTokens()
+      .accountsPartial({
+        beneficiary: sponsor.publicKey,
+        propertyState: property.propertyState,
+        mint: property.mint,
+        vestingSchedule,
+        beneficiaryTokenAccount: sponsorTokenAccount,
+        beneficiaryCredential: credentialAddress(sponsor.publicKey)[0],
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .instruction();
+
+  it("Should release nothing before the cliff", async () => {
+    await expectError([await claim()], [sponsor], "NoVestedTokens");
+  });
+
+  it("Should release the linearly vested amount after the cliff", async () => {
+    await warp(60 * DAY);
+    await send([await claim()], [sponsor]);
+
+    const elapsed = BigInt((await now()) - startAt);
+    const expected = (BigInt(total) * elapsed) / BigInt(120 * DAY);
+    assert.equal(await tokenBalance(sponsorTokenAccount), expected);
+  });
+
+  it("Should release the remainder once fully vested", async () => {
+    await warp(60 * DAY);
+    await send([await claim()], [sponsor]);
+
+    assert.equal(await tokenBalance(sponsorTokenAccount), BigInt(total));
+    const state = await program.account.propertyState.fetch(property.propertyState);
+    assert.equal(state.sponsorClaimedSupply.toString(), total.toString());
+    console.log("✅ 10,000 sponsor tokens claimed over the schedule");
+  });
+});
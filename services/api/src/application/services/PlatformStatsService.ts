@@ -5,6 +5,7 @@ import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import { TOKENS } from '../../shared/container/tokens';
 import { Config } from '../../infrastructure/config/Config';
 import { SolanaConnectionAdapter } from '../../infrastructure/solana/SolanaConnectionAdapter';
+import { isPropertyLive, parsePropertyStatus } from '../../domain/entities';
 
 export interface PlatformStats {
   totalValueLocked: number;      // in USD
@@ -51,7 +52,7 @@ export class PlatformStatsService {
       }
 
       const properties: PropertyFromIndexer[] = response.data.data;
-      const activeProperties = properties.filter(p => p.status === 'active');
+      const activeProperties = properties.filter(p => isPropertyLive(parsePropertyStatus(p.status)));
 
       // Calculate TVL (totalValueUsd is stored in cents in DB)
       const totalValueLocked = activeProperties.reduce((sum, p) => {
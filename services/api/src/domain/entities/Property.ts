@@ -1,7 +1,35 @@
 /**
  * Property Entity - Core domain model for tokenized real estate
  */
-export type PropertyStatus = 'active' | 'paused' | 'frozen';
+/** On-chain PropertyStatus lifecycle, in variant order */
+export const PROPERTY_STATUSES = [
+  'draft',
+  'fundraising',
+  'funded',
+  'operating',
+  'paused',
+  'liquidating',
+  'closed',
+] as const;
+
+export type PropertyStatus = (typeof PROPERTY_STATUSES)[number];
+
+/** Statuses that accept authority minting (mirrors PropertyStatus::allows_minting) */
+const MINTABLE_STATUSES: PropertyStatus[] = ['fundraising', 'funded', 'operating'];
+
+/** Whether a property is live: open for investment or past its primary sale and not paused */
+export function isPropertyLive(status: PropertyStatus): boolean {
+  return status === 'fundraising' || status === 'funded' || status === 'operating';
+}
+
+/**
+ * Parse the status of a decoded PropertyState.
+ * Anchor decodes enums as `{ fundraising: {} }`.
+ */
+export function parsePropertyStatus(status: unknown): PropertyStatus {
+  const variant = status && typeof status === 'object' ? Object.keys(status)[0] : status;
+  return PROPERTY_STATUSES.find((s) => s === variant) ?? 'draft';
+}
 
 export interface PropertyDetails {
   propertyType: string;
@@ -70,7 +98,7 @@ export class PropertyEntity implements Property {
   }
 
   canMint(amount: bigint): boolean {
-    return this.status === 'active' && this.circulatingSupply + amount <= this.totalSupply;
+    return MINTABLE_STATUSES.includes(this.status) && this.circulatingSupply + amount <= this.totalSupply;
   }
 
   static fromOnChain(data: any, mint: string): PropertyEntity {
@@ -89,7 +117,7 @@ export class PropertyEntity implements Property {
         annualYieldPercent: (data.details?.rentalYieldBps || 0) / 100,
         metadataUri: data.details?.metadataUri || '',
       },
-      status: data.isActive ? 'active' : 'paused',
+      status: parsePropertyStatus(data.status),
       currentEpoch: Number(data.currentEpoch?.toString() || '0'),
       createdAt: data.createdAt
         ? new Date(Number(data.createdAt.toString()) * 1000)
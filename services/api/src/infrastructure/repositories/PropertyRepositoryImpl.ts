@@ -5,7 +5,7 @@ import { TOKENS } from '../../shared/container/tokens';
 import { SolanaProgramAdapter } from '../solana/SolanaProgramAdapter';
 import { Config } from '../config/Config';
 import { IPropertyRepository, PropertyFilter } from '../../application/ports/IPropertyRepository';
-import { PropertyEntity, Property } from '../../domain/entities';
+import { PropertyEntity, Property, parsePropertyStatus } from '../../domain/entities';
 
 @injectable()
 export class PropertyRepositoryImpl implements IPropertyRepository {
@@ -93,7 +93,7 @@ export class PropertyRepositoryImpl implements IPropertyRepository {
       authority: data.authority,
       name: data.name,
       symbol: data.symbol,
-      status: data.status,
+      status: parsePropertyStatus(data.status),
       totalSupply: BigInt(data.totalSupply || data.total_supply || 0),
       circulatingSupply: BigInt(data.circulatingSupply || data.circulating_supply || 0),
       decimals: data.decimals,
@@ -190,12 +190,12 @@ export class AdminService {
   }
 
   /**
-   * Derive the Hub Credential PDA for a wallet
-   * Seeds: ["credential", wallet]
+   * Derive the Hub Credential PDA for a wallet and credential type
+   * Seeds: ["credential", wallet, credential_type]
    */
-  private deriveInvestorCredentialPda(investor: PublicKey): PublicKey {
+  private deriveInvestorCredentialPda(investor: PublicKey, credentialType = 0): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('credential'), investor.toBuffer()],
+      [Buffer.from('credential'), investor.toBuffer(), Buffer.from([credentialType])],
       HUB_CREDENTIAL_PROGRAM_ID
     );
     return pda;
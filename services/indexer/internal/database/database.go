@@ -34,7 +34,7 @@ func (db *DB) Initialize() error {
 		symbol VARCHAR(10) NOT NULL,
 		authority VARCHAR(44) NOT NULL,
 		seller_wallet VARCHAR(44),
-		status VARCHAR(20) NOT NULL DEFAULT 'active',
+		status VARCHAR(20) NOT NULL DEFAULT 'draft',
 		total_supply BIGINT NOT NULL DEFAULT 0,
 		circulating_supply BIGINT NOT NULL DEFAULT 0,
 		decimals INTEGER NOT NULL DEFAULT 0,
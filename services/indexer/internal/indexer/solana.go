@@ -163,8 +163,8 @@ func (s *SolanaClient) parsePropertyState(pubkey solana.PublicKey, data []byte)
 	metadataURI := string(data[offset : offset+int(uriLen)])
 	offset += int(uriLen)
 
-	// is_active (bool - 1 byte)
-	isActive := data[offset] == 1
+	// status (PropertyStatus enum - 1 byte, the former is_active slot)
+	statusByte := data[offset]
 	offset += 1
 
 	// created_at (i64 - 8 bytes) - skip
@@ -176,9 +176,9 @@ func (s *SolanaClient) parsePropertyState(pubkey solana.PublicKey, data []byte)
 	// bump (u8 - 1 byte) - skip
 	// offset += 1
 
-	status := "paused"
-	if isActive {
-		status = "active"
+	status := "draft"
+	if int(statusByte) < len(propertyStatuses) {
+		status = propertyStatuses[statusByte]
 	}
 
 	return models.Property{
@@ -200,6 +200,17 @@ func (s *SolanaClient) parsePropertyState(pubkey solana.PublicKey, data []byte)
 	}, nil
 }
 
+// propertyStatuses maps the on-chain PropertyStatus variant index to its name
+var propertyStatuses = []string{
+	"draft",
+	"fundraising",
+	"funded",
+	"operating",
+	"paused",
+	"liquidating",
+	"closed",
+}
+
 func (s *SolanaClient) DerivePropertyStatePDA(mint string) (string, error) {
 	mintPubkey, err := solana.PublicKeyFromBase58(mint)
 	if err != nil {
@@ -11,11 +11,12 @@ import {
 
 const router = Router();
 
-// GET /api/credentials/:walletAddress - Get credential for a wallet
+// GET /api/credentials/:walletAddress?type=kycBasic - Get a wallet's credential of one type
 router.get('/:walletAddress', async (req: Request, res: Response) => {
   try {
     const { walletAddress } = req.params;
-    const result = await credentialService.getCredential(walletAddress);
+    const credentialType = typeof req.query.type === 'string' ? req.query.type : undefined;
+    const result = await credentialService.getCredential(walletAddress, credentialType);
 
     if (!result.success) {
       return res.status(404).json({ error: result.error });
@@ -3,6 +3,7 @@ import * as anchor from '@coral-xyz/anchor';
 import { BN } from '@coral-xyz/anchor';
 import { config } from '../config.js';
 import {
+  CredentialAttributesInput,
   IssueCredentialInput,
   RefreshCredentialInput,
   RevokeCredentialInput,
@@ -26,6 +27,19 @@ function getCredentialTypeValue(typeStr: string): number {
   return typeMap[typeStr] || 0;
 }
 
+// Map validated attributes to the on-chain CredentialAttributes struct
+function toOnChainAttributes(attributes: CredentialAttributesInput) {
+  return {
+    countryOfResidence: Array.from(Buffer.from(attributes.countryOfResidence)),
+    taxResidency: Array.from(Buffer.from(attributes.taxResidency ?? attributes.countryOfResidence)),
+    investorTier: { [attributes.investorTier]: {} },
+    riskBand: { [attributes.riskBand]: {} },
+    isPep: attributes.isPep,
+    investmentLimit:
+      attributes.investmentLimitLamports !== undefined ? new BN(attributes.investmentLimitLamports) : null,
+  };
+}
+
 // Map credential type enum or number to name
 // Anchor returns enums as objects like { kycBasic: {} } or as numbers
 function getCredentialTypeName(typeValue: any): string {
@@ -90,14 +104,22 @@ export class CredentialService {
     }
   }
 
-  private findCredentialPDA(holder: PublicKey): [PublicKey, number] {
-    return PublicKey.findProgramAddressSync([Buffer.from('credential'), holder.toBuffer()], this.programId);
+  // One credential per holder and type: seeds ["credential", holder, credential_type]
+  private findCredentialPDA(holder: PublicKey, credentialType: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('credential'), holder.toBuffer(), Buffer.from([getCredentialTypeValue(credentialType)])],
+      this.programId
+    );
   }
 
   private findNetworkPDA(): [PublicKey, number] {
     return PublicKey.findProgramAddressSync([Buffer.from('credential_network')], this.programId);
   }
 
+  private findTreasuryPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([Buffer.from('network_treasury')], this.programId);
+  }
+
   private findIssuerPDA(issuerAuthority: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync([Buffer.from('issuer'), issuerAuthority.toBuffer()], this.programId);
   }
@@ -117,25 +139,30 @@ export class CredentialService {
     try {
       console.log('[5] Issue credential - input:', input);
       const holderPubkey = new PublicKey(input.userWallet);
-      const [credentialPDA] = this.findCredentialPDA(holderPubkey);
+      const [credentialPDA] = this.findCredentialPDA(holderPubkey, input.credentialType);
       const [networkPDA] = this.findNetworkPDA();
+      const [treasuryPDA] = this.findTreasuryPDA();
       const [issuerPDA] = this.findIssuerPDA(issuerKeypair.publicKey);
 
       const expiryTimestamp = Math.floor(Date.now() / 1000) + input.expiresInDays * 24 * 60 * 60;
       const credentialType = getCredentialTypeValue(input.credentialType);
       const metadataUri = input.metadata || '';
+      const attributes = toOnChainAttributes(input.attributes);
+      const commitment = input.commitment ? Array.from(Buffer.from(input.commitment, 'hex')) : new Array(32).fill(0);
 
       console.log('[6] Calling issueCredential with:', {
         credentialType,
         expiryTimestamp,
         metadataUri,
+        attributes,
       });
 
       const tx = await program.methods
-        .issueCredential(credentialType, new BN(expiryTimestamp), metadataUri)
+        .issueCredential(credentialType, new BN(expiryTimestamp), metadataUri, attributes, commitment)
         .accounts({
           issuerAuthority: issuerKeypair.publicKey,
           network: networkPDA,
+          treasury: treasuryPDA,
           issuer: issuerPDA,
           holder: holderPubkey,
           credential: credentialPDA,
@@ -169,10 +196,10 @@ export class CredentialService {
     }
   }
 
-  async verifyCredential(userWallet: string, requiredType?: string): Promise<VerificationResponse> {
+  async verifyCredential(userWallet: string, requiredType = 'kycBasic'): Promise<VerificationResponse> {
     try {
       const holderPubkey = new PublicKey(userWallet);
-      const [credentialPDA] = this.findCredentialPDA(holderPubkey);
+      const [credentialPDA] = this.findCredentialPDA(holderPubkey, requiredType);
 
       const accountInfo = await this.connection.getAccountInfo(credentialPDA);
       if (!accountInfo) {
@@ -226,24 +253,6 @@ export class CredentialService {
         };
       }
 
-      if (requiredType && credentialType !== requiredType) {
-        return {
-          success: true,
-          isValid: false,
-          reason: `Credential type mismatch: required ${requiredType}, got ${credentialType}`,
-          credential: {
-            publicKey: credentialPDA.toString(),
-            user: credentialAccount.holder.toString(),
-            issuer: credentialAccount.issuer.toString(),
-            credentialType,
-            status,
-            issuedAt: credentialAccount.issuedAt.toNumber(),
-            expiresAt,
-            metadata: credentialAccount.metadataUri,
-          },
-        };
-      }
-
       return {
         success: true,
         isValid: true,
@@ -274,8 +283,10 @@ export class CredentialService {
 
     try {
       const holderPubkey = new PublicKey(input.userWallet);
-      const [credentialPDA] = this.findCredentialPDA(holderPubkey);
+      const [credentialPDA] = this.findCredentialPDA(holderPubkey, input.credentialType);
       const [issuerPDA] = this.findIssuerPDA(issuerKeypair.publicKey);
+      const [networkPDA] = this.findNetworkPDA();
+      const [treasuryPDA] = this.findTreasuryPDA();
 
       const newExpiryTimestamp = Math.floor(Date.now() / 1000) + input.newExpiresInDays * 24 * 60 * 60;
 
@@ -283,9 +294,12 @@ export class CredentialService {
         .refreshCredential(new BN(newExpiryTimestamp))
         .accounts({
           issuerAuthority: issuerKeypair.publicKey,
+          network: networkPDA,
+          treasury: treasuryPDA,
           issuer: issuerPDA,
           holder: holderPubkey,
           credential: credentialPDA,
+          systemProgram: SystemProgram.programId,
         })
         .signers([issuerKeypair])
         .rpc();
@@ -322,7 +336,7 @@ export class CredentialService {
 
     try {
       const holderPubkey = new PublicKey(input.userWallet);
-      const [credentialPDA] = this.findCredentialPDA(holderPubkey);
+      const [credentialPDA] = this.findCredentialPDA(holderPubkey, input.credentialType);
       const [issuerPDA] = this.findIssuerPDA(issuerKeypair.publicKey);
       const [networkPDA] = this.findNetworkPDA();
 
@@ -360,10 +374,10 @@ export class CredentialService {
     }
   }
 
-  async getCredential(userWallet: string): Promise<CredentialResponse> {
+  async getCredential(userWallet: string, credentialType = 'kycBasic'): Promise<CredentialResponse> {
     try {
       const holderPubkey = new PublicKey(userWallet);
-      const [credentialPDA] = this.findCredentialPDA(holderPubkey);
+      const [credentialPDA] = this.findCredentialPDA(holderPubkey, credentialType);
 
       const program = this.getProgram();
       if (!program) {
@@ -1,6 +1,11 @@
 import { v4 as uuidv4 } from 'uuid';
-import { KycVerificationRequest, KycSessionResponse } from '../types/credential.js';
+import {
+  CredentialAttributesSchema,
+  KycVerificationRequest,
+  KycSessionResponse,
+} from '../types/credential.js';
 import { credentialService } from './credentialService.js';
+import { AttributeDisclosure, buildAttributeCommitment } from '../utils/attributeCommitment.js';
 
 interface KycSession {
   sessionId: string;
@@ -14,6 +19,8 @@ interface KycSession {
     passed: boolean;
     reason?: string;
     credentialSignature?: string;
+    // Salts and proofs for prove_attribute; only the holder receives these
+    attributeDisclosures?: AttributeDisclosure[];
   };
 }
 
@@ -96,10 +103,18 @@ export class KycSessionService {
           type: session.credentialType
         });
 
+        // Anchor only a commitment to the KYC attributes on-chain (LGPD data minimization)
+        const commitment = buildAttributeCommitment(this.committedAttributes(session));
+
         const credentialResult = await credentialService.issueCredential({
           userWallet: session.walletAddress,
           credentialType: session.credentialType as any,
           expiresInDays: 365,
+          attributes: CredentialAttributesSchema.parse({
+            countryOfResidence: session.data.country?.toUpperCase(),
+            investorTier: session.credentialType === 'accreditedInvestor' ? 'qualified' : 'retail',
+          }),
+          commitment: commitment.root,
           metadata: JSON.stringify({
             verificationDate: new Date().toISOString(),
             sessionId: session.sessionId,
@@ -113,6 +128,7 @@ export class KycSessionService {
           session.verificationResult = {
             passed: true,
             credentialSignature: credentialResult.signature,
+            attributeDisclosures: commitment.disclosures,
           };
         } else {
           session.status = 'failed';
@@ -142,6 +158,29 @@ export class KycSessionService {
     }
   }
 
+  // Claims that can later be proven individually with prove_attribute
+  private committedAttributes(session: KycSession): string[] {
+    const { credentialType, data } = session;
+    const attributes = [`credential_type=${credentialType}`];
+
+    if (data.country) {
+      attributes.push(`country=${data.country.toUpperCase()}`);
+    }
+    if (data.dateOfBirth) {
+      const adultBirthDate = new Date();
+      adultBirthDate.setFullYear(adultBirthDate.getFullYear() - 18);
+      attributes.push(new Date(data.dateOfBirth) <= adultBirthDate ? 'age>=18' : 'age<18');
+    }
+    if (data.cpf) {
+      attributes.push('cpf_verified=true');
+    }
+    if (data.cnpj) {
+      attributes.push('cnpj_verified=true');
+    }
+
+    return attributes;
+  }
+
   private async verifyKycData(session: KycSession): Promise<{ passed: boolean; reason?: string }> {
     const { credentialType, data } = session;
 
@@ -30,11 +30,28 @@ export const CredentialTypeSchema = z.enum([
   'brazilianCnpj',
 ]);
 
+export const InvestorTierSchema = z.enum(['retail', 'qualified', 'professional', 'institutional']);
+
+export const RiskBandSchema = z.enum(['low', 'medium', 'high']);
+
+// On-chain compliance attributes (country codes are ISO 3166-1 alpha-2)
+export const CredentialAttributesSchema = z.object({
+  countryOfResidence: z.string().regex(/^[A-Z]{2}$/).default('BR'),
+  taxResidency: z.string().regex(/^[A-Z]{2}$/).optional(), // defaults to countryOfResidence
+  investorTier: InvestorTierSchema.default('retail'),
+  riskBand: RiskBandSchema.default('low'),
+  isPep: z.boolean().default(false),
+  investmentLimitLamports: z.number().int().positive().optional(),
+});
+
 export const IssueCredentialSchema = z.object({
   userWallet: z.string().min(32).max(44),
   credentialType: CredentialTypeSchema,
   expiresInDays: z.number().int().min(1).max(365).default(365),
   metadata: z.string().max(256).optional(),
+  attributes: CredentialAttributesSchema.default({}),
+  // Merkle root of salted off-chain KYC attributes (hex, see utils/attributeCommitment)
+  commitment: z.string().regex(/^[0-9a-f]{64}$/).optional(),
 });
 
 export const VerifyCredentialSchema = z.object({
@@ -44,11 +61,13 @@ export const VerifyCredentialSchema = z.object({
 
 export const RefreshCredentialSchema = z.object({
   userWallet: z.string().min(32).max(44),
+  credentialType: CredentialTypeSchema.default('kycBasic'),
   newExpiresInDays: z.number().int().min(1).max(365),
 });
 
 export const RevokeCredentialSchema = z.object({
   userWallet: z.string().min(32).max(44),
+  credentialType: CredentialTypeSchema.default('kycBasic'),
   reason: z.string().max(256).optional(),
 });
 
@@ -71,6 +90,7 @@ export const KycVerificationRequestSchema = z.object({
   documentNumber: z.string().optional(),
 });
 
+export type CredentialAttributesInput = z.infer<typeof CredentialAttributesSchema>;
 export type IssueCredentialInput = z.infer<typeof IssueCredentialSchema>;
 export type VerifyCredentialInput = z.infer<typeof VerifyCredentialSchema>;
 export type RefreshCredentialInput = z.infer<typeof RefreshCredentialSchema>;
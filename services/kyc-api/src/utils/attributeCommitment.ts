@@ -0,0 +1,71 @@
+import { createHash, randomBytes } from 'crypto';
+
+// Salted Merkle commitments to KYC attributes, matching the credential
+// program's prove_attribute instruction:
+// - leaf = sha256(0x00 || salt || attribute)
+// - node = sha256(0x01 || min(a, b) || max(a, b))
+
+export interface AttributeDisclosure {
+  attribute: string;
+  /** 32-byte salt (hex) */
+  salt: string;
+  /** Merkle proof from the leaf to the commitment (hex nodes) */
+  proof: string[];
+}
+
+export interface AttributeCommitment {
+  /** Merkle root stored on-chain (hex) */
+  root: string;
+  /** One disclosure package per attribute, handed to the holder only */
+  disclosures: AttributeDisclosure[];
+}
+
+const sha256 = (...parts: Buffer[]): Buffer => createHash('sha256').update(Buffer.concat(parts)).digest();
+
+export function attributeLeaf(salt: Buffer, attribute: string): Buffer {
+  return sha256(Buffer.from([0x00]), salt, Buffer.from(attribute));
+}
+
+function hashNode(a: Buffer, b: Buffer): Buffer {
+  return Buffer.compare(a, b) <= 0
+    ? sha256(Buffer.from([0x01]), a, b)
+    : sha256(Buffer.from([0x01]), b, a);
+}
+
+export function buildAttributeCommitment(attributes: string[]): AttributeCommitment {
+  if (attributes.length === 0) {
+    throw new Error('At least one attribute is required');
+  }
+
+  const salts = attributes.map(() => randomBytes(32));
+  const proofs: Buffer[][] = attributes.map(() => []);
+
+  // Track which original leaves sit under each node of the current level
+  let level = attributes.map((attribute, i) => ({ hash: attributeLeaf(salts[i], attribute), leaves: [i] }));
+
+  while (level.length > 1) {
+    const next: typeof level = [];
+    for (let i = 0; i < level.length; i += 2) {
+      const left = level[i];
+      const right = level[i + 1];
+      if (!right) {
+        // Odd node is promoted unchanged
+        next.push(left);
+        continue;
+      }
+      left.leaves.forEach((leaf) => proofs[leaf].push(right.hash));
+      right.leaves.forEach((leaf) => proofs[leaf].push(left.hash));
+      next.push({ hash: hashNode(left.hash, right.hash), leaves: [...left.leaves, ...right.leaves] });
+    }
+    level = next;
+  }
+
+  return {
+    root: level[0].hash.toString('hex'),
+    disclosures: attributes.map((attribute, i) => ({
+      attribute,
+      salt: salts[i].toString('hex'),
+      proof: proofs[i].map((node) => node.toString('hex')),
+    })),
+  };
+}